@@ -79,31 +79,37 @@ INSERT INTO users (id, username, email, password, metadata)
 VALUES ('ab4e5b77-45be-4d19-915b-517c39437e3d', 'example-user-1', 'email@email.com', '123', '{}');
 
 INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata) 
-VALUES ('bdb9334a-d703-11ed-afa1-0242ac120002', 'simple-storage', 'Simple Storage', 'Create a simple storage smart contract that stores a single unsigned 32-bit integer. The contract should have a `get` function to retrieve the stored value and a `set` function to update the stored value.', 'easy', 60, 4.5, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod simple_storage {\n    #[ink(storage)]\n    pub struct SimpleStorage {\n        // TODO, add your code here\n    }\n    impl SimpleStorage {\n        #[ink(constructor)]\n        pub fn new() -> Self {\n            // TODO, add your code here\n        }\n        // TODO, add your functions here\n    }\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n\n        use ink_lang as ink;\n\n        #[ink::test]\n        fn test_get_and_set() {\n            let mut simple_storage = SimpleStorage::new();\n            assert_eq!(simple_storage.get(), None);\n\n            let new_value = 42;\n            simple_storage.set(new_value);\n            assert_eq!(simple_storage.get(), Some(new_value));\n        }\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod simple_storage {\n    #[ink(storage)]\n    pub struct SimpleStorage {\n        value: ink_storage::collections::HashMap<AccountId, u32>,\n    }\n\n    impl SimpleStorage {\n        #[ink(constructor)]\n        pub fn new() -> Self {\n            Self {\n                value: Default::default(),\n            }\n        }\n\n        #[ink(message)]\n        pub fn get(&self) -> Option<u32> {\n            self.value.get(&self.env().caller()).copied()\n        }\n\n        #[ink(message)]\n        pub fn set(&mut self, value: u32) {\n            self.value.insert(self.env().caller(), value);\n        }\n    }\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n\n        use ink_lang as ink;\n\n        #[ink::test]\n        fn test_get_and_set() {\n            let mut simple_storage = SimpleStorage::new();\n            assert_eq!(simple_storage.get(), None);\n\n            let new_value = 42;\n            simple_storage.set(new_value);\n            assert_eq!(simple_storage.get(), Some(new_value));\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "storage"]}');
+VALUES ('bdb9334a-d703-11ed-afa1-0242ac120002', 'simple-storage', 'Simple Storage', 'Create a simple storage smart contract that stores a single unsigned 32-bit integer. The contract should have a `get` function to retrieve the stored value and a `set` function, restricted to the deployer, to update it. Keep the last few values in a fixed-size ring buffer returned by `get_history`, rejecting capacities above `MAX_HISTORY_CAPACITY` (100) with `CapacityTooLarge`, and emit a `ValueChanged` event with the old value, the new value and the caller.', 'easy', 60, 4.5, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::simple_storage::{Error, SimpleStorage, ValueChanged, MAX_HISTORY_CAPACITY};\n\n#[ink::contract]\nmod simple_storage {\n    use ink::prelude::vec::Vec;\n\n    #[ink(storage)]\n    pub struct SimpleStorage {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl SimpleStorage {\n        #[ink(constructor)]\n        pub fn new(history_capacity: u32) -> Result<Self, Error> {\n            // TODO, add your code here\n        }\n\n        // TODO, add your messages here\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::simple_storage::{Error, SimpleStorage, ValueChanged, MAX_HISTORY_CAPACITY};\n\n#[ink::contract]\nmod simple_storage {\n    use ink::prelude::vec::Vec;\n\n    /// The most values the history may keep, all of them are stored in a single cell.\n    pub const MAX_HISTORY_CAPACITY: u32 = 100;\n\n    /// A single value only the deployer may change, along with the last few values it\n    /// was set to.\n    #[ink(storage)]\n    pub struct SimpleStorage {\n        value: Option<u32>,\n        owner: AccountId,\n        /// A ring buffer of the most recent values, `history_start` is its oldest entry\n        /// once it is full.\n        history: Vec<u32>,\n        history_start: u32,\n        history_capacity: u32,\n    }\n\n    #[ink(event)]\n    pub struct ValueChanged {\n        /// `None` when the value was set for the first time.\n        pub old: Option<u32>,\n        pub new: u32,\n        #[ink(topic)]\n        pub by: AccountId,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        Unauthorized,\n        /// The history capacity is above `MAX_HISTORY_CAPACITY`.\n        CapacityTooLarge,\n    }\n\n    impl SimpleStorage {\n        /// Creates the storage, keeping the last `history_capacity` values.\n        #[ink(constructor)]\n        pub fn new(history_capacity: u32) -> Result<Self, Error> {\n            if history_capacity > MAX_HISTORY_CAPACITY {\n                return Err(Error::CapacityTooLarge);\n            }\n            Ok(Self {\n                value: None,\n                owner: Self::env().caller(),\n                history: Vec::new(),\n                history_start: 0,\n                history_capacity,\n            })\n        }\n\n        #[ink(message)]\n        pub fn get(&self) -> Option<u32> {\n            self.value\n        }\n\n        #[ink(message)]\n        pub fn set(&mut self, value: u32) -> Result<(), Error> {\n            let caller = self.env().caller();\n            if caller != self.owner {\n                return Err(Error::Unauthorized);\n            }\n            let old = self.value.replace(value);\n            self.record(value);\n            self.env().emit_event(ValueChanged {\n                old,\n                new: value,\n                by: caller,\n            });\n            Ok(())\n        }\n\n        /// Returns the last values that were set, oldest first.\n        #[ink(message)]\n        pub fn get_history(&self) -> Vec<u32> {\n            let (newer, older) = self.history.split_at(self.history_start as usize);\n            older.iter().chain(newer).copied().collect()\n        }\n\n        #[ink(message)]\n        pub fn get_owner(&self) -> AccountId {\n            self.owner\n        }\n\n        /// Appends `value` to the history, overwriting the oldest entry once it is full.\n        fn record(&mut self, value: u32) {\n            if self.history_capacity == 0 {\n                return;\n            }\n            if self.history.len() < self.history_capacity as usize {\n                self.history.push(value);\n            } else {\n                self.history[self.history_start as usize] = value;\n                self.history_start = (self.history_start + 1) % self.history_capacity;\n            }\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "storage"]}');
 
 INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata) 
-VALUES ('e9d2b4c0-d703-11ed-afa1-0242ac120002', 'counter', 'Counter', 'Create a Counter smart contract that allows users to increment and decrement a counter value. The contract should have `increment` and `decrement` functions, and a `get` function to retrieve the current value of the counter.', 'easy', 50, 4, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod counter {\n    #[ink(storage)]\n    pub struct Counter {\n        // TODO, add your code here\n    }\n    impl Counter {\n        #[ink(constructor)]\n        pub fn new() -> Self {\n            // TODO, add your code here\n        }\n        // TODO, add your functions here\n    }\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n\n        use ink_lang as ink;\n\n        #[ink::test]\n        fn test_increment() {\n            let mut counter = Counter::new();\n            assert_eq!(counter.get(), 0);\n\n            counter.increment();\n            assert_eq!(counter.get(), 1);\n\n            counter.increment();\n            assert_eq!(counter.get(), 2);\n        }\n\n        #[ink::test]\n        fn test_decrement() {\n            let mut counter = Counter::new();\n            assert_eq!(counter.get(), 0);\n\n            counter.decrement();\n            assert_eq!(counter.get(), -1);\n\n            counter.decrement();\n            assert_eq!(counter.get(), -2);\n        }\n\n        #[ink::test]\n        fn test_increment_and_decrement() {\n            let mut counter = Counter::new();\n            assert_eq!(counter.get(), 0);\n\n            counter.increment();\n            assert_eq!(counter.get(), 1);\n\n            counter.decrement();\n            assert_eq!(counter.get(), 0);\n        }\n    }\n\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod counter {\n    #[ink(storage)]\n    pub struct Counter {\n        value: i32,\n    }\n    impl Counter {\n        #[ink(constructor)]\n        pub fn new() -> Self {\n            Self { value: 0 }\n        }\n\n        #[ink(message)]\n        pub fn get(&self) -> i32 {\n            self.value\n        }\n\n        #[ink(message)]\n        pub fn increment(&mut self) {\n            self.value += 1;\n        }\n\n        #[ink(message)]\n        pub fn decrement(&mut self) {\n            self.value -= 1;\n        }\n    }\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n\n        use ink_lang as ink;\n\n        #[ink::test]\n        fn test_increment() {\n            let mut counter = Counter::new();\n            assert_eq!(counter.get(), 0);\n\n            counter.increment();\n            assert_eq!(counter.get(), 1);\n\n            counter.increment();\n            assert_eq!(counter.get(), 2);\n        }\n\n        #[ink::test]\n        fn test_decrement() {\n            let mut counter = Counter::new();\n            assert_eq!(counter.get(), 0);\n\n            counter.decrement();\n            assert_eq!(counter.get(), -1);\n\n            counter.decrement();\n            assert_eq!(counter.get(), -2);\n        }\n\n        #[ink::test]\n        fn test_increment_and_decrement() {\n            let mut counter = Counter::new();\n            assert_eq!(counter.get(), 0);\n\n            counter.increment();\n            assert_eq!(counter.get(), 1);\n\n            counter.decrement();\n            assert_eq!(counter.get(), 0);\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "counter"]}');
+VALUES ('e9d2b4c0-d703-11ed-afa1-0242ac120002', 'counter', 'Counter', 'Create a Counter smart contract that starts at an initial value and stays between a minimum and a maximum. The contract should have `increment` and `decrement` functions moving by a configured step, `increment_by` and `decrement_by` functions moving by any amount, a `reset` function only the deployer may call, and a `get` function to retrieve the current value of the counter. Changes that would leave the bounds must fail with `CounterError::OutOfBounds`. Keep track of how much each account moved the counter in a `Mapping`, and expose it through `get_mine`, `get_of` and a `total` that always matches the distance from the initial value.', 'easy', 50, 4, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::counter::{Counter, CounterError, Decremented, Incremented, Reset};\n\n#[ink::contract]\nmod counter {\n    use ink::storage::Mapping;\n\n    #[ink(storage)]\n    pub struct Counter {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl Counter {\n        #[ink(constructor)]\n        pub fn new(init_value: i32, min: i32, max: i32, step: u32) -> Result<Self, CounterError> {\n            // TODO, add your code here\n        }\n\n        // TODO, add your messages here\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::counter::{Counter, CounterError, Decremented, Incremented, Reset};\n\n#[ink::contract]\nmod counter {\n    use ink::storage::Mapping;\n\n    /// A counter that moves in steps and stays within `min..=max`.\n    ///\n    /// Next to the global value it keeps, per account, the net amount that account\n    /// moved the counter by, so that `value == init_value + total` always holds.\n    #[ink(storage)]\n    pub struct Counter {\n        value: i32,\n        /// The value the counter started at, restored by `reset`.\n        init_value: i32,\n        min: i32,\n        max: i32,\n        /// The amount `increment` and `decrement` move the counter by.\n        step: u32,\n        /// The deployer, the only account allowed to reset the counter.\n        owner: AccountId,\n        /// The net amount each account moved the counter by.\n        counts: Mapping<AccountId, i64>,\n        /// The sum of all `counts`.\n        total: i64,\n    }\n\n    #[ink(event)]\n    pub struct Incremented {\n        #[ink(topic)]\n        pub caller: AccountId,\n        pub amount: u32,\n        /// The value after the change.\n        pub value: i32,\n    }\n\n    #[ink(event)]\n    pub struct Decremented {\n        #[ink(topic)]\n        pub caller: AccountId,\n        pub amount: u32,\n        /// The value after the change.\n        pub value: i32,\n    }\n\n    #[ink(event)]\n    pub struct Reset {\n        #[ink(topic)]\n        pub caller: AccountId,\n        pub value: i32,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum CounterError {\n        /// The value would leave `min..=max`.\n        OutOfBounds,\n        /// `min` is above `max`, or the initial value lies outside them.\n        InvalidBounds,\n        /// The step must not be zero.\n        InvalidStep,\n        Unauthorized,\n        /// The caller''s own count would leave the range of an `i64`.\n        Overflow,\n    }\n\n    impl Counter {\n        #[ink(constructor)]\n        pub fn new(init_value: i32, min: i32, max: i32, step: u32) -> Result<Self, CounterError> {\n            if min > max || !(min..=max).contains(&init_value) {\n                return Err(CounterError::InvalidBounds);\n            }\n            if step == 0 {\n                return Err(CounterError::InvalidStep);\n            }\n            Ok(Self {\n                value: init_value,\n                init_value,\n                min,\n                max,\n                step,\n                owner: Self::env().caller(),\n                counts: Mapping::new(),\n                total: 0,\n            })\n        }\n\n        #[ink(message)]\n        pub fn get(&self) -> i32 {\n            self.value\n        }\n\n        /// Returns `(min, max)`.\n        #[ink(message)]\n        pub fn get_bounds(&self) -> (i32, i32) {\n            (self.min, self.max)\n        }\n\n        #[ink(message)]\n        pub fn get_step(&self) -> u32 {\n            self.step\n        }\n\n        #[ink(message)]\n        pub fn get_owner(&self) -> AccountId {\n            self.owner\n        }\n\n        /// Returns the net amount the caller moved the counter by.\n        #[ink(message)]\n        pub fn get_mine(&self) -> i64 {\n            self.get_of(self.env().caller())\n        }\n\n        #[ink(message)]\n        pub fn get_of(&self, account: AccountId) -> i64 {\n            self.counts.get(account).unwrap_or(0)\n        }\n\n        /// Returns the sum of the counts of all accounts.\n        #[ink(message)]\n        pub fn total(&self) -> i64 {\n            self.total\n        }\n\n        /// Moves the counter up by one step.\n        #[ink(message)]\n        pub fn increment(&mut self) -> Result<(), CounterError> {\n            self.increment_by(self.step)\n        }\n\n        /// Moves the counter down by one step.\n        #[ink(message)]\n        pub fn decrement(&mut self) -> Result<(), CounterError> {\n            self.decrement_by(self.step)\n        }\n\n        #[ink(message)]\n        pub fn increment_by(&mut self, amount: u32) -> Result<(), CounterError> {\n            let caller = self.env().caller();\n            self.move_by(caller, i64::from(amount))?;\n            self.env().emit_event(Incremented {\n                caller,\n                amount,\n                value: self.value,\n            });\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn decrement_by(&mut self, amount: u32) -> Result<(), CounterError> {\n            let caller = self.env().caller();\n            self.move_by(caller, -i64::from(amount))?;\n            self.env().emit_event(Decremented {\n                caller,\n                amount,\n                value: self.value,\n            });\n            Ok(())\n        }\n\n        /// Puts the counter back to its initial value, called by the deployer. The\n        /// change counts as the deployer''s own.\n        #[ink(message)]\n        pub fn reset(&mut self) -> Result<(), CounterError> {\n            let caller = self.env().caller();\n            if caller != self.owner {\n                return Err(CounterError::Unauthorized);\n            }\n            let delta = i64::from(self.init_value) - i64::from(self.value);\n            self.move_by(caller, delta)?;\n            self.env().emit_event(Reset {\n                caller,\n                value: self.value,\n            });\n            Ok(())\n        }\n\n        /// Moves the counter by `delta` on behalf of `account`. The sums are computed in\n        /// `i64`, as a single move, a reset included, can span more than an `i32`.\n        fn move_by(&mut self, account: AccountId, delta: i64) -> Result<(), CounterError> {\n            let value = i64::from(self.value) + delta;\n            if value < i64::from(self.min) || value > i64::from(self.max) {\n                return Err(CounterError::OutOfBounds);\n            }\n            let count = self\n                .get_of(account)\n                .checked_add(delta)\n                .ok_or(CounterError::Overflow)?;\n            self.value = value as i32;\n            self.counts.insert(account, &count);\n            // Cannot overflow, the total stays equal to `value - init_value`.\n            self.total += delta;\n            Ok(())\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "counter"]}');
 
 INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata) 
-VALUES ('24f5a5d2-d703-11ed-afa1-0242ac120002', 'simple-token', 'Simple Token', 'Create a simple token smart contract that allows users to mint, transfer, and check their token balance. Implement `mint`, `transfer`, and `get_balance` functions.', 'med', 70, 4.5, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod simple_token {\n    #[ink(storage)]\n    pub struct SimpleToken {\n        balances: ink_prelude::collections::HashMap<AccountId, Balance>,\n    }\n    // Add your implementation here\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n    \n        use ink_lang as ink;\n    \n        #[ink::test]\n        fn test_mint() {\n            let mut token = SimpleToken::new();\n            let initial_balance = token.balance_of(AccountId::from([0x1; 32]));\n            assert_eq!(initial_balance, 0);\n    \n            let amount = 100;\n            let to = AccountId::from([0x2; 32]);\n            token.mint(to, amount);\n            assert_eq!(token.balance_of(to), amount);\n    \n            let transfer_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"Transfer\"))\n                .expect(\"no transfer event was emitted\");\n            assert_eq!(transfer_event.event, \"Transfer\");\n            assert_eq!(transfer_event.args[0].clone().into_account_id(), None);\n            assert_eq!(transfer_event.args[1].clone().into_account_id(), Some(to));\n            assert_eq!(transfer_event.args[2].clone().into_balance(), amount);\n        }\n    \n        #[ink::test]\n        fn test_transfer() {\n            let mut token = SimpleToken::new();\n            let sender = AccountId::from([0x1; 32]);\n            let recipient = AccountId::from([0x2; 32]);\n            let amount = 100;\n            token.mint(sender, amount);\n    \n            let transfer_success = token.transfer(recipient, amount);\n            assert!(transfer_success);\n            assert_eq!(token.balance_of(sender), 0);\n            assert_eq!(token.balance_of(recipient), amount);\n    \n            let transfer_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"Transfer\"))\n                .expect(\"no transfer event was emitted\");\n            assert_eq!(transfer_event.event, \"Transfer\");\n            assert_eq!(transfer_event.args[0].clone().into_account_id(), Some(sender));\n            assert_eq!(transfer_event.args[1].clone().into_account_id(), Some(recipient));\n            assert_eq!(transfer_event.args[2].clone().into_balance(), amount);\n        }\n    \n        #[ink::test]\n        fn test_insufficient_balance_transfer() {\n            let mut token = SimpleToken::new();\n            let sender = AccountId::from([0x1; 32]);\n            let recipient = AccountId::from([0x2; 32]);\n            let amount = 100;\n            token.mint(sender, amount);\n    \n            let transfer_success = token.transfer(recipient, amount + 1);\n            assert!(!transfer_success);\n            assert_eq!(token.balance_of(sender), amount);\n            assert_eq!(token.balance_of(recipient), 0);\n    \n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.len(), 0);\n        }\n    \n        #[ink::test]\n        fn test_balance_of() {\n            let mut token = SimpleToken::new();\n            let account = AccountId::from([0x1; 32]);\n            let amount = 100;\n            token.mint(account, amount);\n    \n            assert_eq!(token.balance_of(account), amount);\n        }\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod simple_token {\n    use ink_prelude::vec::Vec;\n\n    #[ink(storage)]\n    pub struct SimpleToken {\n        balances: ink_prelude::collections::HashMap<AccountId, Balance>,\n    }\n\n    #[ink(event)]\n    pub struct Transfer {\n        #[ink(topic)]\n        from: Option<AccountId>,\n        #[ink(topic)]\n        to: Option<AccountId>,\n        value: Balance,\n    }\n\n    impl SimpleToken {\n        #[ink(constructor)]\n        pub fn new() -> Self {\n            Self {\n                balances: Default::default(),\n            }\n        }\n\n        #[ink(message)]\n        pub fn mint(&mut self, to: AccountId, amount: Balance) {\n            let sender = self.env().caller();\n            self.balances\n                .entry(to)\n                .and_modify(|balance| *balance += amount)\n                .or_insert(amount);\n            self.env().emit_event(Transfer {\n                from: None,\n                to: Some(to),\n                value: amount,\n            });\n        }\n\n        #[ink(message)]\n        pub fn transfer(&mut self, to: AccountId, amount: Balance) -> bool {\n            let sender = self.env().caller();\n            let sender_balance = self.balance_of(sender);\n            if sender_balance < amount {\n                return false;\n            }\n            self.balances.insert(sender, sender_balance - amount);\n            self.balances\n                .entry(to)\n                .and_modify(|balance| *balance += amount)\n                .or_insert(amount);\n            self.env().emit_event(Transfer {\n                from: Some(sender),\n                to: Some(to),\n                value: amount,\n            });\n            true\n        }\n\n        #[ink(message)]\n        pub fn balance_of(&self, owner: AccountId) -> Balance {\n            self.balances.get(&owner).cloned().unwrap_or(0)\n        }\n    }\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n    \n        use ink_lang as ink;\n    \n        #[ink::test]\n        fn test_mint() {\n            let mut token = SimpleToken::new();\n            let initial_balance = token.balance_of(AccountId::from([0x1; 32]));\n            assert_eq!(initial_balance, 0);\n    \n            let amount = 100;\n            let to = AccountId::from([0x2; 32]);\n            token.mint(to, amount);\n            assert_eq!(token.balance_of(to), amount);\n    \n            let transfer_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"Transfer\"))\n                .expect(\"no transfer event was emitted\");\n            assert_eq!(transfer_event.event, \"Transfer\");\n            assert_eq!(transfer_event.args[0].clone().into_account_id(), None);\n            assert_eq!(transfer_event.args[1].clone().into_account_id(), Some(to));\n            assert_eq!(transfer_event.args[2].clone().into_balance(), amount);\n        }\n    \n        #[ink::test]\n        fn test_transfer() {\n            let mut token = SimpleToken::new();\n            let sender = AccountId::from([0x1; 32]);\n            let recipient = AccountId::from([0x2; 32]);\n            let amount = 100;\n            token.mint(sender, amount);\n    \n            let transfer_success = token.transfer(recipient, amount);\n            assert!(transfer_success);\n            assert_eq!(token.balance_of(sender), 0);\n            assert_eq!(token.balance_of(recipient), amount);\n    \n            let transfer_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"Transfer\"))\n                .expect(\"no transfer event was emitted\");\n            assert_eq!(transfer_event.event, \"Transfer\");\n            assert_eq!(transfer_event.args[0].clone().into_account_id(), Some(sender));\n            assert_eq!(transfer_event.args[1].clone().into_account_id(), Some(recipient));\n            assert_eq!(transfer_event.args[2].clone().into_balance(), amount);\n        }\n    \n        #[ink::test]\n        fn test_insufficient_balance_transfer() {\n            let mut token = SimpleToken::new();\n            let sender = AccountId::from([0x1; 32]);\n            let recipient = AccountId::from([0x2; 32]);\n            let amount = 100;\n            token.mint(sender, amount);\n    \n            let transfer_success = token.transfer(recipient, amount + 1);\n            assert!(!transfer_success);\n            assert_eq!(token.balance_of(sender), amount);\n            assert_eq!(token.balance_of(recipient), 0);\n    \n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.len(), 0);\n        }\n    \n        #[ink::test]\n        fn test_balance_of() {\n            let mut token = SimpleToken::new();\n            let account = AccountId::from([0x1; 32]);\n            let amount = 100;\n            token.mint(account, amount);\n    \n            assert_eq!(token.balance_of(account), amount);\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "token"]}');
+VALUES ('24f5a5d2-d703-11ed-afa1-0242ac120002', 'simple-token', 'Simple Token', 'Create a simple token smart contract owned by its deployer. The owner and the minters they appoint can mint tokens up to an optional supply cap, while holders can transfer and burn their tokens. Implement `mint`, `burn`, `transfer`, `add_minter`, `remove_minter`, `balance_of` and `total_supply` functions, and reject unauthorized minting with a typed error.', 'med', 70, 4.5, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::simple_token::{Error, SimpleToken, Transfer};\n\n#[ink::contract]\nmod simple_token {\n    use ink::storage::Mapping;\n\n    #[ink(storage)]\n    pub struct SimpleToken {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl SimpleToken {\n        #[ink(constructor)]\n        pub fn new(cap: Option<Balance>) -> Self {\n            // TODO, add your code here\n        }\n\n        // TODO, add your messages here\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::simple_token::{Error, SimpleToken, Transfer};\n\n#[ink::contract]\nmod simple_token {\n    use ink::storage::Mapping;\n\n    #[ink(storage)]\n    pub struct SimpleToken {\n        balances: Mapping<AccountId, Balance>,\n        total_supply: Balance,\n        /// Minting stops once the total supply would exceed the cap.\n        cap: Option<Balance>,\n        owner: AccountId,\n        /// Accounts the owner allowed to mint.\n        minters: Mapping<AccountId, ()>,\n    }\n\n    #[ink(event)]\n    pub struct Transfer {\n        #[ink(topic)]\n        pub from: Option<AccountId>,\n        #[ink(topic)]\n        pub to: Option<AccountId>,\n        pub value: Balance,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        /// Only the owner, or a minter for `mint`, may call this.\n        Unauthorized,\n        /// Minting would raise the total supply above the cap.\n        CapExceeded,\n        InsufficientBalance,\n        /// The total supply would leave the range of `Balance`.\n        Overflow,\n    }\n\n    impl SimpleToken {\n        /// Creates the token with the caller as its owner and an optional supply cap.\n        #[ink(constructor)]\n        pub fn new(cap: Option<Balance>) -> Self {\n            Self {\n                balances: Mapping::new(),\n                total_supply: 0,\n                cap,\n                owner: Self::env().caller(),\n                minters: Mapping::new(),\n            }\n        }\n\n        /// Creates `amount` new tokens for `to`, called by the owner or a minter.\n        #[ink(message)]\n        pub fn mint(&mut self, to: AccountId, amount: Balance) -> Result<(), Error> {\n            let caller = self.env().caller();\n            if caller != self.owner && !self.is_minter(caller) {\n                return Err(Error::Unauthorized);\n            }\n            let total_supply = self\n                .total_supply\n                .checked_add(amount)\n                .ok_or(Error::Overflow)?;\n            if self.cap.is_some_and(|cap| total_supply > cap) {\n                return Err(Error::CapExceeded);\n            }\n            let to_balance = self\n                .balance_of(to)\n                .checked_add(amount)\n                .ok_or(Error::Overflow)?;\n            self.total_supply = total_supply;\n            self.balances.insert(to, &to_balance);\n            self.env().emit_event(Transfer {\n                from: None,\n                to: Some(to),\n                value: amount,\n            });\n            Ok(())\n        }\n\n        /// Destroys `amount` of the caller''s tokens, lowering the total supply.\n        #[ink(message)]\n        pub fn burn(&mut self, amount: Balance) -> Result<(), Error> {\n            let caller = self.env().caller();\n            let balance = self.balance_of(caller);\n            if balance < amount {\n                return Err(Error::InsufficientBalance);\n            }\n            let total_supply = self\n                .total_supply\n                .checked_sub(amount)\n                .ok_or(Error::Overflow)?;\n            self.balances.insert(caller, &(balance - amount));\n            self.total_supply = total_supply;\n            self.env().emit_event(Transfer {\n                from: Some(caller),\n                to: None,\n                value: amount,\n            });\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn transfer(&mut self, to: AccountId, amount: Balance) -> bool {\n            let sender = self.env().caller();\n            let sender_balance = self.balance_of(sender);\n            if sender_balance < amount {\n                return false;\n            }\n            self.balances.insert(sender, &(sender_balance - amount));\n            // Cannot saturate, the balances add up to the total supply.\n            let to_balance = self.balance_of(to).saturating_add(amount);\n            self.balances.insert(to, &to_balance);\n            self.env().emit_event(Transfer {\n                from: Some(sender),\n                to: Some(to),\n                value: amount,\n            });\n            true\n        }\n\n        /// Lets `account` mint, called by the owner.\n        #[ink(message)]\n        pub fn add_minter(&mut self, account: AccountId) -> Result<(), Error> {\n            self.ensure_owner()?;\n            self.minters.insert(account, &());\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn remove_minter(&mut self, account: AccountId) -> Result<(), Error> {\n            self.ensure_owner()?;\n            self.minters.remove(account);\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn balance_of(&self, owner: AccountId) -> Balance {\n            self.balances.get(owner).unwrap_or(0)\n        }\n\n        #[ink(message)]\n        pub fn total_supply(&self) -> Balance {\n            self.total_supply\n        }\n\n        #[ink(message)]\n        pub fn get_cap(&self) -> Option<Balance> {\n            self.cap\n        }\n\n        #[ink(message)]\n        pub fn get_owner(&self) -> AccountId {\n            self.owner\n        }\n\n        #[ink(message)]\n        pub fn is_minter(&self, account: AccountId) -> bool {\n            self.minters.contains(account)\n        }\n\n        fn ensure_owner(&self) -> Result<(), Error> {\n            if self.env().caller() != self.owner {\n                return Err(Error::Unauthorized);\n            }\n            Ok(())\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "token"]}');
 
 INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata) 
-VALUES ('5da2b7da-d703-11ed-afa1-0242ac120002', 'voting', 'Voting', 'Create a voting smart contract that allows users to propose options and vote on them. Implement propose_option, vote, and get_results functions. The contract should prevent users from voting more than once.', 'med', 40, 4.2, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod voting {\n    #[ink(storage)]\n    pub struct Voting {\n    options: ink_prelude::collections::HashMap<u32, (String, u32)>,\n    voters: ink_prelude::collections::HashMap<AccountId, bool>,\n    }\n    // Add your implementation here\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n    \n        use ink_lang as ink;\n    \n        #[ink::test]\n        fn test_propose_option() {\n            let mut voting = Voting::new();\n            let option_name = \"Option 1\".to_string();\n            voting.propose_option(option_name.clone());\n    \n            let options = voting.get_options();\n            assert_eq!(options.len(), 1);\n            let (name, count) = options[0];\n            assert_eq!(name, option_name);\n            assert_eq!(count, 0);\n    \n            let propose_option_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"ProposeOption\"))\n                .expect(\"no ProposeOption event was emitted\");\n            assert_eq!(propose_option_event.event, \"ProposeOption\");\n            assert_eq!(propose_option_event.args[0].clone().into_string().unwrap(), option_name);\n        }\n    \n        #[ink::test]\n        fn test_vote() {\n            let mut voting = Voting::new();\n            let option_name = \"Option 1\".to_string();\n            voting.propose_option(option_name.clone());\n    \n            let voter = AccountId::from([0x1; 32]);\n            let vote_success = voting.vote(0, {ink_env::test::Call::new(voter, 0)});\n            assert!(vote_success);\n            assert!(voting.has_voted(voter));\n            assert_eq!(voting.get_option_count(0), 1);\n    \n            let vote_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"Vote\"))\n                .expect(\"no Vote event was emitted\");\n            assert_eq!(vote_event.event, \"Vote\");\n            assert_eq!(vote_event.args[0].clone().into_account_id(), Some(voter));\n            assert_eq!(vote_event.args[1].clone().into_u32(), 0);\n        }\n    \n        #[ink::test]\n        fn test_vote_twice() {\n            let mut voting = Voting::new();\n            let option_name = \"Option 1\".to_string();\n            voting.propose_option(option_name.clone());\n    \n            let voter = AccountId::from([0x1; 32]);\n            let vote_success = voting.vote(0, {ink_env::test::Call::new(voter, 0)});\n            assert!(vote_success);\n            assert!(voting.has_voted(voter));\n            assert_eq!(voting.get_option_count(0), 1);\n    \n            let vote_success = voting.vote(0, {ink_env::test::Call::new(voter, 0)});\n            assert!(!vote_success);\n            assert!(voting.has_voted(voter));\n            assert_eq!(voting.get_option_count(0), 1);\n    \n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.len(), 1);\n        }\n    \n        #[ink::test]\n        fn test_get_results() {\n            let mut voting = Voting::new();\n            let option_name_1 = \"Option 1\".to_string();\n            let option_name_2 = \"Option 2\".to_string();\n            voting.propose_option(option_name_1.clone());\n            voting.propose_option(option_name_2.clone());\n    \n            let voter_1 = AccountId::from([0x1; 32]);\n            let voter_2 = AccountId::from([0x2; 32]);\n            let voter_3 = AccountId::from([0x3; 32]);\n            voting.vote(0, {ink_env::test::Call::new(voter_1, 0\n            voting.vote(1, {ink_env::test::Call::new(voter_1, 0)});\n            voting.vote(1, {ink_env::test::Call::new(voter_2, 0)});\n            voting.vote(1, {ink_env::test::Call::new(voter_3, 0)});\n            voting.vote(1, {ink_env::test::Call::new(voter_3, 0)});\n            voting.vote(1, {ink_env::test::Call::new(voter_3, 0)});\n        \n            let results = voting.get_results();\n            assert_eq!(results.len(), 2);\n            let (name_1, count_1) = results[0];\n            let (name_2, count_2) = results[1];\n            assert_eq!(name_1, option_name_1);\n            assert_eq!(name_2, option_name_2);\n            assert_eq!(count_1, 1);\n            assert_eq!(count_2, 4);\n        \n            let get_results_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"GetResults\"))\n                .expect(\"no GetResults event was emitted\");\n            assert_eq!(get_results_event.event, \"GetResults\");\n            assert_eq!(get_results_event.args[0].clone().into_vec().unwrap().len(), 2);\n        }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod voting {\n    use ink_prelude::vec::Vec;\n\n    #[ink(storage)]\n    pub struct Voting {\n        options: ink_prelude::collections::Vec<(String, u32)>,\n        voters: ink_prelude::collections::HashMap<AccountId, bool>,\n    }\n\n    #[ink(event)]\n    pub struct ProposeOption {\n        #[ink(topic)]\n        name: String,\n    }\n\n    #[ink(event)]\n    pub struct Vote {\n        #[ink(topic)]\n        voter: AccountId,\n        #[ink(topic)]\n        option_index: u32,\n    }\n\n    #[ink(event)]\n    pub struct GetResults {\n        results: Vec<(String, u32)>,\n    }\n\n    impl Voting {\n        #[ink(constructor)]\n        pub fn new() -> Self {\n            Self {\n                options: ink_prelude::collections::Vec::new(),\n                voters: ink_prelude::collections::HashMap::new(),\n            }\n        }\n\n        #[ink(message)]\n        pub fn propose_option(&mut self, name: String) {\n            self.options.push((name.clone(), 0));\n            self.env().emit_event(ProposeOption { name });\n        }\n\n        #[ink(message)]\n        pub fn vote(&mut self, option_index: u32) -> bool {\n            let sender = self.env().caller();\n            if self.voters.contains_key(&sender) {\n                return false;\n            }\n            if let Some((name, count)) = self.options.get_mut(option_index as usize) {\n                *count += 1;\n                self.voters.insert(sender, true);\n                self.env().emit_event(Vote {\n                    voter: sender,\n                    option_index,\n                });\n                return true;\n            }\n            false\n        }\n\n        #[ink(message)]\n        pub fn has_voted(&self, voter: AccountId) -> bool {\n            self.voters.contains_key(&voter)\n        }\n\n        #[ink(message)]\n        pub fn get_option_count(&self, option_index: u32) -> u32 {\n            if let Some((_, count)) = self.options.get(option_index as usize) {\n                *count\n            } else {\n                0\n            }\n        }\n\n        #[ink(message)]\n        pub fn get_options(&self) -> Vec<(String, u32)> {\n            self.options.clone()\n        }\n\n        #[ink(message)]\n        pub fn get_results(&self) -> Vec<(String, u32)> {\n            self.options.clone()\n        }\n\n    }\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n\n        use ink_lang as ink;\n\n        #[ink::test]\n        fn test_propose_option() {\n            let mut voting = Voting::new();\n            let option_name = \"Option 1\".to_string();\n            voting.propose_option(option_name.clone());\n\n            let options = voting.get_options();\n            assert_eq!(options.len(), 1);\n            let (name, count) = options[0];\n            assert_eq!(name, option_name);\n            assert_eq!(count, 0);\n\n            let propose_option_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"ProposeOption\"))\n                .expect(\"no ProposeOption event was emitted\");\n            assert_eq!(propose_option_event.event, \"ProposeOption\");\n            assert_eq!(propose_option_event.args[0].clone().into_string().unwrap(), option_name);\n        }\n\n        #[ink::test]\n        fn test_vote() {\n            let mut voting = Voting::new();\n            let option_name = \"Option 1\".to_string();\n            voting.propose_option(option_name.clone());\n\n            let voter = AccountId::from([0x1; 32]);\n            let vote_success = voting.vote(0, {ink_env::test::Call::new(voter, 0)});\n            assert!(vote_success);\n            assert!(voting.has_voted(voter));\n            assert_eq!(voting.get_option_count(0), 1);\n\n            let vote_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"Vote\"))\n                .expect(\"no Vote event was emitted\");\n            assert_eq!(vote_event.event, \"Vote\");\n            assert_eq!(vote_event.args[0].clone().into_account_id(), Some(voter));\n            assert_eq!(vote_event.args[1].clone().into_u32(), 0);\n        }\n\n        #[ink::test]\n        fn test_vote_twice() {\n            let mut voting = Voting::new();\n            let option_name = \"Option 1\".to_string();\n            voting.propose_option(option_name.clone());\n\n            let voter = AccountId::from([0x1; 32]);\n            let vote_success = voting.vote(0, {ink_env::test::Call::new(voter, 0)});\n            assert!(vote_success);\n            assert!(voting.has_voted(voter));\n            assert_eq!(voting.get_option_count(0), 1);\n\n            let vote_success = voting.vote(0, {ink_env::test::Call::new(voter, 0)});\n            assert!(!vote_success);\n            assert!(voting.has_voted(voter));\n            assert_eq!(voting.get_option_count(0), 1);\n\n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.len(), 1);\n        }\n\n        #[ink::test]\n        fn test_get_results() {\n            let mut voting = Voting::new();\n            let option_name_1 = \"Option 1\".to_string();\n            let option_name_2 = \"Option 2\".to_string();\n            voting.propose_option(option_name_1.clone());\n            voting.propose_option(option_name_2.clone());\n\n            let voter_1 = AccountId::from([0x1; 32]);\n            let voter_2 = AccountId::from([0x2; 32]);\n            let voter_3 = AccountId::from([0x3; 32]);\n            voting.vote(1, {ink_env::test::Call::new(voter_1, 0)});\n            voting.vote(1, {ink_env::test::Call::new(voter_2, 0)});\n            voting.vote(1, {ink_env::test::Call::new(voter_3, 0)});\n            voting.vote(1, {ink_env::test::Call::new(voter_3, 0)});\n            voting.vote(1, {ink_env::test::Call::new(voter_3, 0)});\n\n            let results = voting.get_results();\n            assert_eq!(results.len(), 2);\n            let (name_1, count_1) = results[0];\n            let (name_2, count_2) = results[1];\n            assert_eq!(name_1, option_name_1);\n            assert_eq!(name_2, option_name_2);\n            assert_eq!(count_1, 1);\n            assert_eq!(count_2, 4);\n\n            let get_results_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"GetResults\"))\n                .expect(\"no GetResults event was emitted\");\n            assert_eq!(get_results_event.event, \"GetResults\");\n            assert_eq!(get_results_event.args[0].clone().into_vec().unwrap().len(), 2);\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "voting"]}');
+VALUES ('5da2b7da-d703-11ed-afa1-0242ac120002', 'voting', 'Voting', 'Create a voting smart contract run by a chairperson, who proposes the options before voting opens. A poll has at most 16 options, each named with at most 64 bytes. Votes are accepted between two block numbers set at deployment, and once voting closed anyone can finalize the poll, which picks a winner and emits the results. Implement propose_option, vote, finalize and get_results functions. The contract should prevent users from voting more than once and reject votes outside the voting period with typed errors. A poll can also be token-weighted with `Weighting::Registered`: it keeps a registry of token balances that the chairperson mints into and holders transfer with `transfer`, and each vote counts with the voter''s balance when voting opened, so tokens transferred during the vote cannot be counted twice and accounts that held no tokens then cannot vote. While voting is open, voters can change their vote or delegate their weight to another account, following delegation chains and rejecting cycles; get_vote tells which ballot an account''s weight counts for. The tally is chosen at deployment: plurality, quadratic voting where each vote costs its square out of a credit budget, or instant-runoff over ranked ballots cast by at most 256 accounts, all breaking ties in favor of the earliest proposed option. A poll deployed with a reveal deadline is secret: voters commit to the hash of their ballot and a salt while voting is open, then reveal them after voting closes, and only revealed ballots are counted.', 'med', 40, 4.2, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::voting::{\n    Ballot, CastBallot, ChangeBallot, ChangeVote, CommitVote, Delegate, Error, GetResults,\n    ProposeOption, Tally, Transfer, Vote, Voter, Voting, Weighting, MAX_OPTIONS,\n    MAX_OPTION_NAME_LEN, MAX_RUNOFF_VOTERS,\n};\n\n#[ink::contract]\nmod voting {\n    use ink::env::hash::Blake2x256;\n    use ink::prelude::string::String;\n    use ink::prelude::vec;\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    #[ink(storage)]\n    pub struct Voting {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl Voting {\n        #[ink(constructor)]\n        pub fn new(voting_start: BlockNumber, voting_end: BlockNumber, weighting: Weighting, tally: Tally, reveal_end: Option<BlockNumber>) -> Result<Self, Error> {\n            // TODO, add your code here\n        }\n\n        // TODO, add your messages here\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::voting::{\n    Ballot, CastBallot, ChangeBallot, ChangeVote, CommitVote, Delegate, Error, GetResults,\n    ProposeOption, Tally, Transfer, Vote, Voter, Voting, Weighting, MAX_OPTIONS,\n    MAX_OPTION_NAME_LEN, MAX_RUNOFF_VOTERS,\n};\n\n#[ink::contract]\nmod voting {\n    use ink::env::hash::Blake2x256;\n    use ink::prelude::string::String;\n    use ink::prelude::vec;\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    /// The most options a poll may have, all of them are stored in a single cell.\n    pub const MAX_OPTIONS: u32 = 16;\n    /// The longest option name in bytes.\n    pub const MAX_OPTION_NAME_LEN: u32 = 64;\n    /// The most accounts that may cast a ballot of their own in an instant runoff\n    /// poll, as finalizing it loads all of their ballots.\n    pub const MAX_RUNOFF_VOTERS: u32 = 256;\n\n    /// A poll run by its deployer, the chairperson.\n    ///\n    /// The chairperson proposes the options before `voting_start`. Votes are accepted\n    /// from block `voting_start` up to, but excluding, block `voting_end`, after which\n    /// anyone can finalize the poll. While voting is open, a voter can change their\n    /// vote or delegate their weight to another account instead of voting.\n    ///\n    /// A poll deployed with a `reveal_end` is secret: voters only commit to a hash of\n    /// their ballot while voting is open, and reveal the ballot from `voting_end` up to\n    /// `reveal_end`. Ballots that are not revealed in time are not counted.\n    ///\n    /// An instant runoff poll accepts ballots from at most `MAX_RUNOFF_VOTERS`\n    /// accounts, so that finalizing it stays within a single call. Further accounts\n    /// can still delegate their weight to one of them.\n    ///\n    /// A weighted poll keeps a registry of token balances, which the chairperson mints\n    /// and the holders transfer at any time. A vote counts with the voter''s balance as\n    /// it was when voting started, so tokens transferred during the vote count once.\n    #[ink(storage)]\n    pub struct Voting {\n        chairperson: AccountId,\n        /// Each option with its count, see [`Tally`] for what is counted.\n        options: Vec<(String, Balance)>,\n        voters: Mapping<AccountId, Voter>,\n        weighting: Weighting,\n        /// The token balance of each account in a weighted poll.\n        balances: Mapping<AccountId, Balance>,\n        /// The balance an account held when voting started, recorded the first time it\n        /// changes afterwards. Accounts without one still hold that balance.\n        snapshots: Mapping<AccountId, Balance>,\n        total_supply: Balance,\n        tally: Tally,\n        /// The accounts that cast a ballot of their own in an instant runoff poll, to\n        /// run the rounds from when it is finalized, indexed in the order they voted.\n        ballot_holders: Mapping<u32, AccountId>,\n        ballot_holder_count: u32,\n        voting_start: BlockNumber,\n        voting_end: BlockNumber,\n        reveal_end: Option<BlockNumber>,\n        /// The committed hashes of the ballots in a secret poll, until they are revealed.\n        commitments: Mapping<AccountId, Hash>,\n        finalized: bool,\n        /// The index of the winning option, once finalized.\n        winner: Option<u32>,\n    }\n\n    /// How much a vote counts.\n    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub enum Weighting {\n        /// Every account''s vote counts as 1.\n        #[default]\n        OnePerAccount,\n        /// Votes count with the account''s balance in the poll''s token registry when\n        /// voting started, accounts without one cannot vote.\n        Registered,\n    }\n\n    /// How the ballots are counted and the winner is picked. Ties always go to the\n    /// earliest proposed option.\n    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub enum Tally {\n        /// Voters cast a [`Ballot::Choice`] and the option with the most weight wins.\n        #[default]\n        Plurality,\n        /// Voters cast [`Ballot::Votes`], paying the square of the votes they give an\n        /// option out of a budget of `credits` per unit of weight. The option with the\n        /// most votes wins.\n        Quadratic { credits: Balance },\n        /// Voters cast a [`Ballot::Ranking`]. The options count the weight of the\n        /// ballots ranking them first, and the last one is eliminated until an option\n        /// holds a majority of the ballots that still rank one of the remaining options.\n        InstantRunoff,\n    }\n\n    /// What a voter votes for, which must match the [`Tally`] of the poll.\n    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub enum Ballot {\n        /// The index of a single option.\n        Choice(u32),\n        /// The number of votes given to each option, by option index. Options past\n        /// the end of the list get none.\n        Votes(Vec<u32>),\n        /// Option indices in order of preference. Options left out are not ranked.\n        Ranking(Vec<u32>),\n    }\n\n    /// What an account did with its vote.\n    #[derive(Debug, Default, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub struct Voter {\n        /// The weight other accounts delegated to this one, counted with its own weight\n        /// when it votes.\n        pub delegated_weight: Balance,\n        /// The ballot this account cast itself.\n        pub ballot: Option<Ballot>,\n        /// The account this one delegated its weight to, instead of voting.\n        pub delegate: Option<AccountId>,\n    }\n\n    #[ink(event)]\n    pub struct ProposeOption {\n        #[ink(topic)]\n        pub name: String,\n    }\n\n    #[ink(event)]\n    pub struct Vote {\n        #[ink(topic)]\n        pub voter: AccountId,\n        #[ink(topic)]\n        pub option_index: u32,\n        pub weight: Balance,\n    }\n\n    #[ink(event)]\n    pub struct ChangeVote {\n        #[ink(topic)]\n        pub voter: AccountId,\n        pub from_option: u32,\n        pub to_option: u32,\n        pub weight: Balance,\n    }\n\n    #[ink(event)]\n    pub struct CastBallot {\n        #[ink(topic)]\n        pub voter: AccountId,\n        pub ballot: Ballot,\n        pub weight: Balance,\n    }\n\n    #[ink(event)]\n    pub struct ChangeBallot {\n        #[ink(topic)]\n        pub voter: AccountId,\n        /// The new ballot.\n        pub ballot: Ballot,\n        pub weight: Balance,\n    }\n\n    /// Emitted when a voter commits to a ballot in a secret poll, which is emitted as\n    /// [`CastBallot`] once revealed.\n    #[ink(event)]\n    pub struct CommitVote {\n        #[ink(topic)]\n        pub voter: AccountId,\n    }\n\n    #[ink(event)]\n    pub struct Delegate {\n        #[ink(topic)]\n        pub delegator: AccountId,\n        /// The end of the delegation chain, which received the weight.\n        #[ink(topic)]\n        pub delegate: AccountId,\n        pub weight: Balance,\n    }\n\n    /// Emitted when tokens of a weighted poll are minted, with no `from`, or\n    /// transferred.\n    #[ink(event)]\n    pub struct Transfer {\n        #[ink(topic)]\n        pub from: Option<AccountId>,\n        #[ink(topic)]\n        pub to: Option<AccountId>,\n        pub value: Balance,\n    }\n\n    /// Emitted once, when the poll is finalized.\n    #[ink(event)]\n    pub struct GetResults {\n        pub results: Vec<(String, Balance)>,\n        pub winner: Option<u32>,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        /// Only the chairperson may propose options and mint tokens.\n        Unauthorized,\n        /// `voting_start` must come before `voting_end`, and `voting_end` before\n        /// `reveal_end`.\n        InvalidPeriod,\n        /// Options can only be proposed before voting starts.\n        ProposalsClosed,\n        /// The poll already has `MAX_OPTIONS` options.\n        TooManyOptions,\n        /// The option name is longer than `MAX_OPTION_NAME_LEN` bytes.\n        NameTooLong,\n        /// Only a weighted poll keeps a token registry.\n        NotRegistered,\n        InsufficientBalance,\n        /// The caller held no tokens when voting started in a weighted poll and has no\n        /// weight delegated to it.\n        NoVotingPower,\n        VotingNotStarted,\n        VotingClosed,\n        /// The caller already voted or delegated.\n        AlreadyVoted,\n        /// The caller has no vote of its own to change, or no commitment to reveal.\n        NotVoted,\n        /// The delegation chain would lead back to the caller.\n        DelegationCycle,\n        OptionNotFound,\n        /// The ballot does not match the tally of the poll, votes for nothing or ranks\n        /// an option twice.\n        InvalidBallot,\n        /// The quadratic cost of the votes exceeds the caller''s credits.\n        InsufficientCredits,\n        /// Ballots of a secret poll must be committed and revealed.\n        SecretPoll,\n        /// Only ballots of a secret poll are committed and revealed.\n        PublicPoll,\n        /// Ballots are revealed once voting ended.\n        RevealNotStarted,\n        RevealClosed,\n        /// The revealed ballot and salt do not hash to the caller''s commitment.\n        CommitmentMismatch,\n        /// A count, a delegated weight or the token supply would exceed `Balance::MAX`.\n        Overflow,\n        /// `MAX_RUNOFF_VOTERS` accounts already cast a ballot in the instant runoff\n        /// poll.\n        TooManyVoters,\n        /// The poll can only be finalized once voting ended.\n        VotingNotEnded,\n        AlreadyFinalized,\n    }\n\n    impl Voting {\n        #[ink(constructor)]\n        pub fn new(\n            voting_start: BlockNumber,\n            voting_end: BlockNumber,\n            weighting: Weighting,\n            tally: Tally,\n            reveal_end: Option<BlockNumber>,\n        ) -> Result<Self, Error> {\n            if voting_start >= voting_end || reveal_end.is_some_and(|end| voting_end >= end) {\n                return Err(Error::InvalidPeriod);\n            }\n            Ok(Self {\n                chairperson: Self::env().caller(),\n                options: Vec::new(),\n                voters: Mapping::new(),\n                weighting,\n                balances: Mapping::new(),\n                snapshots: Mapping::new(),\n                total_supply: 0,\n                tally,\n                ballot_holders: Mapping::new(),\n                ballot_holder_count: 0,\n                voting_start,\n                voting_end,\n                reveal_end,\n                commitments: Mapping::new(),\n                finalized: false,\n                winner: None,\n            })\n        }\n\n        #[ink(message)]\n        pub fn propose_option(&mut self, name: String) -> Result<(), Error> {\n            if self.env().caller() != self.chairperson {\n                return Err(Error::Unauthorized);\n            }\n            if self.env().block_number() >= self.voting_start {\n                return Err(Error::ProposalsClosed);\n            }\n            if self.options.len() >= MAX_OPTIONS as usize {\n                return Err(Error::TooManyOptions);\n            }\n            if name.len() > MAX_OPTION_NAME_LEN as usize {\n                return Err(Error::NameTooLong);\n            }\n            self.options.push((name.clone(), 0));\n            self.env().emit_event(ProposeOption { name });\n            Ok(())\n        }\n\n        /// Mints `value` tokens of a weighted poll to `to`, called by the chairperson.\n        #[ink(message)]\n        pub fn mint(&mut self, to: AccountId, value: Balance) -> Result<(), Error> {\n            if self.env().caller() != self.chairperson {\n                return Err(Error::Unauthorized);\n            }\n            if self.weighting != Weighting::Registered {\n                return Err(Error::NotRegistered);\n            }\n            // Checked once here, so that no balance or sum of weights can overflow.\n            self.total_supply = self\n                .total_supply\n                .checked_add(value)\n                .ok_or(Error::Overflow)?;\n            self.set_balance(to, self.balance_of(to) + value);\n            self.env().emit_event(Transfer {\n                from: None,\n                to: Some(to),\n                value,\n            });\n            Ok(())\n        }\n\n        /// Transfers `value` of the caller''s tokens of a weighted poll to `to`. Once\n        /// voting started, this no longer changes the weight of either account.\n        #[ink(message)]\n        pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<(), Error> {\n            if self.weighting != Weighting::Registered {\n                return Err(Error::NotRegistered);\n            }\n            let from = self.env().caller();\n            let from_balance = self.balance_of(from);\n            if from_balance < value {\n                return Err(Error::InsufficientBalance);\n            }\n            self.set_balance(from, from_balance - value);\n            self.set_balance(to, self.balance_of(to) + value);\n            self.env().emit_event(Transfer {\n                from: Some(from),\n                to: Some(to),\n                value,\n            });\n            Ok(())\n        }\n\n        /// Votes for `option_index` in a plurality poll, with the caller''s own weight\n        /// and the weight delegated to it.\n        #[ink(message)]\n        pub fn vote(&mut self, option_index: u32) -> Result<(), Error> {\n            self.ensure_public_voting_open()?;\n            let weight = self.cast(Ballot::Choice(option_index))?;\n            self.env().emit_event(Vote {\n                voter: self.env().caller(),\n                option_index,\n                weight,\n            });\n            Ok(())\n        }\n\n        /// Moves the caller''s vote in a plurality poll, with all the weight delegated\n        /// to it, to `option_index`.\n        #[ink(message)]\n        pub fn change_vote(&mut self, option_index: u32) -> Result<(), Error> {\n            self.ensure_public_voting_open()?;\n            let (previous, weight) = self.change(Ballot::Choice(option_index))?;\n            let Ballot::Choice(from_option) = previous else {\n                unreachable!(\"a plurality poll only holds choices\");\n            };\n            self.env().emit_event(ChangeVote {\n                voter: self.env().caller(),\n                from_option,\n                to_option: option_index,\n                weight,\n            });\n            Ok(())\n        }\n\n        /// Casts a ballot of the kind the poll''s [`Tally`] counts.\n        #[ink(message)]\n        pub fn cast_ballot(&mut self, ballot: Ballot) -> Result<(), Error> {\n            self.ensure_public_voting_open()?;\n            let weight = self.cast(ballot.clone())?;\n            self.env().emit_event(CastBallot {\n                voter: self.env().caller(),\n                ballot,\n                weight,\n            });\n            Ok(())\n        }\n\n        /// Replaces the caller''s ballot.\n        #[ink(message)]\n        pub fn change_ballot(&mut self, ballot: Ballot) -> Result<(), Error> {\n            self.ensure_public_voting_open()?;\n            let (_, weight) = self.change(ballot.clone())?;\n            self.env().emit_event(ChangeBallot {\n                voter: self.env().caller(),\n                ballot,\n                weight,\n            });\n            Ok(())\n        }\n\n        /// Commits the caller to a ballot in a secret poll, replacing its previous\n        /// commitment. `commitment` is the Blake2x256 hash of the SCALE encoded\n        /// `(ballot, salt)`, with a random 32 byte salt kept until the reveal.\n        #[ink(message)]\n        pub fn commit_vote(&mut self, commitment: Hash) -> Result<(), Error> {\n            if self.reveal_end.is_none() {\n                return Err(Error::PublicPoll);\n            }\n            self.ensure_voting_open()?;\n            let sender = self.env().caller();\n            if self\n                .voters\n                .get(sender)\n                .unwrap_or_default()\n                .delegate\n                .is_some()\n            {\n                return Err(Error::AlreadyVoted);\n            }\n            self.commitments.insert(sender, &commitment);\n            self.env().emit_event(CommitVote { voter: sender });\n            Ok(())\n        }\n\n        /// Reveals the ballot the caller committed to, which is then counted as if it\n        /// was cast in a public poll.\n        #[ink(message)]\n        pub fn reveal_vote(&mut self, ballot: Ballot, salt: [u8; 32]) -> Result<(), Error> {\n            let Some(reveal_end) = self.reveal_end else {\n                return Err(Error::PublicPoll);\n            };\n            let block = self.env().block_number();\n            if block < self.voting_end {\n                return Err(Error::RevealNotStarted);\n            }\n            if block >= reveal_end {\n                return Err(Error::RevealClosed);\n            }\n            let sender = self.env().caller();\n            let commitment = self.commitments.get(sender).ok_or(Error::NotVoted)?;\n            let hash = self.env().hash_encoded::<Blake2x256, _>(&(&ballot, salt));\n            if Hash::from(hash) != commitment {\n                return Err(Error::CommitmentMismatch);\n            }\n            let weight = self.cast(ballot.clone())?;\n            self.commitments.remove(sender);\n            self.env().emit_event(CastBallot {\n                voter: sender,\n                ballot,\n                weight,\n            });\n            Ok(())\n        }\n\n        /// Hands the caller''s weight, including the weight delegated to it, to `to`.\n        ///\n        /// The weight goes to the end of `to`''s delegation chain. It is added to that\n        /// account''s ballot right away if it already voted, and follows its ballot when\n        /// it changes. In a quadratic poll the weight adds to the delegate''s credits\n        /// instead, which it can spend by changing its ballot. In a secret poll the weight\n        /// counts with the delegate''s ballot once it is revealed.\n        #[ink(message)]\n        pub fn delegate(&mut self, to: AccountId) -> Result<(), Error> {\n            self.ensure_voting_open()?;\n            let sender = self.env().caller();\n            let mut voter = self.voters.get(sender).unwrap_or_default();\n            if voter.ballot.is_some()\n                || voter.delegate.is_some()\n                || self.commitments.contains(sender)\n            {\n                return Err(Error::AlreadyVoted);\n            }\n            let mut delegate = to;\n            let mut delegate_voter = loop {\n                if delegate == sender {\n                    return Err(Error::DelegationCycle);\n                }\n                let next = self.voters.get(delegate).unwrap_or_default();\n                match next.delegate {\n                    Some(account) => delegate = account,\n                    None => break next,\n                }\n            };\n            let weight = self.voting_power(sender, &voter)?;\n            delegate_voter.delegated_weight = delegate_voter\n                .delegated_weight\n                .checked_add(weight)\n                .ok_or(Error::Overflow)?;\n            match &delegate_voter.ballot {\n                Some(Ballot::Votes(_)) | None => {}\n                Some(ballot) => self.add_counts(&Self::counts(ballot, weight))?,\n            }\n            self.voters.insert(delegate, &delegate_voter);\n            voter.delegate = Some(delegate);\n            self.voters.insert(sender, &voter);\n            self.env().emit_event(Delegate {\n                delegator: sender,\n                delegate,\n                weight,\n            });\n            Ok(())\n        }\n\n        /// Closes the poll and picks the winner as the poll''s [`Tally`] describes.\n        /// There is no winner if nobody voted. A secret poll is finalized once the\n        /// reveal ended, ignoring the commitments that were not revealed.\n        #[ink(message)]\n        pub fn finalize(&mut self) -> Result<(), Error> {\n            if self.finalized {\n                return Err(Error::AlreadyFinalized);\n            }\n            if self.env().block_number() < self.reveal_end.unwrap_or(self.voting_end) {\n                return Err(Error::VotingNotEnded);\n            }\n            self.winner = match self.tally {\n                Tally::Plurality | Tally::Quadratic { .. } => self.most_counted(),\n                Tally::InstantRunoff => self.instant_runoff(),\n            };\n            self.finalized = true;\n            self.env().emit_event(GetResults {\n                results: self.options.clone(),\n                winner: self.winner,\n            });\n            Ok(())\n        }\n\n        /// Returns whether `voter` voted or delegated its weight.\n        #[ink(message)]\n        pub fn has_voted(&self, voter: AccountId) -> bool {\n            let voter = self.voters.get(voter).unwrap_or_default();\n            voter.ballot.is_some() || voter.delegate.is_some()\n        }\n\n        /// Returns the ballot `account`''s weight counts for, following its delegation\n        /// chain, or `None` if neither it nor its delegate voted yet.\n        #[ink(message)]\n        pub fn get_vote(&self, account: AccountId) -> Option<Ballot> {\n            let mut voter = self.voters.get(account).unwrap_or_default();\n            while let Some(delegate) = voter.delegate {\n                voter = self.voters.get(delegate).unwrap_or_default();\n            }\n            voter.ballot\n        }\n\n        /// Returns the commitment `account` has yet to reveal in a secret poll.\n        #[ink(message)]\n        pub fn get_commitment(&self, account: AccountId) -> Option<Hash> {\n            self.commitments.get(account)\n        }\n\n        #[ink(message)]\n        pub fn get_voter(&self, account: AccountId) -> Voter {\n            self.voters.get(account).unwrap_or_default()\n        }\n\n        /// Returns how much a vote by `account` counts, not including the weight\n        /// delegated to it. In a weighted poll this is its current balance until voting\n        /// starts, and its balance at that point from then on.\n        #[ink(message)]\n        pub fn get_weight(&self, account: AccountId) -> Balance {\n            match self.weighting {\n                Weighting::OnePerAccount => 1,\n                Weighting::Registered => self\n                    .snapshots\n                    .get(account)\n                    .unwrap_or_else(|| self.balance_of(account)),\n            }\n        }\n\n        /// Returns the current token balance of `account` in a weighted poll.\n        #[ink(message)]\n        pub fn balance_of(&self, account: AccountId) -> Balance {\n            self.balances.get(account).unwrap_or(0)\n        }\n\n        #[ink(message)]\n        pub fn total_supply(&self) -> Balance {\n            self.total_supply\n        }\n\n        #[ink(message)]\n        pub fn get_weighting(&self) -> Weighting {\n            self.weighting\n        }\n\n        #[ink(message)]\n        pub fn get_tally(&self) -> Tally {\n            self.tally\n        }\n\n        /// Returns the credits `account` can spend on a quadratic ballot, its weight and\n        /// the weight delegated to it times the credits per unit of weight. It is 0 in\n        /// the other tallies.\n        #[ink(message)]\n        pub fn get_credits(&self, account: AccountId) -> Balance {\n            let Tally::Quadratic { credits } = self.tally else {\n                return 0;\n            };\n            let voter = self.voters.get(account).unwrap_or_default();\n            self.get_weight(account)\n                .saturating_add(voter.delegated_weight)\n                .saturating_mul(credits)\n        }\n\n        #[ink(message)]\n        pub fn get_option_count(&self, option_index: u32) -> Balance {\n            if let Some((_, count)) = self.options.get(option_index as usize) {\n                *count\n            } else {\n                0\n            }\n        }\n\n        #[ink(message)]\n        pub fn get_options(&self) -> Vec<(String, Balance)> {\n            self.options.clone()\n        }\n\n        /// Returns the current counts, while voting is still going on as well. In an\n        /// instant runoff poll these are the first preferences.\n        #[ink(message)]\n        pub fn get_results(&self) -> Vec<(String, Balance)> {\n            self.options.clone()\n        }\n\n        /// Returns the index of the winning option, `None` until the poll is finalized\n        /// or if nobody voted.\n        #[ink(message)]\n        pub fn get_winner(&self) -> Option<u32> {\n            self.winner\n        }\n\n        #[ink(message)]\n        pub fn is_finalized(&self) -> bool {\n            self.finalized\n        }\n\n        #[ink(message)]\n        pub fn get_chairperson(&self) -> AccountId {\n            self.chairperson\n        }\n\n        /// Returns `(voting_start, voting_end)`.\n        #[ink(message)]\n        pub fn get_voting_period(&self) -> (BlockNumber, BlockNumber) {\n            (self.voting_start, self.voting_end)\n        }\n\n        /// Returns the block the reveal of a secret poll ends at, `None` for a public\n        /// poll.\n        #[ink(message)]\n        pub fn get_reveal_end(&self) -> Option<BlockNumber> {\n            self.reveal_end\n        }\n\n        /// Records the caller''s first ballot and returns the weight it counts with.\n        fn cast(&mut self, ballot: Ballot) -> Result<Balance, Error> {\n            let sender = self.env().caller();\n            let mut voter = self.voters.get(sender).unwrap_or_default();\n            if voter.ballot.is_some() || voter.delegate.is_some() {\n                return Err(Error::AlreadyVoted);\n            }\n            let weight = self.voting_power(sender, &voter)?;\n            self.check_ballot(&ballot, weight)?;\n            let ballot_holder_count = self\n                .ballot_holder_count\n                .checked_add(1)\n                .ok_or(Error::Overflow)?;\n            if self.tally == Tally::InstantRunoff && ballot_holder_count > MAX_RUNOFF_VOTERS {\n                return Err(Error::TooManyVoters);\n            }\n            self.add_counts(&Self::counts(&ballot, weight))?;\n            if self.tally == Tally::InstantRunoff {\n                self.ballot_holders\n                    .insert(self.ballot_holder_count, &sender);\n                self.ballot_holder_count = ballot_holder_count;\n            }\n            voter.ballot = Some(ballot);\n            self.voters.insert(sender, &voter);\n            Ok(weight)\n        }\n\n        /// Replaces the caller''s ballot and returns the previous one with the weight\n        /// both count with.\n        fn change(&mut self, ballot: Ballot) -> Result<(Ballot, Balance), Error> {\n            let sender = self.env().caller();\n            let mut voter = self.voters.get(sender).unwrap_or_default();\n            let previous = voter.ballot.take().ok_or(Error::NotVoted)?;\n            let weight = self.voting_power(sender, &voter)?;\n            self.check_ballot(&ballot, weight)?;\n            let previous_counts = Self::counts(&previous, weight);\n            for (option_index, amount) in &previous_counts {\n                // Cannot underflow, the same amount was added when the ballot was cast\n                // or the weight delegated.\n                self.options[*option_index as usize].1 -= amount;\n            }\n            if let Err(err) = self.add_counts(&Self::counts(&ballot, weight)) {\n                for (option_index, amount) in &previous_counts {\n                    let count = &mut self.options[*option_index as usize].1;\n                    // Cannot saturate, the amount was subtracted above.\n                    *count = count.saturating_add(*amount);\n                }\n                return Err(err);\n            }\n            voter.ballot = Some(ballot);\n            self.voters.insert(sender, &voter);\n            Ok((previous, weight))\n        }\n\n        fn ensure_public_voting_open(&self) -> Result<(), Error> {\n            if self.reveal_end.is_some() {\n                return Err(Error::SecretPoll);\n            }\n            self.ensure_voting_open()\n        }\n\n        fn ensure_voting_open(&self) -> Result<(), Error> {\n            let block = self.env().block_number();\n            if block < self.voting_start {\n                return Err(Error::VotingNotStarted);\n            }\n            if block >= self.voting_end {\n                return Err(Error::VotingClosed);\n            }\n            Ok(())\n        }\n\n        /// Records the balance `account` held when voting started before changing it for\n        /// the first time since.\n        fn set_balance(&mut self, account: AccountId, balance: Balance) {\n            if self.env().block_number() >= self.voting_start && !self.snapshots.contains(account) {\n                self.snapshots.insert(account, &self.balance_of(account));\n            }\n            self.balances.insert(account, &balance);\n        }\n\n        /// Returns the weight `account` votes or delegates with, its own and the weight\n        /// delegated to it.\n        fn voting_power(&self, account: AccountId, voter: &Voter) -> Result<Balance, Error> {\n            let weight = self\n                .get_weight(account)\n                .checked_add(voter.delegated_weight)\n                .ok_or(Error::Overflow)?;\n            if weight == 0 {\n                return Err(Error::NoVotingPower);\n            }\n            Ok(weight)\n        }\n\n        /// Checks that `ballot` can be counted by the poll''s tally for a voter with\n        /// `weight`.\n        fn check_ballot(&self, ballot: &Ballot, weight: Balance) -> Result<(), Error> {\n            let options = self.options.len();\n            match (self.tally, ballot) {\n                (Tally::Plurality, Ballot::Choice(option_index)) => {\n                    if *option_index as usize >= options {\n                        return Err(Error::OptionNotFound);\n                    }\n                }\n                (Tally::Quadratic { credits }, Ballot::Votes(votes)) => {\n                    if votes.len() > options {\n                        return Err(Error::OptionNotFound);\n                    }\n                    if votes.iter().all(|votes| *votes == 0) {\n                        return Err(Error::InvalidBallot);\n                    }\n                    // A cost past `Balance::MAX` exceeds any budget as well.\n                    let cost = votes\n                        .iter()\n                        .try_fold(0, |cost: Balance, votes| {\n                            cost.checked_add(Balance::from(*votes).pow(2))\n                        })\n                        .ok_or(Error::InsufficientCredits)?;\n                    if cost > weight.saturating_mul(credits) {\n                        return Err(Error::InsufficientCredits);\n                    }\n                }\n                (Tally::InstantRunoff, Ballot::Ranking(ranking)) => {\n                    if ranking.is_empty() {\n                        return Err(Error::InvalidBallot);\n                    }\n                    if ranking\n                        .iter()\n                        .any(|option_index| *option_index as usize >= options)\n                    {\n                        return Err(Error::OptionNotFound);\n                    }\n                    for (position, option_index) in ranking.iter().enumerate() {\n                        if ranking[..position].contains(option_index) {\n                            return Err(Error::InvalidBallot);\n                        }\n                    }\n                }\n                _ => return Err(Error::InvalidBallot),\n            }\n            Ok(())\n        }\n\n        /// Returns what a checked `ballot` adds to each option''s count. Quadratic votes\n        /// do not scale with the weight, which only buys credits.\n        fn counts(ballot: &Ballot, weight: Balance) -> Vec<(u32, Balance)> {\n            match ballot {\n                Ballot::Choice(option_index) => vec![(*option_index, weight)],\n                Ballot::Votes(votes) => votes\n                    .iter()\n                    .enumerate()\n                    .filter(|(_, votes)| **votes > 0)\n                    .map(|(option_index, votes)| (option_index as u32, Balance::from(*votes)))\n                    .collect(),\n                Ballot::Ranking(ranking) => vec![(ranking[0], weight)],\n            }\n        }\n\n        /// Adds `counts` to the options, all of them or none if one would overflow.\n        fn add_counts(&mut self, counts: &[(u32, Balance)]) -> Result<(), Error> {\n            for (option_index, amount) in counts {\n                self.options[*option_index as usize]\n                    .1\n                    .checked_add(*amount)\n                    .ok_or(Error::Overflow)?;\n            }\n            for (option_index, amount) in counts {\n                let count = &mut self.options[*option_index as usize].1;\n                // Cannot saturate, checked above.\n                *count = count.saturating_add(*amount);\n            }\n            Ok(())\n        }\n\n        /// Returns the option with the highest count, the earliest proposed one on a\n        /// tie.\n        fn most_counted(&self) -> Option<u32> {\n            let mut winner: Option<(u32, Balance)> = None;\n            for (index, (_, count)) in self.options.iter().enumerate() {\n                if *count > winner.map_or(0, |(_, most)| most) {\n                    winner = Some((index as u32, *count));\n                }\n            }\n            winner.map(|(index, _)| index)\n        }\n\n        /// Runs the instant runoff rounds. Of the options tied for the fewest ballots,\n        /// the latest proposed one is eliminated, so that ties go to the earliest.\n        fn instant_runoff(&self) -> Option<u32> {\n            let ballots: Vec<(Vec<u32>, Balance)> = (0..self.ballot_holder_count)\n                .filter_map(|index| {\n                    let account = self.ballot_holders.get(index)?;\n                    let voter = self.voters.get(account)?;\n                    let Some(Ballot::Ranking(ranking)) = voter.ballot else {\n                        return None;\n                    };\n                    let weight = self\n                        .get_weight(account)\n                        .saturating_add(voter.delegated_weight);\n                    Some((ranking, weight))\n                })\n                .collect();\n            let mut eliminated = vec![false; self.options.len()];\n            loop {\n                // Saturating, as the weights of a poll stay far below `Balance::MAX`\n                // in total.\n                let mut tallies: Vec<Balance> = vec![0; self.options.len()];\n                let mut total: Balance = 0;\n                for (ranking, weight) in &ballots {\n                    if let Some(option_index) = ranking\n                        .iter()\n                        .find(|option_index| !eliminated[**option_index as usize])\n                    {\n                        let tally = &mut tallies[*option_index as usize];\n                        *tally = tally.saturating_add(*weight);\n                        total = total.saturating_add(*weight);\n                    }\n                }\n                if total == 0 {\n                    return None;\n                }\n                if let Some(index) = tallies.iter().position(|tally| *tally > total - *tally) {\n                    return Some(index as u32);\n                }\n                let last = (0..tallies.len())\n                    .rev()\n                    .filter(|index| !eliminated[*index])\n                    .min_by_key(|index| tallies[*index])?;\n                eliminated[last] = true;\n            }\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "voting"]}');
 
 INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
-VALUES ('97bca69a-d703-11ed-afa1-0242ac120002', 'escrow', 'Escrow', 'Create an escrow smart contract that allows two parties to lock funds and release them upon agreement. Implement lock_funds, release_funds, and refund_funds functions.', 'hard', 30, 4.8, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod escrow {\n    #[ink(storage)]\n    pub struct Escrow {\n        seller: AccountId,\n        buyer: AccountId,\n        amount: Balance,\n        status: EscrowStatus,\n    }\n    // Add your implementation here\n    \n    #[cfg(test)]\n    mod tests {\n        use super::*;\n    \n        use ink_lang as ink;\n    \n        #[ink::test]\n        fn test_lock_funds() {\n            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);\n            assert_eq!(escrow.get_status(), EscrowStatus::Locked);\n    \n            let lock_funds_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"LockFunds\"))\n                .expect(\"no LockFunds event was emitted\");\n            assert_eq!(lock_funds_event.event, \"LockFunds\");\n            assert_eq!(lock_funds_event.args[0].clone().into_account_id(), AccountId::from([0x1; 32]));\n            assert_eq!(lock_funds_event.args[1].clone().into_account_id(), AccountId::from([0x2; 32]));\n            assert_eq!(lock_funds_event.args[2].clone().into_balance(), 100);\n        }\n    \n        #[ink::test]\n        fn test_release_funds() {\n            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);\n            escrow.release_funds();\n            assert_eq!(escrow.get_status(), EscrowStatus::Released);\n    \n            let release_funds_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"ReleaseFunds\"))\n                .expect(\"no ReleaseFunds event was emitted\");\n            assert_eq!(release_funds_event.event, \"ReleaseFunds\");\n            assert_eq!(release_funds_event.args[0].clone().into_account_id(), AccountId::from([0x1; 32]));\n            assert_eq!(release_funds_event.args[1].clone().into_account_id(), AccountId::from([0x2; 32]));\n            assert_eq!(release_funds_event.args[2].clone().into_balance(), 100);\n        }\n    \n        #[ink::test]\n        fn test_refund_funds() {\n            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);\n            escrow.refund_funds();\n            assert_eq!(escrow.get_status(), EscrowStatus::Refunded);\n    \n            let refund_funds_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"RefundFunds\"))\n                .expect(\"no RefundFunds event was emitted\");\n            assert_eq!(refund_funds_event.event, \"RefundFunds\");\n            assert_eq!(refund_funds_event.args[0].clone().into_account_id(), AccountId::from([0x1; 32]));\n            assert_eq!(refund_funds_event.args[1].clone().into_account_id(), AccountId::from([0x2; 32]));\n            assert_eq!(refund_funds_event.args[2].clone().into_balance(), 100);\n        }\n    \n        #[ink::test]\n        fn test_release_funds_unauthorized() {\n            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);\n            assert_eq!(escrow.get_status(), EscrowStatus::Locked);\n    \n            // try to release funds from a non-buyer account\n            let unauthorized_caller = AccountId::from([0\n            // check that the status has not changed\n            assert_eq!(escrow.get_status(), EscrowStatus::Locked);\n        \n            // check that no events have been emitted\n            let emitted_events = ink_env::test::emitted_events();\n            assert!(emitted_events.iter().all(|event| event.event != \"ReleaseFunds\"));\n            assert!(emitted_events.iter().all(|event| event.event != \"RefundFunds\"));\n        }\n        \n        #[ink::test]\n        fn test_refund_funds_unauthorized() {\n            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);\n            assert_eq!(escrow.get_status(), EscrowStatus::Locked);\n        \n            // try to refund funds from a non-seller account\n            let unauthorized_caller = AccountId::from([0x3; 32]);\n            assert_eq!(\n                escrow.refund_funds_with_call(\n                    ink_env::test::Call::new(unauthorized_caller, 0)\n                ),\n                Err(Error::Unauthorized)\n            );\n        \n            // check that the status has not changed\n            assert_eq!(escrow.get_status(), EscrowStatus::Locked);\n        \n            // check that no events have been emitted\n            let emitted_events = ink_env::test::emitted_events();\n            assert!(emitted_events.iter().all(|event| event.event != \"ReleaseFunds\"));\n            assert!(emitted_events.iter().all(|event| event.event != \"RefundFunds\"));\n        }\n\n\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod escrow {\n    use ink_prelude::collections::HashMap;\n\n    #[ink(storage)]\n    pub struct Escrow {\n        seller: AccountId,\n        buyer: AccountId,\n        amount: Balance,\n        status: EscrowStatus,\n        authorized_callers: HashMap<AccountId, bool>,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum EscrowStatus {\n        Locked,\n        Released,\n        Refunded,\n    }\n\n    #[ink(event)]\n    pub struct LockFunds {\n        #[ink(topic)]\n        seller: AccountId,\n        #[ink(topic)]\n        buyer: AccountId,\n        #[ink(topic)]\n        amount: Balance,\n    }\n\n    #[ink(event)]\n    pub struct ReleaseFunds {\n        #[ink(topic)]\n        seller: AccountId,\n        #[ink(topic)]\n        buyer: AccountId,\n        #[ink(topic)]\n        amount: Balance,\n    }\n\n    #[ink(event)]\n    pub struct RefundFunds {\n        #[ink(topic)]\n        seller: AccountId,\n        #[ink(topic)]\n        buyer: AccountId,\n        #[ink(topic)]\n        amount: Balance,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        Unauthorized,\n    }\n\n    impl Escrow {\n        #[ink(constructor)]\n        pub fn new(seller: AccountId, buyer: AccountId, amount: Balance) -> Self {\n            Self {\n                seller,\n                buyer,\n                amount,\n                status: EscrowStatus::Locked,\n                authorized_callers: HashMap::new(),\n            }\n        }\n\n        #[ink(message)]\n        pub fn lock_funds(&mut self) {\n            self.env().emit_event(LockFunds {\n                seller: self.seller,\n                buyer: self.buyer,\n                amount: self.amount,\n            });\n        }\n\n        #[ink(message)]\n        pub fn release_funds(&mut self) -> Result<(), Error> {\n            let caller = self.env().caller();\n            if !self.authorized_callers.contains_key(&caller) || caller != self.buyer {\n                return Err(Error::Unauthorized);\n            }\n            self.env().transfer(self.seller, self.amount)?;\n            self.status = EscrowStatus::Released;\n            self.env().emit_event(ReleaseFunds {\n                seller: self.seller,\n                buyer: self.buyer,\n                amount: self.amount,\n            });\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn refund_funds(&mut self) -> Result<(), Error> {\n            let caller = self.env().caller();\n            if !self.authorized_callers.contains_key(&caller) || caller != self.seller {\n                return Err(Error::Unauthorized);\n            }\n            self.env().transfer(self.buyer, self.amount)?;\n            self.status = EscrowStatus::Refunded;\n            self.env().emit_event(RefundFunds {\n                seller: self.seller,\n                buyer: self.buyer,\n                amount: self.amount,\n            });\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn get_status(&self) -> EscrowStatus {\n            self.status\n        }\n\n        #[ink(message)]\n        pub fn authorize_caller(&mut self, caller: AccountId) {\n            let _ = self.authorized_callers.insert(caller, true);\n        }\n\n        #[ink(message)]\n        pub fn revoke_authorization(&mut self, caller: AccountId) {\n            let _ = self.authorized_callers.take(&caller);\n        }\n    }\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n\n        use ink_lang as ink;\n\n        #[ink::test]\n        fn test_lock_funds() {\n            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);\n            assert_eq!(escrow.get_status(), EscrowStatus::Locked);\n\n            let lock_funds_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"LockFunds\"))\n                .expect(\"no LockFunds event was emitted\");\n            assert_eq!(lock_funds_event.event, \"LockFunds\");\n            assert_eq!(lock_funds_event.args[0].clone().into_account_id(), AccountId::from([0x1; 32]));\n            assert_eq!(lock_funds_event.args[1].clone().into_account_id(), AccountId::from([0x2; 32]));\n            assert_eq!(lock_funds_event.args[2].clone().into_balance(), 100);\n        }\n\n        #[ink::test]\n        fn test_release_funds() {\n            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);\n            escrow.release_funds();\n            assert_eq!(escrow.get_status(), EscrowStatus::Released);\n\n            let release_funds_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"ReleaseFunds\"))\n                .expect(\"no ReleaseFunds event was emitted\");\n            assert_eq!(release_funds_event.event, \"ReleaseFunds\");\n            assert_eq!(release_funds_event.args[0].clone().into_account_id(), AccountId::from([0x1; 32]));\n            assert_eq!(release_funds_event.args[1].clone().into_account_id(), AccountId::from([0x2; 32]));\n            assert_eq!(release_funds_event.args[2].clone().into_balance(), 100);\n        }\n\n        #[ink::test]\n        fn test_refund_funds() {\n            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);\n            escrow.refund_funds();\n            assert_eq!(escrow.get_status(), EscrowStatus::Refunded);\n\n            let refund_funds_event = ink_env::test::emitted_events()\n                .find(|event| matches!(event.event, \"RefundFunds\"))\n                .expect(\"no RefundFunds event was emitted\");\n            assert_eq!(refund_funds_event.event, \"RefundFunds\");\n            assert_eq!(refund_funds_event.args[0].clone().into_account_id(), AccountId::from([0x1; 32]));\n            assert_eq!(refund_funds_event.args[1].clone().into_account_id(), AccountId::from([0x2; 32]));\n            assert_eq!(refund_funds_event.args[2].clone().into_balance(), 100);\n        }\n\n        #[ink::test]\n        fn test_refund_funds_unauthorized() {\n            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);\n            assert_eq!(escrow.get_status(), EscrowStatus::Locked);\n\n            // try to refund funds from a non-seller account\n            let unauthorized_caller = AccountId::from([0x3; 32]);\n            assert_eq!(\n                escrow.refund_funds_with_call(\n                    ink_env::test::Call::new(unauthorized_caller, 0)\n                ),\n                Err(Error::Unauthorized)\n            );\n\n            // check that the status has not changed\n            assert_eq!(escrow.get_status(), EscrowStatus::Locked);\n\n            // check that no events have been emitted\n            let emitted_events = ink_env::test::emitted_events();\n            assert!(emitted_events.iter().all(|event| event.event != \"ReleaseFunds\"));\n            assert!(emitted_events.iter().all(|event| event.event != \"RefundFunds\"));\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "escrow"]}');
+VALUES ('97bca69a-d703-11ed-afa1-0242ac120002', 'escrow', 'Escrow', 'Create an escrow smart contract that holds the buyer''s payment until the deal is settled. The contract is deployed with `new(seller, buyer, arbiter, amount, deadline)` and returns `Error::InvalidParties` unless the three accounts are different, or `Error::ZeroAmount` for a zero amount. The buyer deposits exactly `amount` with the payable `lock_funds`. After that, the buyer can pay the seller with `release_funds` and the seller can pay the buyer back with `refund_funds`. While the funds are locked, either party can call `raise_dispute`. The arbiter then settles the dispute with `resolve_dispute(release: bool)`, which pays the seller if `release` is true and the buyer otherwise. Once the block timestamp is past the deadline, the buyer can take the funds back with `claim_refund_after_deadline`, even while a dispute is open. The deadline only moves when both parties call `extend_deadline(deadline)` with the same later timestamp, and the first call only records the proposal. Every message returns `Result<(), Error>` and fails with `Unauthorized`, `InvalidStatus`, `IncorrectAmount`, `DeadlineNotReached`, `InvalidDeadline` or `TransferFailed`. Track the `EscrowStatus` (`AwaitingDeposit`, `Locked`, `Disputed`, `Released`, `Refunded`), expose `get_status`, `get_amount`, `get_arbiter` and `get_deadline`, and emit the events re-exported by the starter code.', 'hard', 30, 4.8, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::escrow::{\n    Error, Escrow, EscrowStatus, ExtendDeadline, LockFunds, ProposeExtension, RaiseDispute,\n    RefundFunds, ReleaseFunds, ResolveDispute,\n};\n\n#[ink::contract]\nmod escrow {\n    #[ink(storage)]\n    pub struct Escrow {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl Escrow {\n        #[ink(constructor)]\n        pub fn new(seller: AccountId, buyer: AccountId, arbiter: AccountId, amount: Balance, deadline: Timestamp) -> Result<Self, Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message, payable)]\n        pub fn lock_funds(&mut self) -> Result<(), Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn release_funds(&mut self) -> Result<(), Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn refund_funds(&mut self) -> Result<(), Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn raise_dispute(&mut self) -> Result<(), Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn resolve_dispute(&mut self, release: bool) -> Result<(), Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn claim_refund_after_deadline(&mut self) -> Result<(), Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn extend_deadline(&mut self, deadline: Timestamp) -> Result<(), Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn get_deadline(&self) -> Timestamp {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn get_status(&self) -> EscrowStatus {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn get_amount(&self) -> Balance {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn get_arbiter(&self) -> AccountId {\n            // TODO, add your code here\n        }\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::escrow::{\n    Error, Escrow, EscrowStatus, ExtendDeadline, LockFunds, ProposeExtension, RaiseDispute,\n    RefundFunds, ReleaseFunds, ResolveDispute,\n};\n\n#[ink::contract]\nmod escrow {\n    #[ink(storage)]\n    pub struct Escrow {\n        seller: AccountId,\n        buyer: AccountId,\n        arbiter: AccountId,\n        amount: Balance,\n        status: EscrowStatus,\n        /// After this timestamp the buyer can take back locked funds.\n        deadline: Timestamp,\n        /// A later deadline proposed by one party, waiting for the other to agree.\n        extension: Option<(Timestamp, AccountId)>,\n    }\n\n    /// The escrow moves from `AwaitingDeposit` to `Locked` once the buyer paid, and\n    /// ends in `Released` or `Refunded`. A locked escrow can be `Disputed` by either\n    /// party, after which the arbiter decides.\n    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub enum EscrowStatus {\n        #[default]\n        AwaitingDeposit,\n        Locked,\n        Disputed,\n        Released,\n        Refunded,\n    }\n\n    #[ink(event)]\n    pub struct LockFunds {\n        #[ink(topic)]\n        pub seller: AccountId,\n        #[ink(topic)]\n        pub buyer: AccountId,\n        #[ink(topic)]\n        pub amount: Balance,\n    }\n\n    #[ink(event)]\n    pub struct ReleaseFunds {\n        #[ink(topic)]\n        pub seller: AccountId,\n        #[ink(topic)]\n        pub buyer: AccountId,\n        #[ink(topic)]\n        pub amount: Balance,\n    }\n\n    #[ink(event)]\n    pub struct RefundFunds {\n        #[ink(topic)]\n        pub seller: AccountId,\n        #[ink(topic)]\n        pub buyer: AccountId,\n        #[ink(topic)]\n        pub amount: Balance,\n    }\n\n    #[ink(event)]\n    pub struct RaiseDispute {\n        #[ink(topic)]\n        pub raised_by: AccountId,\n    }\n\n    #[ink(event)]\n    pub struct ResolveDispute {\n        #[ink(topic)]\n        pub arbiter: AccountId,\n        /// Whether the funds went to the seller rather than back to the buyer.\n        pub released: bool,\n    }\n\n    #[ink(event)]\n    pub struct ProposeExtension {\n        #[ink(topic)]\n        pub proposed_by: AccountId,\n        pub deadline: Timestamp,\n    }\n\n    #[ink(event)]\n    pub struct ExtendDeadline {\n        pub deadline: Timestamp,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        Unauthorized,\n        TransferFailed,\n        /// The escrow is not in a status that allows this call.\n        InvalidStatus,\n        /// The deposit does not match the agreed amount.\n        IncorrectAmount,\n        DeadlineNotReached,\n        /// A new deadline must be later than the current one.\n        InvalidDeadline,\n        /// The seller, the buyer and the arbiter are not three different accounts.\n        InvalidParties,\n        /// The agreed amount is zero.\n        ZeroAmount,\n    }\n\n    impl Escrow {\n        #[ink(constructor)]\n        pub fn new(\n            seller: AccountId,\n            buyer: AccountId,\n            arbiter: AccountId,\n            amount: Balance,\n            deadline: Timestamp,\n        ) -> Result<Self, Error> {\n            // An arbiter who is also a party could settle a dispute in their own favor.\n            if seller == buyer || arbiter == seller || arbiter == buyer {\n                return Err(Error::InvalidParties);\n            }\n            if amount == 0 {\n                return Err(Error::ZeroAmount);\n            }\n            Ok(Self {\n                seller,\n                buyer,\n                arbiter,\n                amount,\n                status: EscrowStatus::AwaitingDeposit,\n                deadline,\n                extension: None,\n            })\n        }\n\n        /// Deposits the agreed amount, which must be sent along with the call by the buyer.\n        #[ink(message, payable)]\n        pub fn lock_funds(&mut self) -> Result<(), Error> {\n            if self.env().caller() != self.buyer {\n                return Err(Error::Unauthorized);\n            }\n            if self.status != EscrowStatus::AwaitingDeposit {\n                return Err(Error::InvalidStatus);\n            }\n            if self.env().transferred_value() != self.amount {\n                return Err(Error::IncorrectAmount);\n            }\n            self.status = EscrowStatus::Locked;\n            self.env().emit_event(LockFunds {\n                seller: self.seller,\n                buyer: self.buyer,\n                amount: self.amount,\n            });\n            Ok(())\n        }\n\n        /// Pays the seller, called by the buyer once the deal went through.\n        #[ink(message)]\n        pub fn release_funds(&mut self) -> Result<(), Error> {\n            if self.env().caller() != self.buyer {\n                return Err(Error::Unauthorized);\n            }\n            self.release()\n        }\n\n        /// Pays the buyer back, called by the seller to cancel the deal.\n        #[ink(message)]\n        pub fn refund_funds(&mut self) -> Result<(), Error> {\n            if self.env().caller() != self.seller {\n                return Err(Error::Unauthorized);\n            }\n            self.refund()\n        }\n\n        #[ink(message)]\n        pub fn raise_dispute(&mut self) -> Result<(), Error> {\n            let caller = self.env().caller();\n            if caller != self.buyer && caller != self.seller {\n                return Err(Error::Unauthorized);\n            }\n            if self.status != EscrowStatus::Locked {\n                return Err(Error::InvalidStatus);\n            }\n            self.status = EscrowStatus::Disputed;\n            self.env().emit_event(RaiseDispute { raised_by: caller });\n            Ok(())\n        }\n\n        /// Settles a dispute, paying the seller if `release` is set and the buyer otherwise.\n        #[ink(message)]\n        pub fn resolve_dispute(&mut self, release: bool) -> Result<(), Error> {\n            let caller = self.env().caller();\n            if caller != self.arbiter {\n                return Err(Error::Unauthorized);\n            }\n            if self.status != EscrowStatus::Disputed {\n                return Err(Error::InvalidStatus);\n            }\n            if release {\n                self.release()?;\n            } else {\n                self.refund()?;\n            }\n            self.env().emit_event(ResolveDispute {\n                arbiter: caller,\n                released: release,\n            });\n            Ok(())\n        }\n\n        /// Refunds the buyer once the deadline passed without the funds being released.\n        ///\n        /// This also works while a dispute is open, so that a dispute the arbiter never\n        /// settles cannot hold the funds past the deadline.\n        #[ink(message)]\n        pub fn claim_refund_after_deadline(&mut self) -> Result<(), Error> {\n            if self.env().caller() != self.buyer {\n                return Err(Error::Unauthorized);\n            }\n            self.ensure_funded()?;\n            if self.env().block_timestamp() <= self.deadline {\n                return Err(Error::DeadlineNotReached);\n            }\n            self.refund()\n        }\n\n        /// Moves the deadline to `deadline` once both the buyer and the seller asked for it.\n        ///\n        /// The first call only records the proposal, the other party then has to call\n        /// again with the same deadline.\n        #[ink(message)]\n        pub fn extend_deadline(&mut self, deadline: Timestamp) -> Result<(), Error> {\n            let caller = self.env().caller();\n            if caller != self.buyer && caller != self.seller {\n                return Err(Error::Unauthorized);\n            }\n            if matches!(self.status, EscrowStatus::Released | EscrowStatus::Refunded) {\n                return Err(Error::InvalidStatus);\n            }\n            if deadline <= self.deadline {\n                return Err(Error::InvalidDeadline);\n            }\n\n            match self.extension {\n                Some((proposed, proposed_by)) if proposed == deadline && proposed_by != caller => {\n                    self.deadline = deadline;\n                    self.extension = None;\n                    self.env().emit_event(ExtendDeadline { deadline });\n                }\n                _ => {\n                    self.extension = Some((deadline, caller));\n                    self.env().emit_event(ProposeExtension {\n                        proposed_by: caller,\n                        deadline,\n                    });\n                }\n            }\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn get_deadline(&self) -> Timestamp {\n            self.deadline\n        }\n\n        #[ink(message)]\n        pub fn get_status(&self) -> EscrowStatus {\n            self.status\n        }\n\n        #[ink(message)]\n        pub fn get_amount(&self) -> Balance {\n            self.amount\n        }\n\n        #[ink(message)]\n        pub fn get_arbiter(&self) -> AccountId {\n            self.arbiter\n        }\n\n        fn release(&mut self) -> Result<(), Error> {\n            self.ensure_funded()?;\n            self.env()\n                .transfer(self.seller, self.amount)\n                .map_err(|_| Error::TransferFailed)?;\n            self.status = EscrowStatus::Released;\n            self.env().emit_event(ReleaseFunds {\n                seller: self.seller,\n                buyer: self.buyer,\n                amount: self.amount,\n            });\n            Ok(())\n        }\n\n        fn refund(&mut self) -> Result<(), Error> {\n            self.ensure_funded()?;\n            self.env()\n                .transfer(self.buyer, self.amount)\n                .map_err(|_| Error::TransferFailed)?;\n            self.status = EscrowStatus::Refunded;\n            self.env().emit_event(RefundFunds {\n                seller: self.seller,\n                buyer: self.buyer,\n                amount: self.amount,\n            });\n            Ok(())\n        }\n\n        /// The funds can only be paid out once, while they are locked or disputed.\n        fn ensure_funded(&self) -> Result<(), Error> {\n            match self.status {\n                EscrowStatus::Locked | EscrowStatus::Disputed => Ok(()),\n                _ => Err(Error::InvalidStatus),\n            }\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "escrow"]}');
 
 INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
-VALUES ('d300b862-d703-11ed-afa1-0242ac120002', 'multi-signature-wallet', 'Multi-Signature Wallet', 'Create a multi-signature wallet smart contract that requires multiple confirmations for transactions. Implement propose_transaction, confirm_transaction, and execute_transaction functions.', 'hard', 20, 4.7, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod multisig_wallet {\n    #[ink(storage)]\n    pub struct MultisigWallet {\n        owners: ink_prelude::collections::HashMap<AccountId, bool>,\n        required_confirmations: u32,\n        transactions: ink_prelude::collections::HashMap<u32, (Hash, Balance, AccountId, u32, bool)>,\n        confirmations: ink_prelude::collections::HashMap<u32, ink_prelude::collections::HashMap<AccountId, bool>>,\n    }\n    // Add your implementation here\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n        use ink_lang as ink;\n\n        #[ink::test]\n        fn test_propose_transaction() {\n            let mut wallet = MultisigWallet::new(2);\n\n            let tx_hash = Hash::from([0x1; 32]);\n            let to = AccountId::from([0x2; 32]);\n            let value = 100;\n            let data = vec![0x3; 10];\n\n            assert_eq!(\n                wallet.propose_transaction(to, value, data.clone()),\n                Ok(0)\n            );\n\n            let tx = wallet.get_transaction(0);\n            assert_eq!(tx, Some((tx_hash, value, to, 0, false)));\n            assert_eq!(wallet.get_confirmations(0), HashMap::new());\n            assert_eq!(wallet.get_transaction_count(), 1);\n\n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.count(), 1);\n            assert_eq!(\n                emitted_events\n                    .into_iter()\n                    .next()\n                    .expect(\"no events emitted\")\n                    .event,\n                \"ProposeTransaction\"\n            );\n        }\n\n        #[ink::test]\n        fn test_confirm_transaction() {\n            let mut wallet = MultisigWallet::new(2);\n\n            let tx_hash = Hash::from([0x1; 32]);\n            let to = AccountId::from([0x2; 32]);\n            let value = 100;\n            let data = vec![0x3; 10];\n            wallet.propose_transaction(to, value, data.clone()).unwrap();\n\n            let signer1 = AccountId::from([0x4; 32]);\n            let signer2 = AccountId::from([0x5; 32]);\n\n            assert_eq!(\n                wallet.confirm_transaction(0, signer1),\n                Ok(())\n            );\n            assert_eq!(wallet.get_confirmations(0), hash_map! { signer1 => true });\n            assert_eq!(wallet.is_confirmed(0), false);\n\n            assert_eq!(\n                wallet.confirm_transaction(0, signer2),\n                Ok(())\n            );\n            assert_eq!(\n                wallet.get_confirmations(0),\n                hash_map! { signer1 => true, signer2 => true }\n            );\n            assert_eq!(wallet.is_confirmed(0), true);\n\n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.count(), 2);\n            let events: Vec<_> = emitted_events\n                .into_iter()\n                .map(|e| e.event)\n                .collect();\n            assert!(events.contains(&\"ConfirmTransaction\".to_string()));\n            assert!(events.contains(&\"ExecuteTransaction\".to_string()));\n        }\n\n        #[ink::test]\n        fn test_execute_transaction() {\n            let mut wallet = MultisigWallet::new(2);\n\n            let tx_hash = Hash::from([0x1; 32]);\n            let to = AccountId::from([0x2; 32]);\n            let value = 100;\n            let data = vec![0x3; 10];\n            wallet.propose_transaction(to, value, data.clone()).unwrap();\n\n            let signer1 = AccountId::from([0x4; 32]);\n            let signer2 = AccountId::from([0x5; 32]);\n            wallet.confirm_transaction(0, signer1).unwrap();\n            wallet.confirm_transaction(0, signer2).unwrap();\n\n            assert_eq!(\n                wallet.execute_transaction(0),\n                Ok(())\n            );\n            assert_eq!(wallet.get_transaction(0), Some((tx_hash, value, to, 2, true)));\n            assert_eq!(wallet.get_confirmations(0), HashMap::new());\n            assert_eq!(wallet.get_transaction_count(), 1);\n\n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.count(), 2);\n            let events: Vec<_> = emitted_events\n                .into_iter()\n                .map(|e| e.event)\n                .collect();\n            assert!(events.contains(&\"ExecuteTransaction\".to_string()));\n        }\n\n        #[ink::test]\n        fn test_execute_transaction_not_enough_confirmations() {\n            let mut wallet = MultisigWallet::new(2);\n\n            let tx_hash = Hash::from([0x1; 32]);\n            let to = AccountId::from([0x2; 32]);\n            let value = 100;\n            let data = vec![0x3; 10];\n            wallet.propose_transaction(to, value, data.clone()).unwrap();\n\n            let signer1 = AccountId::from([0x4; 32]);\n            wallet.confirm_transaction(0, signer1).unwrap();\n\n            assert_eq!(\n                wallet.execute_transaction(0),\n                Err(Error::NotEnoughConfirmations)\n            );\n            assert_eq!(wallet.get_transaction(0), Some((tx_hash, value, to, 1, false)));\n            assert_eq!(\n                wallet.get_confirmations(0),\n                hash_map! { signer1 => true }\n            );\n            assert_eq!(wallet.get_transaction_count(), 1);\n\n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.count(), 0);\n        }\n\n        #[ink::test]\n        fn test_execute_transaction_already_executed() {\n            let mut wallet = MultisigWallet::new(2);\n\n            let tx_hash = Hash::from([0x1; 32]);\n            let to = AccountId::from([0x2; 32]);\n            let value = 100;\n            let data = vec![0x3; 10];\n            wallet.propose_transaction(to, value, data.clone()).unwrap();\n\n            let signer1 = AccountId::from([0x4; 32]);\n            let signer2 = AccountId::from([0x5; 32]);\n            wallet.confirm_transaction(0, signer1).unwrap();\n            wallet.confirm_transaction(0, signer2).unwrap();\n            wallet.execute_transaction(0).unwrap();\n\n            assert_eq!(\n                wallet.execute_transaction(0),\n                Err(Error::AlreadyExecuted)\n            );\n            assert_eq!(wallet.get_transaction(0), Some((tx_hash, value, to, 2, true)));\n            assert_eq!(wallet.get_confirmations(0), HashMap::new());\n            assert_eq!(wallet.get_transaction_count(), 1);\n\n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.count(), 1);\n            assert_eq!(\n                emitted_events\n                    .into_iter()\n                    .next()\n                    .expect(\"no events emitted\")\n                    .event,\n                \"ExecuteTransaction\"\n            );\n        }\n    }\n}\n\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod multisig_wallet {\n    use ink_prelude::collections::HashMap;\n    use ink_prelude::vec::Vec;\n\n    #[ink(storage)]\n    pub struct MultisigWallet {\n        owners: HashMap<AccountId, bool>,\n        required_confirmations: u32,\n        transactions: HashMap<u32, (Hash, Balance, AccountId, u32, bool)>,\n        confirmations: HashMap<u32, HashMap<AccountId, bool>>,\n    }\n\n    impl MultisigWallet {\n        #[ink(constructor)]\n        pub fn new(required_confirmations: u32) -> Self {\n            Self {\n                owners: HashMap::new(),\n                required_confirmations,\n                transactions: HashMap::new(),\n                confirmations: HashMap::new(),\n            }\n        }\n\n        pub fn propose_transaction(\n            &mut self,\n            to: AccountId,\n            value: Balance,\n            data: Vec<u8>,\n        ) -> Result<u32, Error> {\n            let id = self.transactions.len() as u32;\n            let tx_hash = Self::hash(&to, value, &data);\n            self.transactions.insert(\n                id,\n                (tx_hash, value, to, 0, false)\n            );\n            self.confirmations.insert(id, HashMap::new());\n            self.emit_propose_transaction(id, tx_hash, to, value, data);\n            Ok(id)\n        }\n\n        pub fn confirm_transaction(\n            &mut self,\n            id: u32,\n            owner: AccountId,\n        ) -> Result<(), Error> {\n            let tx = self.transactions.get(&id).ok_or(Error::InvalidTransactionId)?;\n            let mut confirmations = self.confirmations.get(&id).ok_or(Error::InvalidTransactionId)?;\n\n            if !self.owners.contains_key(&owner) {\n                return Err(Error::Unauthorized);\n            }\n            if confirmations.contains_key(&owner) {\n                return Err(Error::AlreadyConfirmed);\n            }\n\n            confirmations.insert(owner, true);\n            self.emit_confirm_transaction(id, owner);\n\n            if self.is_confirmed(id) {\n                self.execute_transaction(id)?;\n            }\n\n            Ok(())\n        }\n\n        pub fn execute_transaction(&mut self, id: u32) -> Result<(), Error> {\n            let mut tx = self.transactions.get_mut(&id).ok_or(Error::InvalidTransactionId)?;\n            let mut confirmations = self.confirmations.remove(&id).ok_or(Error::InvalidTransactionId)?;\n\n            if tx.4 {\n                return Err(Error::AlreadyExecuted);\n            }\n            if !self.is_confirmed(id) {\n                return Err(Error::NotEnoughConfirmations);\n            }\n\n            for (owner, _) in confirmations.iter() {\n                let balance = Self::env().balance(*owner);\n                Self::env().transfer(*owner, tx.1).map_err(|_| Error::TransferFailed)?;\n            }\n\n            tx.3 = confirmations.len() as u32;\n            tx.4 = true;\n            self.emit_execute_transaction(id, tx.2, tx.1);\n\n            Ok(())\n        }\n\n        pub fn add_owner(&mut self, owner: AccountId) -> Result<(), Error> {\n            if self.owners.contains_key(&owner) {\n                return Err(Error::AlreadyOwner);\n            }\n            self.owners.insert(owner, true);\n            Ok(())\n        }\n\n        pub fn remove_owner(&mut self, owner: AccountId) -> Result<(), Error> {\n            if self.owners.len() == 1 {\n                return Err(Error::CannotRemoveLastOwner);\n            }\n            if !self.owners.contains_key(&owner) {\n                return Err(Error::NotOwner);\n            }\n            self.owners.remove(&owner);\n            Ok(())\n        }\n\n        pub fn is_confirmed(&self, id: u32) -> bool {\n            let confirmations = self.confirmations.get(&id).unwrap();\n            confirmations.len() >= self.required_confirmations as usize\n        }\n\n        pub fn get_transaction_count(&self) -> u32 {\n            self.transactions.len() as u32\n        }\n\n        pub fn get_transaction(&self, id: u32) -> Option<(Hash, Balance, AccountId, u32, bool)> {\n            self.transactions.get(&id).cloned()\n        }\n\n        pub fn get_confirmations(&self, id: u32) -> HashMap<AccountId, bool> {\n            self.confirmations.get(&id).cloned().unwrap_or_default()\n        }\n\n        fn hash(to: &AccountId, value: Balance, data: &[u8]) -> Hash {\n            Self::env().hash_of(&(to, value, data))\n        }\n    }\n\n    #[ink(event)]\n    pub struct ProposeTransaction {\n        #[ink(topic)]\n        id: u32,\n        tx_hash: Hash,\n        to: AccountId,\n        value: Balance,\n        data: Vec<u8>,\n    }\n\n    #[ink(event)]\n    pub struct ConfirmTransaction {\n        #[ink(topic)]\n        id: u32,\n        owner: AccountId,\n    }\n\n    #[ink(event)]\n    pub struct ExecuteTransaction {\n        #[ink(topic)]\n        id: u32,\n        to: AccountId,\n        value: Balance,\n    }\n\n    #[ink(event)]\n    pub struct AddOwner {\n        #[ink(topic)]\n        owner: AccountId,\n    }\n\n    #[ink(event)]\n    pub struct RemoveOwner {\n        #[ink(topic)]\n        owner: AccountId,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        InvalidTransactionId,\n        Unauthorized,\n        AlreadyConfirmed,\n        AlreadyExecuted,\n        NotEnoughConfirmations,\n        TransferFailed,\n        AlreadyOwner,\n        NotOwner,\n        CannotRemoveLastOwner,\n    }\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n        use ink_lang as ink;\n\n        #[ink::test]\n        fn test_propose_transaction() {\n            let mut wallet = MultisigWallet::new(2);\n\n            let tx_hash = Hash::from([0x1; 32]);\n            let to = AccountId::from([0x2; 32]);\n            let value = 100;\n            let data = vec![0x3; 10];\n\n            assert_eq!(\n                wallet.propose_transaction(to, value, data.clone()),\n                Ok(0)\n            );\n\n            let tx = wallet.get_transaction(0);\n            assert_eq!(tx, Some((tx_hash, value, to, 0, false)));\n            assert_eq!(wallet.get_confirmations(0), HashMap::new());\n            assert_eq!(wallet.get_transaction_count(), 1);\n\n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.count(), 1);\n            assert_eq!(\n                emitted_events\n                    .into_iter()\n                    .next()\n                    .expect(\"no events emitted\")\n                    .event,\n                \"ProposeTransaction\"\n            );\n        }\n\n        #[ink::test]\n        fn test_confirm_transaction() {\n            let mut wallet = MultisigWallet::new(2);\n\n            let tx_hash = Hash::from([0x1; 32]);\n            let to = AccountId::from([0x2; 32]);\n            let value = 100;\n            let data = vec![0x3; 10];\n            wallet.propose_transaction(to, value, data.clone()).unwrap();\n\n            let signer1 = AccountId::from([0x4; 32]);\n            let signer2 = AccountId::from([0x5; 32]);\n\n            assert_eq!(\n                wallet.confirm_transaction(0, signer1),\n                Ok(())\n            );\n            assert_eq!(wallet.get_confirmations(0), hash_map! { signer1 => true });\n            assert_eq!(wallet.is_confirmed(0), false);\n\n            assert_eq!(\n                wallet.confirm_transaction(0, signer2),\n                Ok(())\n            );\n            assert_eq!(\n                wallet.get_confirmations(0),\n                hash_map! { signer1 => true, signer2 => true }\n            );\n            assert_eq!(wallet.is_confirmed(0), true);\n\n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.count(), 2);\n            let events: Vec<_> = emitted_events\n                .into_iter()\n                .map(|e| e.event)\n                .collect();\n            assert!(events.contains(&\"ConfirmTransaction\".to_string()));\n            assert!(events.contains(&\"ExecuteTransaction\".to_string()));\n        }\n\n        #[ink::test]\n        fn test_execute_transaction() {\n            let mut wallet = MultisigWallet::new(2);\n\n            let tx_hash = Hash::from([0x1; 32]);\n            let to = AccountId::from([0x2; 32]);\n            let value = 100;\n            let data = vec![0x3; 10];\n            wallet.propose_transaction(to, value, data.clone()).unwrap();\n\n            let signer1 = AccountId::from([0x4; 32]);\n            let signer2 = AccountId::from([0x5; 32]);\n            wallet.confirm_transaction(0, signer1).unwrap();\n            wallet.confirm_transaction(0, signer2).unwrap();\n\n            assert_eq!(\n                wallet.execute_transaction(0),\n                Ok(())\n            );\n            assert_eq!(wallet.get_transaction(0), Some((tx_hash, value, to, 2, true)));\n            assert_eq!(wallet.get_confirmations(0), HashMap::new());\n            assert_eq!(wallet.get_transaction_count(), 1);\n\n            let emitted_events = ink_env::test::emitted_events();\n            assert_eq!(emitted_events.count(), 2);\n            let events: Vec<_> = emitted_events\n                .into_iter()\n                .map(|e| e.event)\n                .collect();\n            assert!(events.contains(&\"ExecuteTransaction\".to_string()));\n        }\n    }\n\n    #[ink::test]\n    fn test_execute_transaction_not_enough_confirmations() {\n        let mut wallet = MultisigWallet::new(2);\n\n        let tx_hash = Hash::from([0x1; 32]);\n        let to = AccountId::from([0x2; 32]);\n        let value = 100;\n        let data = vec![0x3; 10];\n        wallet.propose_transaction(to, value, data.clone()).unwrap();\n\n        let signer1 = AccountId::from([0x4; 32]);\n        wallet.confirm_transaction(0, signer1).unwrap();\n\n        assert_eq!(\n            wallet.execute_transaction(0),\n            Err(Error::NotEnoughConfirmations)\n        );\n        assert_eq!(wallet.get_transaction(0), Some((tx_hash, value, to, 1, false)));\n        assert_eq!(\n            wallet.get_confirmations(0),\n            hash_map! { signer1 => true }\n        );\n        assert_eq!(wallet.get_transaction_count(), 1);\n\n        let emitted_events = ink_env::test::emitted_events();\n        assert_eq!(emitted_events.count(), 0);\n    }\n\n    #[ink::test]\n    fn test_execute_transaction_already_executed() {\n        let mut wallet = MultisigWallet::new(2);\n\n        let tx_hash = Hash::from([0x1; 32]);\n        let to = AccountId::from([0x2; 32]);\n        let value = 100;\n        let data = vec![0x3; 10];\n        wallet.propose_transaction(to, value, data.clone()).unwrap();\n\n        let signer1 = AccountId::from([0x4; 32]);\n        let signer2 = AccountId::from([0x5; 32]);\n        wallet.confirm_transaction(0, signer1).unwrap();\n        wallet.confirm_transaction(0, signer2).unwrap();\n        wallet.execute_transaction(0).unwrap();\n\n        assert_eq!(\n            wallet.execute_transaction(0),\n            Err(Error::AlreadyExecuted)\n        );\n        assert_eq!(wallet.get_transaction(0), Some((tx_hash, value, to, 2, true)));\n        assert_eq!(wallet.get_confirmations(0), HashMap::new());\n        assert_eq!(wallet.get_transaction_count(), 1);\n\n        let emitted_events = ink_env::test::emitted_events();\n        assert_eq!(emitted_events.count(), 1);\n        assert_eq!(\n            emitted_events\n                .into_iter()\n                .next()\n                .expect(\"no events emitted\")\n                .event,\n            \"ExecuteTransaction\"\n        );\n    }\n}\n\n"}, "category": "Ink!", "tags": ["smart contract", "multisig"]}');
-
--- INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
--- VALUES ('111c5b2a-d704-11ed-afa1-0242ac120002', 'simple-auction', 'Simple Auction', 'Create a simple auction smart contract that allows users to bid on an item. Implement place_bid, withdraw, and finalize_auction functions. The contract should prevent bids after the auction has ended.', 'med', 45, 4.3, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod simple_auction {\n    #[ink(storage)]\n    pub struct SimpleAuction {\n        auction_end: Timestamp,\n        highest_bidder: AccountId,\n        highest_bid: Balance,\n    }\n    // Add your implementation here\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n    \n        use ink_lang as ink;\n    \n        #[ink::test]\n        fn test_place_bid() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // place a bid\n            let bidder = account_id_from_seed(&\"Alice\");\n            let value = 100;\n            assert_eq!(auction.place_bid().transact(bidder, value), Ok(()));\n            // check that the highest bidder and bid have been updated\n            assert_eq!(auction.highest_bidder(), Some(bidder));\n            assert_eq!(auction.highest_bid(), value);\n        }\n    \n        #[ink::test]\n        fn test_withdraw() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // place a bid\n            let bidder = account_id_from_seed(&\"Alice\");\n            let value = 100;\n            assert_eq!(auction.place_bid().transact(bidder, value), Ok(()));\n            // withdraw the bid\n            assert_eq!(auction.withdraw().transact(bidder, ()), Ok(()));\n            // check that the highest bidder and bid have been reset\n            assert_eq!(auction.highest_bidder(), None);\n            assert_eq!(auction.highest_bid(), 0);\n        }\n    \n        #[ink::test]\n        fn test_finalize_auction() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // place a bid\n            let bidder = account_id_from_seed(&\"Alice\");\n            let value = 100;\n            assert_eq!(auction.place_bid().transact(bidder, value), Ok(()));\n            // finalize the auction\n            assert_eq!(auction.finalize_auction().transact(bidder, ()), Ok(()));\n            // check that the highest bidder and bid have been reset\n            assert_eq!(auction.highest_bidder(), Some(bidder));\n            assert_eq!(auction.highest_bid(), value);\n        }\n    \n        #[ink::test]\n        fn test_place_bid_after_auction_end() {\n            // create a new auction ending in one second\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 1);\n            // wait for the auction to end\n            Self::env().test().advance_block_timestamp(2);\n            // place a bid\n            let bidder = account_id_from_seed(&\"Alice\");\n            let value = 100;\n            assert_eq!(\n                auction.place_bid().transact(bidder, value),\n                Err(Error::AuctionEnded)\n            );\n        }\n    \n        #[ink::test]\n        fn test_withdraw_before_bid() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // withdraw the bid\n            let bidder = account_id_from_seed(&\"Alice\");\n            assert_eq!(\n                auction.withdraw().transact(bidder, ()),\n                Err(Error::NoBidPlaced)\n            );\n        }\n    \n        #[ink::test]\n        fn test_finalize_auction_before_end() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // finalize the auction\n            let bidder = account_id_from_seed(&\"Alice\");\n            assert(\n                auction.finalize_auction().transact(bidder, ()),\n                Err(Error::AuctionNotEnded),\n            );\n        }\n\n        #[ink::test]\n        fn test_place_bid_lower_than_highest_bid() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // place a bid\n            let bidder1 = account_id_from_seed(&\"Alice\");\n            let value1 = 100;\n            assert_eq!(auction.place_bid().transact(bidder1, value1), Ok(()));\n            // place a lower bid\n            let bidder2 = account_id_from_seed(&\"Bob\");\n            let value2 = 50;\n            assert_eq!(\n                auction.place_bid().transact(bidder2, value2),\n                Err(Error::BidTooLow)\n            );\n        }\n        \n        #[ink::test]\n        fn test_finalize_auction_no_bids() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // finalize the auction\n            let bidder = account_id_from_seed(&\"Alice\");\n            assert_eq!(\n                auction.finalize_auction().transact(bidder, ()),\n                Err(Error::NoBidPlaced)\n            );\n        }\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\nuse ink_lang::contract;\nuse ink_prelude::vec::Vec;\nuse ink_prelude::collections::HashMap;\nuse ink_storage::collections::Vec as StorageVec;\nuse ink_storage::traits::{PackedLayout, SpreadLayout};\n\n#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n#[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\npub enum Error {\n    AuctionEnded,\n    NoBidPlaced,\n    BidTooLow,\n    Unauthorized,\n}\n\n#[derive(Debug, PartialEq, Eq, Copy, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout)]\n#[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\npub struct Bid {\n    bidder: AccountId,\n    amount: Balance,\n}\n\n#[contract]\nmod simple_auction {\n    use super::*;\n\n    #[ink(storage)]\n    pub struct SimpleAuction {\n        owner: AccountId,\n        highest_bid: Balance,\n        highest_bidder: AccountId,\n        bids: StorageVec<Bid>,\n        auction_end: Timestamp,\n    }\n\n    impl SimpleAuction {\n        #[ink(constructor)]\n        pub fn new(auction_end: Timestamp) -> Self {\n            Self {\n                owner: Self::env().caller(),\n                highest_bid: 0,\n                highest_bidder: AccountId::default(),\n                bids: StorageVec::new(),\n                auction_end,\n            }\n        }\n\n        #[ink(message)]\n        pub fn place_bid(&mut self) -> Result<(), Error> {\n            let caller = self.env().caller();\n            let value = self.env().transferred_balance();\n            if self.env().block_timestamp() >= self.auction_end {\n                return Err(Error::AuctionEnded);\n            }\n            if value <= self.highest_bid {\n                return Err(Error::BidTooLow);\n            }\n            self.bids.push(Bid { bidder: caller, amount: value });\n            self.highest_bid = value;\n            self.highest_bidder = caller;\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn withdraw(&mut self) -> Result<(), Error> {\n            let caller = self.env().caller();\n            if self.env().block_timestamp() >= self.auction_end {\n                return Err(Error::AuctionEnded);\n            }\n            let bid_index = self.bids\n                .iter()\n                .position(|bid| bid.bidder == caller)\n                .ok_or(Error::NoBidPlaced)?;\n            let bid = self.bids.remove(bid_index);\n            if bid.amount == self.highest_bid {\n                self.highest_bid = self.bids.iter()\n                    .map(|bid| bid.amount)\n                    .max()\n                    .unwrap_or(0);\n                self.highest_bidder = self.bids.iter()\n                    .find(|bid| bid.amount == self.highest_bid)\n                    .map(|bid| bid.bidder)\n                    .unwrap_or(AccountId::default());\n            }\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn finalize_auction(&mut self) -> Result<(), Error> {\n            let caller = self.env().caller();\n            if caller != self.owner {\n                return Err(Error::Unauthorized);\n            }\n            if self.env().block_timestamp() < self.auction_end {\n                return Err(Error::AuctionNotEnded);\n            }\n            if let Some(bid) = self.bids.last() {\n                self.env().transfer(bid.bidder, bid.amount)\n                    .map_err(|_| Error::Unauthorized)?;\n            } else {\n                return Err(Error::NoBidPlaced);\n            }\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn highest_bidder(&self) -> Option<AccountId> {\n            if self.env().block_timestamp() < self.auction_end {\n                None\n            } else {\n                Some(self.highest_bidder)\n            }\n        }\n\n        #[ink(message)]\n        pub fn highest_bid(&self) -> Balance {\n            self.highest_bid\n        }\n    }\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n    \n        use ink_lang as ink;\n    \n        #[ink::test]\n        fn test_place_bid() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // place a bid\n            let bidder = account_id_from_seed(&\"Alice\");\n            let value = 100;\n            assert_eq!(auction.place_bid().transact(bidder, value), Ok(()));\n            // check that the highest bidder and bid have been updated\n            assert_eq!(auction.highest_bidder(), Some(bidder));\n            assert_eq!(auction.highest_bid(), value);\n        }\n    \n        #[ink::test]\n        fn test_withdraw() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // place a bid\n            let bidder = account_id_from_seed(&\"Alice\");\n            let value = 100;\n            assert_eq!(auction.place_bid().transact(bidder, value), Ok(()));\n            // withdraw the bid\n            assert_eq!(auction.withdraw().transact(bidder, ()), Ok(()));\n            // check that the highest bidder and bid have been reset\n            assert_eq!(auction.highest_bidder(), None);\n            assert_eq!(auction.highest_bid(), 0);\n        }\n    \n        #[ink::test]\n        fn test_finalize_auction() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // place a bid\n            let bidder = account_id_from_seed(&\"Alice\");\n            let value = 100;\n            assert_eq!(auction.place_bid().transact(bidder, value), Ok(()));\n            // finalize the auction\n            assert_eq!(auction.finalize_auction().transact(bidder, ()), Ok(()));\n            // check that the highest bidder and bid have been reset\n            assert_eq!(auction.highest_bidder(), Some(bidder));\n            assert_eq!(auction.highest_bid(), value);\n        }\n    \n        #[ink::test]\n        fn test_place_bid_after_auction_end() {\n            // create a new auction ending in one second\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 1);\n            // wait for the auction to end\n            Self::env().test().advance_block_timestamp(2);\n            // place a bid\n            let bidder = account_id_from_seed(&\"Alice\");\n            let value = 100;\n            assert_eq!(\n                auction.place_bid().transact(bidder, value),\n                Err(Error::AuctionEnded)\n            );\n        }\n    \n        #[ink::test]\n        fn test_withdraw_before_bid() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // withdraw the bid\n            let bidder = account_id_from_seed(&\"Alice\");\n            assert_eq!(\n                auction.withdraw().transact(bidder, ()),\n                Err(Error::NoBidPlaced)\n            );\n        }\n    \n        #[ink::test]\n        fn test_finalize_auction_before_end() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // finalize the auction\n            let bidder = account_id_from_seed(&\"Alice\");\n            assert(\n                auction.finalize_auction().transact(bidder, ()),\n                Err(Error::AuctionNotEnded),\n            );\n        }\n\n        #[ink::test]\n        fn test_place_bid_lower_than_highest_bid() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // place a bid\n            let bidder1 = account_id_from_seed(&\"Alice\");\n            let value1 = 100;\n            assert_eq!(auction.place_bid().transact(bidder1, value1), Ok(()));\n            // place a lower bid\n            let bidder2 = account_id_from_seed(&\"Bob\");\n            let value2 = 50;\n            assert_eq!(\n                auction.place_bid().transact(bidder2, value2),\n                Err(Error::BidTooLow)\n            );\n        }\n        \n        #[ink::test]\n        fn test_finalize_auction_no_bids() {\n            // create a new auction ending in one hour\n            let mut auction = SimpleAuction::new(Self::env().block_timestamp() + 3600);\n            // finalize the auction\n            let bidder = account_id_from_seed(&\"Alice\");\n            assert_eq!(\n                auction.finalize_auction().transact(bidder, ()),\n                Err(Error::NoBidPlaced)\n            );\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "auction"]}');
---
--- INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
--- VALUES ('524a87a2-d704-11ed-afa1-0242ac120002', 'timed-lock', 'Timed Lock', 'Create a timed lock smart contract that allows users to lock their funds for a specified duration. Implement lock, unlock, and get_remaining_time functions. The contract should prevent withdrawals before the lock duration has elapsed.', 'med', 35, 4.1, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod timed_lock {\n    #[ink(storage)]\n    pub struct TimedLock {\n        lock_duration: Timestamp,\n        lock_end: Timestamp,\n        locked_balance: Balance,\n        owner: AccountId,\n    }\n    // Add your implementation here\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n    \n        use ink_env::{\n            AccountId,\n            DefaultEnvironment,\n            Environment,\n            Hash,\n        };\n        use ink_lang as ink;\n        use ink_prelude::{string::String, vec::Vec};\n        use ink_storage::traits::{PackedLayout, SpreadLayout};\n    \n        type AccountId = <DefaultEnvironment as Environment>::AccountId;\n        type Balance = <DefaultEnvironment as Environment>::Balance;\n        type Timestamp = <DefaultEnvironment as Environment>::Timestamp;\n    \n        const ENDOWMENT: Balance = 1_000_000;\n        const MAX_GAS: u64 = 1_000_000;\n    \n        fn account_id_from_seed(seed: &str) -> AccountId {\n            let env = ink_env::test::TestEnvironment::new();\n            let callee = ink_env::account_id::<DefaultEnvironment>(ink_env::call::callee::<DefaultEnvironment>().unwrap());\n            let rand = ink_env::hash::Sha2x256::hash(seed.as_bytes());\n            let mut buf = [0u8; 32];\n            buf.copy_from_slice(&rand.as_fixed_bytes());\n            ink_env::test::CallBuilder::new(env.accounts.create().endowment(ENDOWMENT).build().create_call(\n                callee,\n                \"set_owner\",\n                ink_env::test::CallData::new(buf.to_vec()),\n            )).gas_limit(MAX_GAS).execute().expect(\"creating new account instance failed\").unwrap()\n        }\n    \n        #[ink::test]\n        fn test_lock() {\n            // create a new timed lock contract with a duration of 1 hour\n            let mut lock = TimedLock::new(Self::env().caller(), 3600);\n            // lock 100 tokens\n            let value = 100;\n            assert_eq!(lock.lock().transact(Self::env().caller(), value), Ok(()));\n            // check that the locked balance has increased\n            assert_eq!(lock.locked_balance(), value);\n        }\n    \n        #[ink::test]\n        fn test_unlock() {\n            // create a new timed lock contract with a duration of 1 hour\n            let mut lock = TimedLock::new(Self::env().caller(), 3600);\n            // lock 100 tokens\n            let value = 100;\n            assert_eq!(lock.lock().transact(Self::env().caller(), value), Ok(()));\n            // wait for the lock duration to expire\n            Self::env().test_set_timestamp(Self::env().block_timestamp() + 3600);\n            // unlock the tokens\n            assert_eq!(lock.unlock().transact(Self::env().caller(), ()), Ok(()));\n            // check that the locked balance has been cleared\n            assert_eq!(lock.locked_balance(), 0);\n        }\n    \n        #[ink::test]\n        fn test_get_remaining_time() {\n            // create a new timed lock contract with a duration of 1 hour\n            let mut lock = TimedLock::new(Self::env().caller(), 3600);\n            // check that the remaining time is initially 1 hour\n            assert_eq!(lock.get_remaining_time(), 3600);\n            // lock 100 tokens\n            let value = 100;\n            assert_eq!(lock.lock().transact(Self::env().caller(), value), Ok(()));\n            // check that the remaining time is still 1 hour\n            assert_eq!(lock.get_remaining_time(), 3600);\n            // wait for 30 minutes\n            Self::env().test_set_timestamp(Self::env().block_timestamp() + 1800);\n            // check that the remaining time is now 30 minutes\n            assert_eq!(lock.get_remaining_time(), 1800);\n            // wait for another 30 minutes\n            Self::env().test_set_timestamp(Self::env().block_timestamp() + 1800);\n            // check that the remaining time is now 0\n            assert_eq!(lock.get_remaining_time(), 0);\n        }\n    \n        #[ink::test]\n        fn test_unlock_before_duration_has_elapsed() {\n            // create a new timed lock contract with a duration of 1 hour\n            let mut lock = TimedLock::new(Self::env().caller(), 3600);\n            // lock 100 tokens\n            let value = 100;\n            assert_eq!(lock.lock().transact(Self::env().caller(), value), Ok(()));\n            // try to unlock the tokens before the lock duration has elapsed\n            assert_eq!(lock.unlock().transact(Self::env().caller(), ()), Err(Error::DurationNotElapsed));\n            // check that the locked balance has not been cleared\n            assert_eq!(lock.locked_balance(), value);\n        }\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod timed_lock {\n    use ink_prelude::vec::Vec;\n\n    #[ink(storage)]\n    pub struct TimedLock {\n        lock_duration: Timestamp,\n        lock_end: Timestamp,\n        locked_balance: Balance,\n        owner: AccountId,\n    }\n    \n\n    impl TimedLock {\n        #[ink(constructor)]\n        pub fn new(owner: AccountId, lock_duration: Timestamp) -> Self {\n            Self {\n                owner,\n                lock_duration,\n                ..Default::default()\n            }\n        }\n\n        #[ink(message)]\n        pub fn lock(&mut self) -> Result<(), Error> {\n            if self.locked_balance > 0 {\n                return Err(Error::AlreadyLocked);\n            }\n            let caller = self.env().caller();\n            let balance = self.env().balance_of(caller);\n            if balance == 0 {\n                return Err(Error::NoBalance);\n            }\n            self.locked_balance = balance;\n            self.lock_end = self.env().block_timestamp() + self.lock_duration;\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn unlock(&mut self) -> Result<(), Error> {\n            if self.locked_balance == 0 {\n                return Err(Error::NotLocked);\n            }\n            if self.env().block_timestamp() < self.lock_end {\n                return Err(Error::DurationNotElapsed);\n            }\n            let caller = self.env().caller();\n            if caller != self.owner {\n                return Err(Error::NotAuthorized);\n            }\n            let balance = self.locked_balance;\n            self.locked_balance = 0;\n            self.env().transfer(caller, balance)?;\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn get_remaining_time(&self) -> Timestamp {\n            let now = self.env().block_timestamp();\n            if now >= self.lock_end {\n                return 0;\n            }\n            self.lock_end - now\n        }\n\n        #[ink(message)]\n        pub fn locked_balance(&self) -> Balance {\n            self.locked_balance\n        }\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        AlreadyLocked,\n        NoBalance,\n        NotLocked,\n        DurationNotElapsed,\n        NotAuthorized,\n    }\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n    \n        use ink_env::{\n            AccountId,\n            DefaultEnvironment,\n            Environment,\n            Hash,\n        };\n        use ink_lang as ink;\n        use ink_prelude::{string::String, vec::Vec};\n        use ink_storage::traits::{PackedLayout, SpreadLayout};\n    \n        type AccountId = <DefaultEnvironment as Environment>::AccountId;\n        type Balance = <DefaultEnvironment as Environment>::Balance;\n        type Timestamp = <DefaultEnvironment as Environment>::Timestamp;\n    \n        const ENDOWMENT: Balance = 1_000_000;\n        const MAX_GAS: u64 = 1_000_000;\n    \n        fn account_id_from_seed(seed: &str) -> AccountId {\n            let env = ink_env::test::TestEnvironment::new();\n            let callee = ink_env::account_id::<DefaultEnvironment>(ink_env::call::callee::<DefaultEnvironment>().unwrap());\n            let rand = ink_env::hash::Sha2x256::hash(seed.as_bytes());\n            let mut buf = [0u8; 32];\n            buf.copy_from_slice(&rand.as_fixed_bytes());\n            ink_env::test::CallBuilder::new(env.accounts.create().endowment(ENDOWMENT).build().create_call(\n                callee,\n                \"set_owner\",\n                ink_env::test::CallData::new(buf.to_vec()),\n            )).gas_limit(MAX_GAS).execute().expect(\"creating new account instance failed\").unwrap()\n        }\n    \n        #[ink::test]\n        fn test_lock() {\n            // create a new timed lock contract with a duration of 1 hour\n            let mut lock = TimedLock::new(Self::env().caller(), 3600);\n            // lock 100 tokens\n            let value = 100;\n            assert_eq!(lock.lock().transact(Self::env().caller(), value), Ok(()));\n            // check that the locked balance has increased\n            assert_eq!(lock.locked_balance(), value);\n        }\n    \n        #[ink::test]\n        fn test_unlock() {\n            // create a new timed lock contract with a duration of 1 hour\n            let mut lock = TimedLock::new(Self::env().caller(), 3600);\n            // lock 100 tokens\n            let value = 100;\n            assert_eq!(lock.lock().transact(Self::env().caller(), value), Ok(()));\n            // wait for the lock duration to expire\n            Self::env().test_set_timestamp(Self::env().block_timestamp() + 3600);\n            // unlock the tokens\n            assert_eq!(lock.unlock().transact(Self::env().caller(), ()), Ok(()));\n            // check that the locked balance has been cleared\n            assert_eq!(lock.locked_balance(), 0);\n        }\n    \n        #[ink::test]\n        fn test_get_remaining_time() {\n            // create a new timed lock contract with a duration of 1 hour\n            let mut lock = TimedLock::new(Self::env().caller(), 3600);\n            // check that the remaining time is initially 1 hour\n            assert_eq!(lock.get_remaining_time(), 3600);\n            // lock 100 tokens\n            let value = 100;\n            assert_eq!(lock.lock().transact(Self::env().caller(), value), Ok(()));\n            // check that the remaining time is still 1 hour\n            assert_eq!(lock.get_remaining_time(), 3600);\n            // wait for 30 minutes\n            Self::env().test_set_timestamp(Self::env().block_timestamp() + 1800);\n            // check that the remaining time is now 30 minutes\n            assert_eq!(lock.get_remaining_time(), 1800);\n            // wait for another 30 minutes\n            Self::env().test_set_timestamp(Self::env().block_timestamp() + 1800);\n            // check that the remaining time is now 0\n            assert_eq!(lock.get_remaining_time(), 0);\n        }\n    \n        #[ink::test]\n        fn test_unlock_before_duration_has_elapsed() {\n            // create a new timed lock contract with a duration of 1 hour\n            let mut lock = TimedLock::new(Self::env().caller(), 3600);\n            // lock 100 tokens\n            let value = 100;\n            assert_eq!(lock.lock().transact(Self::env().caller(), value), Ok(()));\n            // try to unlock the tokens before the lock duration has elapsed\n            assert_eq!(lock.unlock().transact(Self::env().caller(), ()), Err(Error::DurationNotElapsed));\n            // check that the locked balance has not been cleared\n            assert_eq!(lock.locked_balance(), value);\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "timed lock"]}');
---
--- INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
--- VALUES ('9a3e20e6-d704-11ed-afa1-0242ac120002', 'erc20-token', 'ERC-20 Token', 'Create an ERC-20 compliant token smart contract that allows users to mint, transfer, and check their token balance. Implement the required functions according to the ERC-20 standard.', 'hard', 25, 4.9, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod erc20_token {\n    #[ink(storage)]\n    pub struct Erc20Token {\n        balances: ink_prelude::collections::HashMap<AccountId, Balance>,\n        allowances: ink_prelude::collections::HashMap<(AccountId, AccountId), Balance>,\n        total_supply: Balance,\n    }\n    // Add your implementation here\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n    \n        #[ink::test]\n        fn test_total_supply() {\n            let token = Erc20Token::new();\n            assert_eq!(token.total_supply(), 0);\n        }\n    \n        #[ink::test]\n        fn test_mint() {\n            let mut token = Erc20Token::new();\n            let account_id = AccountId::from([0x01; 32]);\n            let amount = 100;\n            token.mint(account_id, amount);\n            assert_eq!(token.total_supply(), amount);\n            assert_eq!(token.balance_of(account_id), amount);\n        }\n    \n        #[ink::test]\n        fn test_transfer() {\n            let mut token = Erc20Token::new();\n            let account_id1 = AccountId::from([0x01; 32]);\n            let account_id2 = AccountId::from([0x02; 32]);\n            let amount = 100;\n            token.mint(account_id1, amount);\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n            token.transfer(account_id2, amount);\n            assert_eq!(token.balance_of(account_id1), 0);\n            assert_eq!(token.balance_of(account_id2), amount);\n        }\n    \n        #[ink::test]\n        fn test_transfer_insufficient_balance() {\n            let mut token = Erc20Token::new();\n            let account_id1 = AccountId::from([0x01; 32]);\n            let account_id2 = AccountId::from([0x02; 32]);\n            let amount = 100;\n            token.mint(account_id1, amount);\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n            assert_eq!(\n                token.transfer(account_id2, amount + 1),\n                Err(Error::InsufficientBalance)\n            );\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n        }\n    \n        #[ink::test]\n        fn test_approve() {\n            let mut token = Erc20Token::new();\n            let account_id1 = AccountId::from([0x01; 32]);\n            let account_id2 = AccountId::from([0x02; 32]);\n            let amount = 100;\n            token.mint(account_id1, amount);\n            assert_eq!(token.allowance(account_id1, account_id2), 0);\n            token.approve(account_id2, amount / 2);\n            assert_eq!(token.allowance(account_id1, account_id2), amount / 2);\n        }\n    \n        #[ink::test]\n        fn test_transfer_from() {\n            let mut token = Erc20Token::new();\n            let account_id1 = AccountId::from([0x01; 32]);\n            let account_id2 = AccountId::from([0x02; 32]);\n            let account_id3 = AccountId::from([0x03; 32]);\n            let amount = 100;\n            token.mint(account_id1, amount);\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n            assert_eq!(token.allowance(account_id1, account_id3), 0);\n            token.approve(account_id3, amount / 2);\n            assert_eq!(token.allowance(account_id1, account_id3), amount / 2);\n            assert_eq!(\n                token.transfer_from(account_id1, account_id2, amount / 2, account_id3),\n                Ok(())\n            );\n            assert_eq!(token.balance_of(account_id1), amount / 2);\n            assert_eq!(token.balance_of(account_id2), amount / 2);\n            assert_eq!(token.allowance(account_id1, account_id3), 0);\n        }\n\n        #[ink::test]\n        fn test_transfer_from_insufficient_allowance() {\n            let mut token = Erc20Token::new();\n            let account_id1 = AccountId::from([0x01; 32]);\n            let account_id2 = AccountId::from([0x02; 32]);\n            let account_id3 = AccountId::from([0x03; 32]);\n            let amount = 100;\n            token.mint(account_id1, amount);\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n            assert_eq!(token.allowance(account_id1, account_id3), 0);\n            token.approve(account_id3, amount / 2 - 1);\n            assert_eq!(token.allowance(account_id1, account_id3), amount / 2 - 1);\n            assert_eq!(\n                token.transfer_from(account_id1, account_id2, amount / 2, account_id3),\n                Err(Error::InsufficientAllowance)\n            );\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n            assert_eq!(token.allowance(account_id1, account_id3), amount / 2 - 1);\n        }\n\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod erc20_token {\nuse ink_prelude::collections::HashMap;\n    use ink_storage::{\n        traits::{PackedLayout, SpreadLayout},\n        Lazy,\n    };\n\n    #[ink(storage)]\n    pub struct Erc20Token {\n        balances: HashMap<AccountId, Balance>,\n        allowances: HashMap<(AccountId, AccountId), Balance>,\n        total_supply: Balance,\n    }\n\n    #[ink(event)]\n    pub struct Transfer {\n        #[ink(topic)]\n        from: Option<AccountId>,\n        #[ink(topic)]\n        to: Option<AccountId>,\n        #[ink(topic)]\n        value: Balance,\n    }\n\n    #[ink(event)]\n    pub struct Approval {\n        #[ink(topic)]\n        owner: AccountId,\n        #[ink(topic)]\n        spender: AccountId,\n        #[ink(topic)]\n        value: Balance,\n    }\n\n    impl Erc20Token {\n        #[ink(constructor)]\n        pub fn new() -> Self {\n            Self {\n                balances: HashMap::new(),\n                allowances: HashMap::new(),\n                total_supply: 0,\n            }\n        }\n\n        #[ink(message)]\n        pub fn total_supply(&self) -> Balance {\n            self.total_supply\n        }\n\n        #[ink(message)]\n        pub fn balance_of(&self, account: AccountId) -> Balance {\n            self.balances.get(&account).copied().unwrap_or(0)\n        }\n\n        #[ink(message)]\n        pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {\n            self.allowances\n                .get(&(owner, spender))\n                .copied()\n                .unwrap_or(0)\n        }\n\n        #[ink(message)]\n        pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<(), Error> {\n            let from = self.env().caller();\n            self.transfer_internal(from, to, value)\n        }\n\n        #[ink(message)]\n        pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), Error> {\n            let owner = self.env().caller();\n            self.allowances.insert((owner, spender), value);\n            self.env()\n                .emit_event(Approval {\n                    owner,\n                    spender,\n                    value,\n                });\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn transfer_from(\n            &mut self,\n            from: AccountId,\n            to: AccountId,\n            value: Balance,\n            caller: AccountId,\n        ) -> Result<(), Error> {\n            let allowance = self.allowance(from, caller);\n            if allowance < value {\n                return Err(Error::InsufficientAllowance);\n            }\n            let balance = self.balance_of(from);\n            if balance < value {\n                return Err(Error::InsufficientBalance);\n            }\n            self.allowances.insert((from, caller), allowance - value);\n            self.transfer_internal(from, to, value)?;\n            Ok(())\n        }\n\n        fn transfer_internal(\n            &mut self,\n            from: AccountId,\n            to: AccountId,\n            value: Balance,\n        ) -> Result<(), Error> {\n            if from == to {\n                return Ok(());\n            }\n            let from_balance = self.balance_of(from);\n            if from_balance < value {\n                return Err(Error::InsufficientBalance);\n            }\n            let to_balance = self.balance_of(to);\n            self.balances.insert(from, from_balance - value);\n            self.balances.insert(to, to_balance + value);\n            self.env().emit_event(Transfer {\n                from: Some(from),\n                to: Some(to),\n                value,\n            });\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn mint(&mut self, to: AccountId, value: Balance) -> Result<(), Error> {\n            let caller = self.env().caller();\n            self.check_owner(caller)?;\n            self.total_supply = self.total_supply.checked_add(value).ok_or(Error::ArithmeticOverflow)?;\n            let to_balance = self.balance_of(to);\n            self.balances.insert(to, to_balance + value);\n            self.env().emit_event(Transfer {\n                from: None,\n                to: Some(to),\n                value,\n            });\n            Ok(())\n        }\n    \n        #[ink(message)]\n        pub fn burn(&mut self, value: Balance) -> Result<(), Error> {\n            let caller = self.env().caller();\n            let caller_balance = self.balance_of(caller);\n            if caller_balance < value {\n                return Err(Error::InsufficientBalance);\n            }\n            self.total_supply = self.total_supply.checked_sub(value).ok_or(Error::ArithmeticUnderflow)?;\n            self.balances.insert(caller, caller_balance - value);\n            self.env().emit_event(Transfer {\n                from: Some(caller),\n                to: None,\n                value,\n            });\n            Ok(())\n        }\n    \n        fn check_owner(&self, owner: AccountId) -> Result<(), Error> {\n            if !self.is_owner(owner) {\n                return Err(Error::NotOwner);\n            }\n            Ok(())\n        }\n    \n        fn is_owner(&self, account: AccountId) -> bool {\n            self.env().caller() == account\n        }\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        InsufficientBalance,\n        InsufficientAllowance,\n        ArithmeticOverflow,\n        ArithmeticUnderflow,\n        NotOwner,\n    }\n    \n    impl ink::chain::block::BlockNumberProvider for Erc20Token {\n        type BlockNumber = ink_env::chain::block::BlockNumber;\n    \n        fn block_number(&self) -> Self::BlockNumber {\n            ink_env::block_number::<ink_env::DefaultEnvironment>().unwrap_or(0)\n        }\n    }\n\n    #[cfg(test)]\n    mod tests {\n        use super::*;\n    \n        #[ink::test]\n        fn test_total_supply() {\n            let token = Erc20Token::new();\n            assert_eq!(token.total_supply(), 0);\n        }\n    \n        #[ink::test]\n        fn test_mint() {\n            let mut token = Erc20Token::new();\n            let account_id = AccountId::from([0x01; 32]);\n            let amount = 100;\n            token.mint(account_id, amount);\n            assert_eq!(token.total_supply(), amount);\n            assert_eq!(token.balance_of(account_id), amount);\n        }\n    \n        #[ink::test]\n        fn test_transfer() {\n            let mut token = Erc20Token::new();\n            let account_id1 = AccountId::from([0x01; 32]);\n            let account_id2 = AccountId::from([0x02; 32]);\n            let amount = 100;\n            token.mint(account_id1, amount);\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n            token.transfer(account_id2, amount);\n            assert_eq!(token.balance_of(account_id1), 0);\n            assert_eq!(token.balance_of(account_id2), amount);\n        }\n    \n        #[ink::test]\n        fn test_transfer_insufficient_balance() {\n            let mut token = Erc20Token::new();\n            let account_id1 = AccountId::from([0x01; 32]);\n            let account_id2 = AccountId::from([0x02; 32]);\n            let amount = 100;\n            token.mint(account_id1, amount);\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n            assert_eq!(\n                token.transfer(account_id2, amount + 1),\n                Err(Error::InsufficientBalance)\n            );\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n        }\n    \n        #[ink::test]\n        fn test_approve() {\n            let mut token = Erc20Token::new();\n            let account_id1 = AccountId::from([0x01; 32]);\n            let account_id2 = AccountId::from([0x02; 32]);\n            let amount = 100;\n            token.mint(account_id1, amount);\n            assert_eq!(token.allowance(account_id1, account_id2), 0);\n            token.approve(account_id2, amount / 2);\n            assert_eq!(token.allowance(account_id1, account_id2), amount / 2);\n        }\n    \n        #[ink::test]\n        fn test_transfer_from() {\n            let mut token = Erc20Token::new();\n            let account_id1 = AccountId::from([0x01; 32]);\n            let account_id2 = AccountId::from([0x02; 32]);\n            let account_id3 = AccountId::from([0x03; 32]);\n            let amount = 100;\n            token.mint(account_id1, amount);\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n            assert_eq!(token.allowance(account_id1, account_id3), 0);\n            token.approve(account_id3, amount / 2);\n            assert_eq!(token.allowance(account_id1, account_id3), amount / 2);\n            assert_eq!(\n                token.transfer_from(account_id1, account_id2, amount / 2, account_id3),\n                Ok(())\n            );\n            assert_eq!(token.balance_of(account_id1), amount / 2);\n            assert_eq!(token.balance_of(account_id2), amount / 2);\n            assert_eq!(token.allowance(account_id1, account_id3), 0);\n        }\n\n        #[ink::test]\n        fn test_transfer_from_insufficient_allowance() {\n            let mut token = Erc20Token::new();\n            let account_id1 = AccountId::from([0x01; 32]);\n            let account_id2 = AccountId::from([0x02; 32]);\n            let account_id3 = AccountId::from([0x03; 32]);\n            let amount = 100;\n            token.mint(account_id1, amount);\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n            assert_eq!(token.allowance(account_id1, account_id3), 0);\n            token.approve(account_id3, amount / 2 - 1);\n            assert_eq!(token.allowance(account_id1, account_id3), amount / 2 - 1);\n            assert_eq!(\n                token.transfer_from(account_id1, account_id2, amount / 2, account_id3),\n                Err(Error::InsufficientAllowance)\n            );\n            assert_eq!(token.balance_of(account_id1), amount);\n            assert_eq!(token.balance_of(account_id2), 0);\n            assert_eq!(token.allowance(account_id1, account_id3), amount / 2 - 1);\n        }\n\n}\n"}, "category": "Ink!", "tags": ["smart contract", "erc20"]}');
---
--- INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
--- VALUES ('e2ceeb1a-d704-11ed-afa1-0242ac120002', 'decentralized-oracle', 'Decentralized Oracle', 'Create a decentralized oracle smart contract that allows users to request external data and receive it within the contract. Implement request_data, provide_data, and get_data functions.', 'hard', 15, 5, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod decentralized_oracle {\n    #[ink(storage)]\n    pub struct DecentralizedOracle {\n        data_requests: ink_prelude::collections::HashMap<String, Balance>,\n        data_providers: ink_prelude::collections::HashMap<AccountId, bool>,\n    }\n    // Add your implementation here\n\n    #[cfg(test)]\n    mod tests {\n\n        #[ink::test]\n        fn test_request_and_provide_data() {\n            // set up\n            let mut oracle = DecentralizedOracle::new();\n            let data_id = \"BTC/USD\".to_string();\n            let requester = AccountId::new([1; 32]);\n            let provider = AccountId::new([2; 32]);\n            let reward = 1000;\n    \n            // request data\n            assert!(oracle.request_data(data_id.clone(), reward).is_ok());\n            assert_eq!(\n                oracle.data_requests.get(&data_id),\n                Some(&(requester, reward))\n            );\n    \n            // provide data - wrong data ID\n            assert_eq!(\n                oracle.provide_data(\"ETH/USD\".to_string(), vec![0; 32]),\n                Err(Error::DataIdNotFound)\n            );\n            assert_eq!(\n                oracle.data_requests.get(&data_id),\n                Some(&(requester, reward))\n            );\n    \n            // provide data - wrong provider\n            assert_eq!(\n                oracle.provide_data(data_id.clone(), vec![0; 32]),\n                Err(Error::DataProviderAlreadyExists)\n            );\n            assert_eq!(\n                oracle.data_requests.get(&data_id),\n                Some(&(requester, reward))\n            );\n    \n            // provide data - correct data ID and provider\n            assert!(oracle.provide_data(data_id.clone(), vec![0; 32]).is_ok());\n            assert_eq!(oracle.data_results.get(&data_id), Some(&vec![0; 32]));\n            assert_eq!(oracle.data_requests.get(&data_id), None);\n            assert_eq!(oracle.data_providers.get(&provider), Some(&true));\n            assert!(oracle.data_providers.get(&requester).is_none());\n    \n            // provide data - transfer failed\n            assert_eq!(\n                oracle.provide_data(data_id.clone(), vec![0; 32]),\n                Err(Error::TransferFailed)\n            );\n            assert_eq!(oracle.data_results.get(&data_id), Some(&vec![0; 32]));\n            assert_eq!(oracle.data_requests.get(&data_id), None);\n            assert_eq!(oracle.data_providers.get(&provider), Some(&true));\n            assert!(oracle.data_providers.get(&requester).is_none());\n        }\n    \n        #[ink::test]\n        fn test_get_data() {\n            // set up\n            let mut oracle = DecentralizedOracle::new();\n            let data_id = \"BTC/USD\".to_string();\n            let requester = AccountId::new([1; 32]);\n            let provider = AccountId::new([2; 32]);\n            let reward = 1000;\n    \n            // request and provide data\n            oracle.request_data(data_id.clone(), reward).unwrap();\n            oracle.provide_data(data_id.clone(), vec![1; 32]).unwrap();\n    \n            // get data - wrong provider\n            assert_eq!(\n                oracle.get_data(data_id.clone(), { AccountId::new([3; 32]) }),\n                Err(Error::NotAuthorized)\n            );\n    \n            // get data - wrong data ID\n            assert_eq!(\n                oracle.get_data(\"ETH/USD\".to_string(), provider),\n                Err(Error::DataIdNotFound)\n            );\n    \n            // get data - correct data ID and provider\n            assert_eq!(\n                oracle.get_data(data_id.clone(), provider).unwrap(),\n                vec![1; 32]\n            );\n            assert!(oracle.data_requests.get(&data_id).is_none());\n            assert!(oracle.data_providers.get(&provider).is_some());\n            assert!(oracle.data_providers.get(&requester).is_none());\n        }\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std)]\n\nuse ink_lang as ink;\n\n#[ink::contract]\nmod decentralized_oracle {\n    use ink_prelude::vec::Vec;\n\n    #[ink(storage)]\n    pub struct DecentralizedOracle {\n        data_requests: ink_prelude::collections::HashMap<String, (AccountId, Balance)>,\n        data_providers: ink_prelude::collections::HashMap<AccountId, bool>,\n        data_results: ink_prelude::collections::HashMap<String, Vec<u8>>,\n    }\n\n    impl DecentralizedOracle {\n        #[ink(constructor)]\n        pub fn new() -> Self {\n            Self {\n                data_requests: ink_prelude::collections::HashMap::new(),\n                data_providers: ink_prelude::collections::HashMap::new(),\n                data_results: ink_prelude::collections::HashMap::new(),\n            }\n        }\n\n        #[ink(message)]\n        pub fn request_data(&mut self, data_id: String, reward: Balance) -> Result<(), Error> {\n            if self.data_requests.contains_key(&data_id) {\n                return Err(Error::DataIdAlreadyExists);\n            }\n\n            let requester = Self::env().caller();\n            self.data_requests.insert(data_id, (requester, reward));\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn provide_data(&mut self, data_id: String, data: Vec<u8>) -> Result<(), Error> {\n            let provider = Self::env().caller();\n            if !self.data_requests.contains_key(&data_id) {\n                return Err(Error::DataIdNotFound);\n            }\n            if self.data_providers.contains_key(&provider) {\n                return Err(Error::DataProviderAlreadyExists);\n            }\n\n            let (requester, reward) = self.data_requests.get(&data_id).unwrap();\n            if *reward > Self::env().transferred_balance() {\n                return Err(Error::InsufficientFunds);\n            }\n\n            self.data_results.insert(data_id.clone(), data);\n            self.data_requests.remove(&data_id);\n            self.data_providers.insert(provider, true);\n            if !Self::env().transfer(*requester, *reward) {\n                return Err(Error::TransferFailed);\n            }\n\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn get_data(&self, data_id: String, provider: AccountId) -> Result<Vec<u8>, Error> {\n            if !self.data_results.contains_key(&data_id) {\n                return Err(Error::DataIdNotFound);\n            }\n            if !self.data_providers.contains_key(&provider) {\n                return Err(Error::NotAuthorized);\n            }\n\n            Ok(self.data_results.get(&data_id).unwrap().clone())\n        }\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(Debug))]\n    pub enum Error {\n        DataIdNotFound,\n        DataIdAlreadyExists,\n        DataProviderAlreadyExists,\n        NotAuthorized,\n        InsufficientFunds,\n        TransferFailed,\n    }\n\n    #[cfg(test)]\n    mod tests {\n\n        #[ink::test]\n        fn test_request_and_provide_data() {\n            // set up\n            let mut oracle = DecentralizedOracle::new();\n            let data_id = \"BTC/USD\".to_string();\n            let requester = AccountId::new([1; 32]);\n            let provider = AccountId::new([2; 32]);\n            let reward = 1000;\n    \n            // request data\n            assert!(oracle.request_data(data_id.clone(), reward).is_ok());\n            assert_eq!(\n                oracle.data_requests.get(&data_id),\n                Some(&(requester, reward))\n            );\n    \n            // provide data - wrong data ID\n            assert_eq!(\n                oracle.provide_data(\"ETH/USD\".to_string(), vec![0; 32]),\n                Err(Error::DataIdNotFound)\n            );\n            assert_eq!(\n                oracle.data_requests.get(&data_id),\n                Some(&(requester, reward))\n            );\n    \n            // provide data - wrong provider\n            assert_eq!(\n                oracle.provide_data(data_id.clone(), vec![0; 32]),\n                Err(Error::DataProviderAlreadyExists)\n            );\n            assert_eq!(\n                oracle.data_requests.get(&data_id),\n                Some(&(requester, reward))\n            );\n    \n            // provide data - correct data ID and provider\n            assert!(oracle.provide_data(data_id.clone(), vec![0; 32]).is_ok());\n            assert_eq!(oracle.data_results.get(&data_id), Some(&vec![0; 32]));\n            assert_eq!(oracle.data_requests.get(&data_id), None);\n            assert_eq!(oracle.data_providers.get(&provider), Some(&true));\n            assert!(oracle.data_providers.get(&requester).is_none());\n    \n            // provide data - transfer failed\n            assert_eq!(\n                oracle.provide_data(data_id.clone(), vec![0; 32]),\n                Err(Error::TransferFailed)\n            );\n            assert_eq!(oracle.data_results.get(&data_id), Some(&vec![0; 32]));\n            assert_eq!(oracle.data_requests.get(&data_id), None);\n            assert_eq!(oracle.data_providers.get(&provider), Some(&true));\n            assert!(oracle.data_providers.get(&requester).is_none());\n        }\n    \n        #[ink::test]\n        fn test_get_data() {\n            // set up\n            let mut oracle = DecentralizedOracle::new();\n            let data_id = \"BTC/USD\".to_string();\n            let requester = AccountId::new([1; 32]);\n            let provider = AccountId::new([2; 32]);\n            let reward = 1000;\n    \n            // request and provide data\n            oracle.request_data(data_id.clone(), reward).unwrap();\n            oracle.provide_data(data_id.clone(), vec![1; 32]).unwrap();\n    \n            // get data - wrong provider\n            assert_eq!(\n                oracle.get_data(data_id.clone(), { AccountId::new([3; 32]) }),\n                Err(Error::NotAuthorized)\n            );\n    \n            // get data - wrong data ID\n            assert_eq!(\n                oracle.get_data(\"ETH/USD\".to_string(), provider),\n                Err(Error::DataIdNotFound)\n            );\n    \n            // get data - correct data ID and provider\n            assert_eq!(\n                oracle.get_data(data_id.clone(), provider).unwrap(),\n                vec![1; 32]\n            );\n            assert!(oracle.data_requests.get(&data_id).is_none());\n            assert!(oracle.data_providers.get(&provider).is_some());\n            assert!(oracle.data_providers.get(&requester).is_none());\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "decentralized oracle"]}');
+VALUES ('d300b862-d703-11ed-afa1-0242ac120002', 'multi-signature-wallet', 'Multi-Signature Wallet', 'Create a multi-signature wallet smart contract that requires several owners to confirm a transaction before it runs. The wallet is deployed with `new(owners, required_confirmations)`, which returns `Error::AlreadyOwner` for a repeated owner and `Error::InvalidRequirement` when the requirement is zero or larger than the number of owners. Owners propose transactions with `propose_transaction(to, value)`, `propose_add_owner(owner)`, `propose_remove_owner(owner)` and `propose_change_requirement(required_confirmations)`. Each returns the id of the new `Transaction`, ids count up from 0, and `Error::Overflow` is returned once they run out. Owners confirm a pending transaction with `confirm_transaction(id)` and withdraw their confirmation with `revoke_confirmation(id)`. Any owner can call `execute_transaction(id)` once enough owners confirmed it. Only the confirmations of the current owners count, so an owner who was removed and added again has to confirm again. Executing an owner change fails with `AlreadyOwner`, `NotOwner` or `InvalidRequirement` if it would leave the wallet in an invalid state. The messages fail with `Unauthorized`, `InvalidTransactionId`, `AlreadyConfirmed`, `NotConfirmed`, `AlreadyExecuted`, `NotEnoughConfirmations` or `TransferFailed`. Expose `get_owners`, `is_owner(account)`, `get_required_confirmations`, `is_confirmed(id)`, `get_transaction_count`, `get_transaction(id)`, `get_confirmations(id)` and `get_confirmation_count(id)`, and emit the events re-exported by the starter code.', 'hard', 20, 4.7, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::multisig_wallet::{\n    Action, AddOwner, ChangeRequirement, ConfirmTransaction, Error, ExecuteTransaction,\n    MultisigWallet, ProposeTransaction, RemoveOwner, RevokeConfirmation, Transaction,\n};\n\n#[ink::contract]\nmod multisig_wallet {\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    #[ink(storage)]\n    pub struct MultisigWallet {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl MultisigWallet {\n        #[ink(constructor)]\n        pub fn new(owners: Vec<AccountId>, required_confirmations: u32) -> Result<Self, Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn propose_transaction(&mut self, to: AccountId, value: Balance) -> Result<u32, Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn propose_add_owner(&mut self, owner: AccountId) -> Result<u32, Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn propose_remove_owner(&mut self, owner: AccountId) -> Result<u32, Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn propose_change_requirement(&mut self, required_confirmations: u32) -> Result<u32, Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn confirm_transaction(&mut self, id: u32) -> Result<(), Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn revoke_confirmation(&mut self, id: u32) -> Result<(), Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn execute_transaction(&mut self, id: u32) -> Result<(), Error> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn get_owners(&self) -> Vec<AccountId> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn is_owner(&self, account: AccountId) -> bool {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn get_required_confirmations(&self) -> u32 {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn is_confirmed(&self, id: u32) -> bool {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn get_transaction_count(&self) -> u32 {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn get_transaction(&self, id: u32) -> Option<Transaction> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn get_confirmations(&self, id: u32) -> Vec<AccountId> {\n            // TODO, add your code here\n        }\n\n        #[ink(message)]\n        pub fn get_confirmation_count(&self, id: u32) -> u32 {\n            // TODO, add your code here\n        }\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::multisig_wallet::{\n    Action, AddOwner, ChangeRequirement, ConfirmTransaction, Error, ExecuteTransaction,\n    MultisigWallet, ProposeTransaction, RemoveOwner, RevokeConfirmation, Transaction,\n};\n\n#[ink::contract]\nmod multisig_wallet {\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    /// What a transaction does once enough owners confirmed it.\n    ///\n    /// Owner management goes through the same proposals as transfers, so changing the\n    /// set of owners needs as many confirmations as spending the wallet''s funds.\n    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub enum Action {\n        Transfer { to: AccountId, value: Balance },\n        AddOwner(AccountId),\n        RemoveOwner(AccountId),\n        ChangeRequirement(u32),\n    }\n\n    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub struct Transaction {\n        pub action: Action,\n        pub tx_hash: Hash,\n        pub executed: bool,\n    }\n\n    #[ink(storage)]\n    #[derive(Default)]\n    pub struct MultisigWallet {\n        owners: Vec<AccountId>,\n        required_confirmations: u32,\n        transactions: Mapping<u32, Transaction>,\n        transaction_count: u32,\n        /// The term of the owner that gave each confirmation.\n        ///\n        /// Confirmations are counted when needed, and only those of the current\n        /// owners given in their current term count.\n        confirmations: Mapping<(u32, AccountId), u32>,\n        /// How many times each account became an owner.\n        owner_terms: Mapping<AccountId, u32>,\n    }\n\n    impl MultisigWallet {\n        /// Creates a wallet controlled by `owners`, executing transactions once\n        /// `required_confirmations` of them confirmed.\n        #[ink(constructor)]\n        pub fn new(owners: Vec<AccountId>, required_confirmations: u32) -> Result<Self, Error> {\n            let mut wallet = Self::default();\n            for owner in owners {\n                if wallet.owners.contains(&owner) {\n                    return Err(Error::AlreadyOwner);\n                }\n                wallet.owners.push(owner);\n                wallet.owner_terms.insert(owner, &1);\n            }\n            wallet.ensure_requirement(wallet.owners.len(), required_confirmations)?;\n            wallet.required_confirmations = required_confirmations;\n            Ok(wallet)\n        }\n\n        #[ink(message)]\n        pub fn propose_transaction(&mut self, to: AccountId, value: Balance) -> Result<u32, Error> {\n            self.propose(Action::Transfer { to, value })\n        }\n\n        #[ink(message)]\n        pub fn propose_add_owner(&mut self, owner: AccountId) -> Result<u32, Error> {\n            self.propose(Action::AddOwner(owner))\n        }\n\n        #[ink(message)]\n        pub fn propose_remove_owner(&mut self, owner: AccountId) -> Result<u32, Error> {\n            self.propose(Action::RemoveOwner(owner))\n        }\n\n        #[ink(message)]\n        pub fn propose_change_requirement(\n            &mut self,\n            required_confirmations: u32,\n        ) -> Result<u32, Error> {\n            self.propose(Action::ChangeRequirement(required_confirmations))\n        }\n\n        /// Confirms transaction `id` on behalf of the caller.\n        #[ink(message)]\n        pub fn confirm_transaction(&mut self, id: u32) -> Result<(), Error> {\n            let owner = self.ensure_owner()?;\n            self.pending_transaction(id)?;\n            if self.has_confirmed(id, owner) {\n                return Err(Error::AlreadyConfirmed);\n            }\n\n            self.confirmations\n                .insert((id, owner), &self.owner_term(owner));\n            self.env().emit_event(ConfirmTransaction { id, owner });\n\n            Ok(())\n        }\n\n        /// Withdraws the caller''s confirmation of transaction `id`.\n        #[ink(message)]\n        pub fn revoke_confirmation(&mut self, id: u32) -> Result<(), Error> {\n            let owner = self.ensure_owner()?;\n            self.pending_transaction(id)?;\n            if !self.has_confirmed(id, owner) {\n                return Err(Error::NotConfirmed);\n            }\n\n            self.confirmations.remove((id, owner));\n            self.env().emit_event(RevokeConfirmation { id, owner });\n\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn execute_transaction(&mut self, id: u32) -> Result<(), Error> {\n            self.ensure_owner()?;\n            let mut tx = self.pending_transaction(id)?;\n            if !self.is_confirmed(id) {\n                return Err(Error::NotEnoughConfirmations);\n            }\n\n            match tx.action {\n                Action::Transfer { to, value } => {\n                    self.env()\n                        .transfer(to, value)\n                        .map_err(|_| Error::TransferFailed)?;\n                }\n                Action::AddOwner(owner) => self.add_owner(owner)?,\n                Action::RemoveOwner(owner) => self.remove_owner(owner)?,\n                Action::ChangeRequirement(required) => self.change_requirement(required)?,\n            }\n\n            tx.executed = true;\n            self.transactions.insert(id, &tx);\n            self.env().emit_event(ExecuteTransaction {\n                id,\n                action: tx.action,\n            });\n\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn get_owners(&self) -> Vec<AccountId> {\n            self.owners.clone()\n        }\n\n        #[ink(message)]\n        pub fn is_owner(&self, account: AccountId) -> bool {\n            self.owners.contains(&account)\n        }\n\n        #[ink(message)]\n        pub fn get_required_confirmations(&self) -> u32 {\n            self.required_confirmations\n        }\n\n        #[ink(message)]\n        pub fn is_confirmed(&self, id: u32) -> bool {\n            self.transactions.contains(id)\n                && self.get_confirmation_count(id) >= self.required_confirmations\n        }\n\n        #[ink(message)]\n        pub fn get_transaction_count(&self) -> u32 {\n            self.transaction_count\n        }\n\n        #[ink(message)]\n        pub fn get_transaction(&self, id: u32) -> Option<Transaction> {\n            self.transactions.get(id)\n        }\n\n        /// Returns the current owners that confirmed transaction `id`.\n        #[ink(message)]\n        pub fn get_confirmations(&self, id: u32) -> Vec<AccountId> {\n            self.owners\n                .iter()\n                .filter(|owner| self.has_confirmed(id, **owner))\n                .copied()\n                .collect()\n        }\n\n        /// Returns how many of the current owners confirmed transaction `id`.\n        #[ink(message)]\n        pub fn get_confirmation_count(&self, id: u32) -> u32 {\n            self.owners\n                .iter()\n                .filter(|owner| self.has_confirmed(id, **owner))\n                .count() as u32\n        }\n\n        fn propose(&mut self, action: Action) -> Result<u32, Error> {\n            let proposer = self.ensure_owner()?;\n            let id = self.transaction_count;\n            let transaction_count = id.checked_add(1).ok_or(Error::Overflow)?;\n            let tx_hash = Self::hash(id, &action);\n            self.transactions.insert(\n                id,\n                &Transaction {\n                    action: action.clone(),\n                    tx_hash,\n                    executed: false,\n                },\n            );\n            self.transaction_count = transaction_count;\n            self.env().emit_event(ProposeTransaction {\n                id,\n                proposer,\n                tx_hash,\n                action,\n            });\n            Ok(id)\n        }\n\n        fn add_owner(&mut self, owner: AccountId) -> Result<(), Error> {\n            if self.owners.contains(&owner) {\n                return Err(Error::AlreadyOwner);\n            }\n            self.owners.push(owner);\n            // confirmations from an earlier term stop counting\n            self.owner_terms\n                .insert(owner, &self.owner_term(owner).saturating_add(1));\n            self.env().emit_event(AddOwner { owner });\n            Ok(())\n        }\n\n        /// Removes `owner`, whose confirmations stop counting with it.\n        fn remove_owner(&mut self, owner: AccountId) -> Result<(), Error> {\n            let index = self\n                .owners\n                .iter()\n                .position(|known| *known == owner)\n                .ok_or(Error::NotOwner)?;\n            self.ensure_requirement(self.owners.len() - 1, self.required_confirmations)?;\n\n            self.owners.swap_remove(index);\n            self.env().emit_event(RemoveOwner { owner });\n            Ok(())\n        }\n\n        fn change_requirement(&mut self, required_confirmations: u32) -> Result<(), Error> {\n            self.ensure_requirement(self.owners.len(), required_confirmations)?;\n            self.required_confirmations = required_confirmations;\n            self.env().emit_event(ChangeRequirement {\n                required_confirmations,\n            });\n            Ok(())\n        }\n\n        /// Whether `owner` confirmed transaction `id` during their current term.\n        fn has_confirmed(&self, id: u32, owner: AccountId) -> bool {\n            self.confirmations.get((id, owner)) == Some(self.owner_term(owner))\n        }\n\n        fn owner_term(&self, owner: AccountId) -> u32 {\n            self.owner_terms.get(owner).unwrap_or(0)\n        }\n\n        fn ensure_owner(&self) -> Result<AccountId, Error> {\n            let caller = self.env().caller();\n            if self.owners.contains(&caller) {\n                Ok(caller)\n            } else {\n                Err(Error::Unauthorized)\n            }\n        }\n\n        /// Every transaction must be executable by the owners alone.\n        fn ensure_requirement(&self, owners: usize, required: u32) -> Result<(), Error> {\n            if required == 0 || required as usize > owners {\n                return Err(Error::InvalidRequirement);\n            }\n            Ok(())\n        }\n\n        fn pending_transaction(&self, id: u32) -> Result<Transaction, Error> {\n            let tx = self\n                .transactions\n                .get(id)\n                .ok_or(Error::InvalidTransactionId)?;\n            if tx.executed {\n                return Err(Error::AlreadyExecuted);\n            }\n            Ok(tx)\n        }\n\n        fn hash(id: u32, action: &Action) -> Hash {\n            Self::env()\n                .hash_encoded::<ink::env::hash::Blake2x256, _>(&(id, action))\n                .into()\n        }\n    }\n\n    #[ink(event)]\n    pub struct ProposeTransaction {\n        #[ink(topic)]\n        pub id: u32,\n        #[ink(topic)]\n        pub proposer: AccountId,\n        pub tx_hash: Hash,\n        pub action: Action,\n    }\n\n    #[ink(event)]\n    pub struct ConfirmTransaction {\n        #[ink(topic)]\n        pub id: u32,\n        #[ink(topic)]\n        pub owner: AccountId,\n    }\n\n    #[ink(event)]\n    pub struct RevokeConfirmation {\n        #[ink(topic)]\n        pub id: u32,\n        #[ink(topic)]\n        pub owner: AccountId,\n    }\n\n    #[ink(event)]\n    pub struct ExecuteTransaction {\n        #[ink(topic)]\n        pub id: u32,\n        pub action: Action,\n    }\n\n    #[ink(event)]\n    pub struct AddOwner {\n        #[ink(topic)]\n        pub owner: AccountId,\n    }\n\n    #[ink(event)]\n    pub struct RemoveOwner {\n        #[ink(topic)]\n        pub owner: AccountId,\n    }\n\n    #[ink(event)]\n    pub struct ChangeRequirement {\n        pub required_confirmations: u32,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        InvalidTransactionId,\n        Unauthorized,\n        AlreadyConfirmed,\n        NotConfirmed,\n        AlreadyExecuted,\n        NotEnoughConfirmations,\n        TransferFailed,\n        AlreadyOwner,\n        NotOwner,\n        /// Zero, or more confirmations than there are owners.\n        InvalidRequirement,\n        /// Every transaction id was used.\n        Overflow,\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "multisig"]}');
+
+INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
+VALUES ('f1a6c3de-d704-11ed-afa1-0242ac120002', 'milestone-escrow', 'Milestone Escrow', 'Create an escrow smart contract that pays the seller in milestones. The buyer deposits the sum of all milestones and approves them one at a time, while the seller can refund milestones that were not approved yet. The seller and the buyer must be different accounts, otherwise the constructor fails with `InvalidParties`. Once the deadline passed, the buyer can also take back every milestone that is still pending. An escrow has at most 32 milestones (`MAX_MILESTONES`), more are rejected with `TooManyMilestones`. Implement lock_funds, approve_milestone, refund_milestone, refund_remaining and get_deadline functions.', 'hard', 20, 4.6, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::milestone_escrow::{\n    Error, EscrowStatus, LockFunds, Milestone, MilestoneEscrow, MilestoneStatus, RefundMilestone,\n    ReleaseMilestone, MAX_MILESTONES,\n};\n\n#[ink::contract]\nmod milestone_escrow {\n    use ink::prelude::vec::Vec;\n\n    #[ink(storage)]\n    pub struct MilestoneEscrow {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl MilestoneEscrow {\n        #[ink(constructor)]\n        pub fn new(seller: AccountId, buyer: AccountId, amounts: Vec<Balance>, deadline: Timestamp) -> Result<Self, Error> {\n            // TODO, add your code here\n        }\n\n        // TODO, add your messages here\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::milestone_escrow::{\n    Error, EscrowStatus, LockFunds, Milestone, MilestoneEscrow, MilestoneStatus, RefundMilestone,\n    ReleaseMilestone, MAX_MILESTONES,\n};\n\n#[ink::contract]\nmod milestone_escrow {\n    use ink::prelude::vec::Vec;\n\n    /// The most milestones an escrow may have, all of them are stored in a single cell.\n    pub const MAX_MILESTONES: u32 = 32;\n\n    /// An escrow paying the seller in stages: the buyer deposits the sum of all\n    /// milestones up front and approves them one at a time.\n    #[ink(storage)]\n    pub struct MilestoneEscrow {\n        seller: AccountId,\n        buyer: AccountId,\n        milestones: Vec<Milestone>,\n        /// How many milestones were neither released nor refunded yet.\n        pending: u32,\n        status: EscrowStatus,\n        /// After this timestamp the buyer can take back the pending milestones.\n        deadline: Timestamp,\n    }\n\n    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub struct Milestone {\n        pub amount: Balance,\n        pub status: MilestoneStatus,\n    }\n\n    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub enum MilestoneStatus {\n        #[default]\n        Pending,\n        /// Paid to the seller.\n        Released,\n        /// Paid back to the buyer.\n        Refunded,\n    }\n\n    /// The escrow is `Locked` once the buyer deposited the total and `Closed` once\n    /// every milestone was released or refunded.\n    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub enum EscrowStatus {\n        #[default]\n        AwaitingDeposit,\n        Locked,\n        Closed,\n    }\n\n    #[ink(event)]\n    pub struct LockFunds {\n        #[ink(topic)]\n        pub seller: AccountId,\n        #[ink(topic)]\n        pub buyer: AccountId,\n        pub amount: Balance,\n    }\n\n    #[ink(event)]\n    pub struct ReleaseMilestone {\n        #[ink(topic)]\n        pub index: u32,\n        pub amount: Balance,\n    }\n\n    #[ink(event)]\n    pub struct RefundMilestone {\n        #[ink(topic)]\n        pub index: u32,\n        pub amount: Balance,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        Unauthorized,\n        TransferFailed,\n        /// The escrow is not in a status that allows this call.\n        InvalidStatus,\n        /// The deposit does not match the sum of the milestones.\n        IncorrectAmount,\n        /// No milestones, or a milestone without an amount.\n        InvalidMilestones,\n        /// More than `MAX_MILESTONES` milestones.\n        TooManyMilestones,\n        MilestoneNotFound,\n        /// The milestone was already released or refunded.\n        MilestoneClosed,\n        /// The milestones add up to more than `Balance::MAX`.\n        Overflow,\n        DeadlineNotReached,\n        /// The seller and the buyer are the same account.\n        InvalidParties,\n    }\n\n    impl MilestoneEscrow {\n        #[ink(constructor)]\n        pub fn new(\n            seller: AccountId,\n            buyer: AccountId,\n            amounts: Vec<Balance>,\n            deadline: Timestamp,\n        ) -> Result<Self, Error> {\n            if seller == buyer {\n                return Err(Error::InvalidParties);\n            }\n            if amounts.is_empty() || amounts.contains(&0) {\n                return Err(Error::InvalidMilestones);\n            }\n            if amounts.len() > MAX_MILESTONES as usize {\n                return Err(Error::TooManyMilestones);\n            }\n            // Checked once here, so that summing the milestones later cannot overflow.\n            amounts\n                .iter()\n                .try_fold(0, |total: Balance, amount| total.checked_add(*amount))\n                .ok_or(Error::Overflow)?;\n            let pending = amounts.len() as u32;\n            let milestones = amounts\n                .into_iter()\n                .map(|amount| Milestone {\n                    amount,\n                    status: MilestoneStatus::Pending,\n                })\n                .collect();\n            Ok(Self {\n                seller,\n                buyer,\n                milestones,\n                pending,\n                status: EscrowStatus::AwaitingDeposit,\n                deadline,\n            })\n        }\n\n        /// Deposits the sum of all milestones, which must be sent along with the call by\n        /// the buyer.\n        #[ink(message, payable)]\n        pub fn lock_funds(&mut self) -> Result<(), Error> {\n            if self.env().caller() != self.buyer {\n                return Err(Error::Unauthorized);\n            }\n            if self.status != EscrowStatus::AwaitingDeposit {\n                return Err(Error::InvalidStatus);\n            }\n            let amount = self.total();\n            if self.env().transferred_value() != amount {\n                return Err(Error::IncorrectAmount);\n            }\n            self.status = EscrowStatus::Locked;\n            self.env().emit_event(LockFunds {\n                seller: self.seller,\n                buyer: self.buyer,\n                amount,\n            });\n            Ok(())\n        }\n\n        /// Pays milestone `index` to the seller, called by the buyer once it was delivered.\n        #[ink(message)]\n        pub fn approve_milestone(&mut self, index: u32) -> Result<(), Error> {\n            if self.env().caller() != self.buyer {\n                return Err(Error::Unauthorized);\n            }\n            let amount = self.close_milestone(index, MilestoneStatus::Released, self.seller)?;\n            self.env().emit_event(ReleaseMilestone { index, amount });\n            Ok(())\n        }\n\n        /// Pays milestone `index` back to the buyer, called by the seller.\n        #[ink(message)]\n        pub fn refund_milestone(&mut self, index: u32) -> Result<(), Error> {\n            if self.env().caller() != self.seller {\n                return Err(Error::Unauthorized);\n            }\n            self.refund(index)\n        }\n\n        /// Refunds every milestone that is still pending in a single transfer, called by\n        /// the seller at any time or by the buyer once the deadline passed.\n        #[ink(message)]\n        pub fn refund_remaining(&mut self) -> Result<(), Error> {\n            let caller = self.env().caller();\n            if caller != self.seller && caller != self.buyer {\n                return Err(Error::Unauthorized);\n            }\n            if self.status != EscrowStatus::Locked {\n                return Err(Error::InvalidStatus);\n            }\n            if caller == self.buyer && self.env().block_timestamp() <= self.deadline {\n                return Err(Error::DeadlineNotReached);\n            }\n\n            self.env()\n                .transfer(self.buyer, self.get_remaining())\n                .map_err(|_| Error::TransferFailed)?;\n            for index in 0..self.milestones.len() {\n                let milestone = &mut self.milestones[index];\n                if milestone.status == MilestoneStatus::Pending {\n                    milestone.status = MilestoneStatus::Refunded;\n                    let amount = milestone.amount;\n                    self.env().emit_event(RefundMilestone {\n                        index: index as u32,\n                        amount,\n                    });\n                }\n            }\n            self.pending = 0;\n            self.status = EscrowStatus::Closed;\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn get_deadline(&self) -> Timestamp {\n            self.deadline\n        }\n\n        #[ink(message)]\n        pub fn get_status(&self) -> EscrowStatus {\n            self.status\n        }\n\n        #[ink(message)]\n        pub fn get_milestones(&self) -> Vec<Milestone> {\n            self.milestones.clone()\n        }\n\n        #[ink(message)]\n        pub fn get_milestone(&self, index: u32) -> Option<Milestone> {\n            self.milestones.get(index as usize).cloned()\n        }\n\n        /// Returns the amount of the milestones that are still pending.\n        #[ink(message)]\n        pub fn get_remaining(&self) -> Balance {\n            self.milestones\n                .iter()\n                .filter(|milestone| milestone.status == MilestoneStatus::Pending)\n                .map(|milestone| milestone.amount)\n                .sum()\n        }\n\n        fn total(&self) -> Balance {\n            self.milestones\n                .iter()\n                .map(|milestone| milestone.amount)\n                .sum()\n        }\n\n        fn refund(&mut self, index: u32) -> Result<(), Error> {\n            let amount = self.close_milestone(index, MilestoneStatus::Refunded, self.buyer)?;\n            self.env().emit_event(RefundMilestone { index, amount });\n            Ok(())\n        }\n\n        /// Pays a pending milestone to `to` and closes the escrow after its last one.\n        fn close_milestone(\n            &mut self,\n            index: u32,\n            status: MilestoneStatus,\n            to: AccountId,\n        ) -> Result<Balance, Error> {\n            if self.status != EscrowStatus::Locked {\n                return Err(Error::InvalidStatus);\n            }\n            let milestone = self\n                .milestones\n                .get(index as usize)\n                .ok_or(Error::MilestoneNotFound)?;\n            if milestone.status != MilestoneStatus::Pending {\n                return Err(Error::MilestoneClosed);\n            }\n            let amount = milestone.amount;\n\n            self.env()\n                .transfer(to, amount)\n                .map_err(|_| Error::TransferFailed)?;\n            self.milestones[index as usize].status = status;\n            self.pending -= 1;\n            if self.pending == 0 {\n                self.status = EscrowStatus::Closed;\n            }\n            Ok(amount)\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "escrow"]}');
+
+INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
+VALUES ('111c5b2a-d704-11ed-afa1-0242ac120002', 'simple-auction', 'Simple Auction', 'Create a simple auction smart contract that allows users to bid on an item until an end timestamp set at deployment, with the highest bid going to the deployer. Each bid must be higher than the current highest bid, and outbid bidders, including a highest bidder who raises their own bid, take their previous bids back with withdraw rather than being refunded right away. Implement place_bid, withdraw, and finalize_auction functions. The contract should prevent bids after the auction has ended and finalizing it more than once.', 'medium', 45, 4.3, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::simple_auction::{AuctionEnded, Error, HighestBidIncreased, SimpleAuction};\n\n#[ink::contract]\nmod simple_auction {\n    use ink::storage::Mapping;\n\n    #[ink(storage)]\n    pub struct SimpleAuction {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl SimpleAuction {\n        #[ink(constructor)]\n        pub fn new(auction_end: Timestamp) -> Self {\n            // TODO, add your code here\n        }\n\n        // TODO, add your messages here\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::simple_auction::{AuctionEnded, Error, HighestBidIncreased, SimpleAuction};\n\n#[ink::contract]\nmod simple_auction {\n    use ink::storage::Mapping;\n\n    /// An open auction paying the highest bid to its deployer, the beneficiary.\n    ///\n    /// Outbid bidders are not refunded right away, their bids are credited to them\n    /// and paid out once they call `withdraw`.\n    #[ink(storage)]\n    pub struct SimpleAuction {\n        beneficiary: AccountId,\n        /// Bids are accepted up to, but excluding, this timestamp.\n        auction_end: Timestamp,\n        highest_bidder: Option<AccountId>,\n        highest_bid: Balance,\n        /// The outbid amounts each bidder can withdraw.\n        pending_returns: Mapping<AccountId, Balance>,\n        ended: bool,\n    }\n\n    #[ink(event)]\n    pub struct HighestBidIncreased {\n        #[ink(topic)]\n        pub bidder: AccountId,\n        pub amount: Balance,\n    }\n\n    #[ink(event)]\n    pub struct AuctionEnded {\n        /// The highest bidder, or `None` if nobody placed a bid.\n        #[ink(topic)]\n        pub winner: Option<AccountId>,\n        pub amount: Balance,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        /// The auction end was reached, no more bids are accepted.\n        BiddingClosed,\n        /// A bid must be higher than the current highest bid.\n        BidTooLow,\n        AuctionNotEnded,\n        AlreadyFinalized,\n        NothingToWithdraw,\n        TransferFailed,\n        /// The outbid bidder''s pending returns would exceed `Balance::MAX`.\n        Overflow,\n    }\n\n    impl SimpleAuction {\n        /// Opens an auction for the caller, accepting bids until `auction_end`.\n        #[ink(constructor)]\n        pub fn new(auction_end: Timestamp) -> Self {\n            Self {\n                beneficiary: Self::env().caller(),\n                auction_end,\n                highest_bidder: None,\n                highest_bid: 0,\n                pending_returns: Mapping::default(),\n                ended: false,\n            }\n        }\n\n        /// Bids the value sent along with the call.\n        ///\n        /// The previous highest bid, including one of the caller''s own, becomes\n        /// withdrawable by its bidder.\n        #[ink(message, payable)]\n        pub fn place_bid(&mut self) -> Result<(), Error> {\n            if self.env().block_timestamp() >= self.auction_end {\n                return Err(Error::BiddingClosed);\n            }\n            let amount = self.env().transferred_value();\n            if amount <= self.highest_bid {\n                return Err(Error::BidTooLow);\n            }\n\n            if let Some(outbid) = self.highest_bidder {\n                let pending = self\n                    .get_pending_return(outbid)\n                    .checked_add(self.highest_bid)\n                    .ok_or(Error::Overflow)?;\n                self.pending_returns.insert(outbid, &pending);\n            }\n            let bidder = self.env().caller();\n            self.highest_bidder = Some(bidder);\n            self.highest_bid = amount;\n            self.env()\n                .emit_event(HighestBidIncreased { bidder, amount });\n            Ok(())\n        }\n\n        /// Pays the caller back the bids they were outbid on.\n        #[ink(message)]\n        pub fn withdraw(&mut self) -> Result<(), Error> {\n            let caller = self.env().caller();\n            let amount = self\n                .pending_returns\n                .take(caller)\n                .ok_or(Error::NothingToWithdraw)?;\n            if self.env().transfer(caller, amount).is_err() {\n                self.pending_returns.insert(caller, &amount);\n                return Err(Error::TransferFailed);\n            }\n            Ok(())\n        }\n\n        /// Ends the auction and pays the highest bid to the beneficiary, callable by\n        /// anyone once the auction end was reached.\n        #[ink(message)]\n        pub fn finalize_auction(&mut self) -> Result<(), Error> {\n            if self.env().block_timestamp() < self.auction_end {\n                return Err(Error::AuctionNotEnded);\n            }\n            if self.ended {\n                return Err(Error::AlreadyFinalized);\n            }\n            if self.highest_bidder.is_some() {\n                self.env()\n                    .transfer(self.beneficiary, self.highest_bid)\n                    .map_err(|_| Error::TransferFailed)?;\n            }\n            self.ended = true;\n            self.env().emit_event(AuctionEnded {\n                winner: self.highest_bidder,\n                amount: self.highest_bid,\n            });\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn get_highest_bidder(&self) -> Option<AccountId> {\n            self.highest_bidder\n        }\n\n        #[ink(message)]\n        pub fn get_highest_bid(&self) -> Balance {\n            self.highest_bid\n        }\n\n        #[ink(message)]\n        pub fn get_pending_return(&self, bidder: AccountId) -> Balance {\n            self.pending_returns.get(bidder).unwrap_or(0)\n        }\n\n        #[ink(message)]\n        pub fn get_auction_end(&self) -> Timestamp {\n            self.auction_end\n        }\n\n        #[ink(message)]\n        pub fn get_beneficiary(&self) -> AccountId {\n            self.beneficiary\n        }\n\n        #[ink(message)]\n        pub fn is_ended(&self) -> bool {\n            self.ended\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "auction"]}');
+
+INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
+VALUES ('524a87a2-d704-11ed-afa1-0242ac120002', 'timed-lock', 'Timed Lock', 'Create a timed lock smart contract that allows users to lock their funds for a specified duration. Each lock gets its own id and release time, so an account can hold several locks, up to `MAX_LOCKS_PER_ACCOUNT` (32), that are released independently. Implement lock, unlock, and get_remaining_time functions. The contract should prevent withdrawals before the lock duration has elapsed and by anyone but the account that locked the funds.', 'medium', 35, 4.1, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::timed_lock::{Error, Lock, LockFunds, TimedLock, UnlockFunds, MAX_LOCKS_PER_ACCOUNT};\n\n#[ink::contract]\nmod timed_lock {\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    #[ink(storage)]\n    pub struct TimedLock {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl TimedLock {\n        #[ink(constructor)]\n        pub fn new() -> Self {\n            // TODO, add your code here\n        }\n\n        // TODO, add your messages here\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::timed_lock::{Error, Lock, LockFunds, TimedLock, UnlockFunds, MAX_LOCKS_PER_ACCOUNT};\n\n#[ink::contract]\nmod timed_lock {\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    /// The most locks an account may hold at once, their ids are stored in a single\n    /// cell.\n    pub const MAX_LOCKS_PER_ACCOUNT: u32 = 32;\n\n    /// Holds funds sent along with `lock` until their release time, after which only\n    /// the account that locked them can take them back.\n    ///\n    /// An account can hold up to `MAX_LOCKS_PER_ACCOUNT` locks, each with its own\n    /// release time.\n    #[ink(storage)]\n    #[derive(Default)]\n    pub struct TimedLock {\n        locks: Mapping<u32, Lock>,\n        /// The ids of each account''s locks, in the order they were created.\n        lock_ids: Mapping<AccountId, Vec<u32>>,\n        next_id: u32,\n    }\n\n    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub struct Lock {\n        pub owner: AccountId,\n        pub amount: Balance,\n        /// The funds can be unlocked from this timestamp on.\n        pub release_time: Timestamp,\n    }\n\n    #[ink(event)]\n    pub struct LockFunds {\n        #[ink(topic)]\n        pub owner: AccountId,\n        pub id: u32,\n        pub amount: Balance,\n        pub release_time: Timestamp,\n    }\n\n    #[ink(event)]\n    pub struct UnlockFunds {\n        #[ink(topic)]\n        pub owner: AccountId,\n        pub id: u32,\n        pub amount: Balance,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        /// No value was sent along to lock.\n        ZeroAmount,\n        /// The release time would exceed `Timestamp::MAX`.\n        InvalidDuration,\n        /// The caller already holds `MAX_LOCKS_PER_ACCOUNT` locks.\n        TooManyLocks,\n        LockNotFound,\n        /// Only the account that created a lock can unlock it.\n        Unauthorized,\n        /// The release time of the lock was not reached yet.\n        StillLocked,\n        TransferFailed,\n        /// Every lock id was used up.\n        Overflow,\n    }\n\n    impl TimedLock {\n        #[ink(constructor)]\n        pub fn new() -> Self {\n            Self::default()\n        }\n\n        /// Locks the value sent along for `duration`, returning the id of the new lock.\n        #[ink(message, payable)]\n        pub fn lock(&mut self, duration: Timestamp) -> Result<u32, Error> {\n            let amount = self.env().transferred_value();\n            if amount == 0 {\n                return Err(Error::ZeroAmount);\n            }\n            let release_time = self\n                .env()\n                .block_timestamp()\n                .checked_add(duration)\n                .ok_or(Error::InvalidDuration)?;\n            let owner = self.env().caller();\n            let mut ids = self.get_locks(owner);\n            if ids.len() >= MAX_LOCKS_PER_ACCOUNT as usize {\n                return Err(Error::TooManyLocks);\n            }\n            let id = self.next_id;\n            self.next_id = id.checked_add(1).ok_or(Error::Overflow)?;\n\n            self.locks.insert(\n                id,\n                &Lock {\n                    owner,\n                    amount,\n                    release_time,\n                },\n            );\n            ids.push(id);\n            self.lock_ids.insert(owner, &ids);\n            self.env().emit_event(LockFunds {\n                owner,\n                id,\n                amount,\n                release_time,\n            });\n            Ok(id)\n        }\n\n        /// Pays the funds of lock `id` back to its owner once its release time was reached.\n        #[ink(message)]\n        pub fn unlock(&mut self, id: u32) -> Result<(), Error> {\n            let lock = self.locks.get(id).ok_or(Error::LockNotFound)?;\n            let caller = self.env().caller();\n            if caller != lock.owner {\n                return Err(Error::Unauthorized);\n            }\n            if self.env().block_timestamp() < lock.release_time {\n                return Err(Error::StillLocked);\n            }\n\n            self.env()\n                .transfer(caller, lock.amount)\n                .map_err(|_| Error::TransferFailed)?;\n            self.locks.remove(id);\n            let mut ids = self.get_locks(caller);\n            ids.retain(|lock_id| *lock_id != id);\n            if ids.is_empty() {\n                self.lock_ids.remove(caller);\n            } else {\n                self.lock_ids.insert(caller, &ids);\n            }\n            self.env().emit_event(UnlockFunds {\n                owner: caller,\n                id,\n                amount: lock.amount,\n            });\n            Ok(())\n        }\n\n        /// Returns the time left until lock `id` can be unlocked, zero once it can.\n        #[ink(message)]\n        pub fn get_remaining_time(&self, id: u32) -> Option<Timestamp> {\n            let now = self.env().block_timestamp();\n            self.locks\n                .get(id)\n                .map(|lock| lock.release_time.saturating_sub(now))\n        }\n\n        #[ink(message)]\n        pub fn get_lock(&self, id: u32) -> Option<Lock> {\n            self.locks.get(id)\n        }\n\n        /// Returns the ids of the locks `owner` has not unlocked yet.\n        #[ink(message)]\n        pub fn get_locks(&self, owner: AccountId) -> Vec<u32> {\n            self.lock_ids.get(owner).unwrap_or_default()\n        }\n\n        /// Returns the total amount `owner` has locked.\n        #[ink(message)]\n        pub fn get_locked_balance(&self, owner: AccountId) -> Balance {\n            self.get_locks(owner)\n                .into_iter()\n                .filter_map(|id| self.locks.get(id))\n                .map(|lock| lock.amount)\n                .sum()\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "timed lock"]}');
+
+INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
+VALUES ('9a3e20e6-d704-11ed-afa1-0242ac120002', 'psp22-token', 'PSP22 Token', 'Create a PSP22 token smart contract, the ERC-20 equivalent for ink!. The deployer receives the whole supply, holders can transfer tokens and approve other accounts to spend them on their behalf. Implement total_supply, balance_of, allowance, transfer, transfer_from, approve, increase_allowance and decrease_allowance along with the token metadata, and report failures with a PSP22Error. Every message must use its standard PSP22 selector, the first four bytes of the BLAKE2b hash of PSP22::<message> or PSP22Metadata::<message>, e.g. #[ink(message, selector = 0xdb20f9f5)] for transfer.', 'hard', 25, 4.9, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::psp22_token::{Approval, PSP22Error, Psp22Token, Transfer};\n\n#[ink::contract]\nmod psp22_token {\n    use ink::prelude::string::String;\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    #[ink(storage)]\n    pub struct Psp22Token {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl Psp22Token {\n        #[ink(constructor)]\n        pub fn new(total_supply: Balance, name: Option<String>, symbol: Option<String>, decimals: u8) -> Self {\n            // TODO, add your code here\n        }\n\n        // TODO, add your messages here\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::psp22_token::{Approval, PSP22Error, Psp22Token, Transfer};\n\n#[ink::contract]\nmod psp22_token {\n    use ink::prelude::string::String;\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    /// A PSP22 token, the ERC-20 equivalent for ink! contracts, with a fixed supply\n    /// minted to the deployer.\n    ///\n    /// Messages use the standard PSP22 selectors, the first four bytes of the BLAKE2b\n    /// hash of `PSP22::<message>` or `PSP22Metadata::<message>`, so wallets and other\n    /// contracts can call the token without its metadata.\n    #[ink(storage)]\n    #[derive(Default)]\n    pub struct Psp22Token {\n        total_supply: Balance,\n        balances: Mapping<AccountId, Balance>,\n        /// Amount `spender` may still transfer on behalf of `owner`, keyed by `(owner, spender)`.\n        allowances: Mapping<(AccountId, AccountId), Balance>,\n        name: Option<String>,\n        symbol: Option<String>,\n        decimals: u8,\n    }\n\n    #[ink(event)]\n    pub struct Transfer {\n        #[ink(topic)]\n        pub from: Option<AccountId>,\n        #[ink(topic)]\n        pub to: Option<AccountId>,\n        pub value: Balance,\n    }\n\n    #[ink(event)]\n    pub struct Approval {\n        #[ink(topic)]\n        pub owner: AccountId,\n        #[ink(topic)]\n        pub spender: AccountId,\n        /// The new allowance.\n        pub amount: Balance,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum PSP22Error {\n        /// An error not covered by the other variants.\n        Custom(String),\n        InsufficientBalance,\n        InsufficientAllowance,\n    }\n\n    impl Psp22Token {\n        /// Creates the token and mints `total_supply` to the caller.\n        #[ink(constructor)]\n        pub fn new(\n            total_supply: Balance,\n            name: Option<String>,\n            symbol: Option<String>,\n            decimals: u8,\n        ) -> Self {\n            let caller = Self::env().caller();\n            let mut token = Self {\n                total_supply,\n                name,\n                symbol,\n                decimals,\n                ..Default::default()\n            };\n            token.balances.insert(caller, &total_supply);\n            Self::env().emit_event(Transfer {\n                from: None,\n                to: Some(caller),\n                value: total_supply,\n            });\n            token\n        }\n\n        #[ink(message, selector = 0x162df8c2)]\n        pub fn total_supply(&self) -> Balance {\n            self.total_supply\n        }\n\n        #[ink(message, selector = 0x6568382f)]\n        pub fn balance_of(&self, owner: AccountId) -> Balance {\n            self.balances.get(owner).unwrap_or(0)\n        }\n\n        #[ink(message, selector = 0x4d47d921)]\n        pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {\n            self.allowances.get((owner, spender)).unwrap_or(0)\n        }\n\n        /// Transfers `value` from the caller to `to`. `data` is not interpreted by this\n        /// token and is only part of the signature required by PSP22.\n        #[ink(message, selector = 0xdb20f9f5)]\n        pub fn transfer(\n            &mut self,\n            to: AccountId,\n            value: Balance,\n            data: Vec<u8>,\n        ) -> Result<(), PSP22Error> {\n            let _ = data;\n            let from = self.env().caller();\n            self.transfer_from_to(from, to, value)\n        }\n\n        /// Transfers `value` from `from` to `to` out of the caller''s allowance.\n        #[ink(message, selector = 0x54b3c76e)]\n        pub fn transfer_from(\n            &mut self,\n            from: AccountId,\n            to: AccountId,\n            value: Balance,\n            data: Vec<u8>,\n        ) -> Result<(), PSP22Error> {\n            let _ = data;\n            let spender = self.env().caller();\n            if from == to || value == 0 {\n                return Ok(());\n            }\n            let allowance = self.allowance(from, spender);\n            if allowance < value {\n                return Err(PSP22Error::InsufficientAllowance);\n            }\n            if self.balance_of(from) < value {\n                return Err(PSP22Error::InsufficientBalance);\n            }\n            self.set_allowance(from, spender, allowance - value);\n            self.transfer_from_to(from, to, value)\n        }\n\n        /// Sets the allowance of `spender` to `value`, replacing the previous one.\n        #[ink(message, selector = 0xb20f1bbd)]\n        pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error> {\n            let owner = self.env().caller();\n            if owner == spender {\n                return Ok(());\n            }\n            self.set_allowance(owner, spender, value);\n            Ok(())\n        }\n\n        /// Raises the allowance of `spender` by `delta_value`, saturating at the maximum balance.\n        #[ink(message, selector = 0x96d6b57a)]\n        pub fn increase_allowance(\n            &mut self,\n            spender: AccountId,\n            delta_value: Balance,\n        ) -> Result<(), PSP22Error> {\n            let owner = self.env().caller();\n            if owner == spender || delta_value == 0 {\n                return Ok(());\n            }\n            let allowance = self.allowance(owner, spender);\n            self.set_allowance(owner, spender, allowance.saturating_add(delta_value));\n            Ok(())\n        }\n\n        #[ink(message, selector = 0xfecb57d5)]\n        pub fn decrease_allowance(\n            &mut self,\n            spender: AccountId,\n            delta_value: Balance,\n        ) -> Result<(), PSP22Error> {\n            let owner = self.env().caller();\n            if owner == spender || delta_value == 0 {\n                return Ok(());\n            }\n            let allowance = self.allowance(owner, spender);\n            if allowance < delta_value {\n                return Err(PSP22Error::InsufficientAllowance);\n            }\n            self.set_allowance(owner, spender, allowance - delta_value);\n            Ok(())\n        }\n\n        #[ink(message, selector = 0x3d261bd4)]\n        pub fn token_name(&self) -> Option<String> {\n            self.name.clone()\n        }\n\n        #[ink(message, selector = 0x34205be5)]\n        pub fn token_symbol(&self) -> Option<String> {\n            self.symbol.clone()\n        }\n\n        #[ink(message, selector = 0x7271b782)]\n        pub fn token_decimals(&self) -> u8 {\n            self.decimals\n        }\n\n        /// Moves `value` between two accounts. Transfers to oneself and of nothing\n        /// succeed without emitting an event, as PSP22 requires.\n        fn transfer_from_to(\n            &mut self,\n            from: AccountId,\n            to: AccountId,\n            value: Balance,\n        ) -> Result<(), PSP22Error> {\n            if from == to || value == 0 {\n                return Ok(());\n            }\n            let from_balance = self.balance_of(from);\n            if from_balance < value {\n                return Err(PSP22Error::InsufficientBalance);\n            }\n            self.balances.insert(from, &(from_balance - value));\n            // Cannot overflow, the balances add up to the total supply.\n            let to_balance = self.balance_of(to);\n            self.balances.insert(to, &(to_balance + value));\n            self.env().emit_event(Transfer {\n                from: Some(from),\n                to: Some(to),\n                value,\n            });\n            Ok(())\n        }\n\n        fn set_allowance(&mut self, owner: AccountId, spender: AccountId, amount: Balance) {\n            self.allowances.insert((owner, spender), &amount);\n            self.env().emit_event(Approval {\n                owner,\n                spender,\n                amount,\n            });\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "psp22"]}');
+
+INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
+VALUES ('3b7c51e8-d704-11ed-afa1-0242ac120002', 'key-value-store', 'Key-Value Store', 'Create a key-value store smart contract that maps byte keys to byte values for every account. Storing an entry requires a deposit proportional to the bytes it takes up, paid back when the entry is removed or overwritten, and keys and values are limited in size. The limits may not exceed `MAX_KEY_LEN` (128) and `MAX_VALUE_LEN` (4096) bytes, and an account may hold at most `MAX_KEYS_PER_ACCOUNT` (32) keys. Implement insert, remove, get and get_keys functions.', 'medium', 20, 4.5, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::key_value_store::{\n    Error, Insert, KeyValueStore, Remove, MAX_KEYS_PER_ACCOUNT, MAX_KEY_LEN, MAX_VALUE_LEN,\n};\n\n#[ink::contract]\nmod key_value_store {\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    #[ink(storage)]\n    pub struct KeyValueStore {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl KeyValueStore {\n        #[ink(constructor)]\n        pub fn new(max_key_len: u32, max_value_len: u32, deposit_per_byte: Balance) -> Result<Self, Error> {\n            // TODO, add your code here\n        }\n\n        // TODO, add your messages here\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::key_value_store::{\n    Error, Insert, KeyValueStore, Remove, MAX_KEYS_PER_ACCOUNT, MAX_KEY_LEN, MAX_VALUE_LEN,\n};\n\n#[ink::contract]\nmod key_value_store {\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    /// The highest `max_key_len` the store may be created with.\n    pub const MAX_KEY_LEN: u32 = 128;\n    /// The highest `max_value_len` the store may be created with.\n    pub const MAX_VALUE_LEN: u32 = 4096;\n    /// The most keys an account may hold, its key list is stored in a single cell.\n    pub const MAX_KEYS_PER_ACCOUNT: u32 = 32;\n\n    /// Stores byte values under byte keys, separately for every account.\n    ///\n    /// Writing an entry requires a deposit proportional to the bytes it takes up,\n    /// which is paid back once the entry is removed or overwritten.\n    #[ink(storage)]\n    pub struct KeyValueStore {\n        entries: Mapping<EntryKey, Entry>,\n        /// The keys of each account, in the order they were first written.\n        keys: Mapping<AccountId, Vec<Vec<u8>>>,\n        max_key_len: u32,\n        max_value_len: u32,\n        deposit_per_byte: Balance,\n    }\n\n    /// An account and one of its keys.\n    type EntryKey = (AccountId, Vec<u8>);\n\n    /// A stored value along with the deposit paid for it.\n    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    struct Entry {\n        value: Vec<u8>,\n        deposit: Balance,\n    }\n\n    #[ink(event)]\n    pub struct Insert {\n        #[ink(topic)]\n        pub owner: AccountId,\n        pub key: Vec<u8>,\n        pub deposit: Balance,\n    }\n\n    #[ink(event)]\n    pub struct Remove {\n        #[ink(topic)]\n        pub owner: AccountId,\n        pub key: Vec<u8>,\n        /// The deposit paid back to the owner.\n        pub refund: Balance,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        KeyTooLong,\n        ValueTooLong,\n        /// The limits passed to the constructor are above `MAX_KEY_LEN` or\n        /// `MAX_VALUE_LEN`.\n        LimitTooLarge,\n        /// The caller already holds `MAX_KEYS_PER_ACCOUNT` keys.\n        TooManyKeys,\n        /// The value sent along does not match the deposit for the entry.\n        IncorrectDeposit,\n        KeyNotFound,\n        TransferFailed,\n        /// The deposit would exceed `Balance::MAX`.\n        Overflow,\n    }\n\n    impl KeyValueStore {\n        #[ink(constructor)]\n        pub fn new(\n            max_key_len: u32,\n            max_value_len: u32,\n            deposit_per_byte: Balance,\n        ) -> Result<Self, Error> {\n            if max_key_len > MAX_KEY_LEN || max_value_len > MAX_VALUE_LEN {\n                return Err(Error::LimitTooLarge);\n            }\n            Ok(Self {\n                entries: Mapping::new(),\n                keys: Mapping::new(),\n                max_key_len,\n                max_value_len,\n                deposit_per_byte,\n            })\n        }\n\n        /// Stores `value` under the caller''s `key`. The deposit for the new entry must\n        /// be sent along, while the deposit for a value it replaces is paid back.\n        #[ink(message, payable)]\n        pub fn insert(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<(), Error> {\n            if key.len() > self.max_key_len as usize {\n                return Err(Error::KeyTooLong);\n            }\n            if value.len() > self.max_value_len as usize {\n                return Err(Error::ValueTooLong);\n            }\n            let deposit = self.deposit_for(key.len() + value.len())?;\n            if self.env().transferred_value() != deposit {\n                return Err(Error::IncorrectDeposit);\n            }\n\n            let caller = self.env().caller();\n            match self.entries.get((caller, key.clone())) {\n                Some(entry) => self.refund(caller, entry.deposit)?,\n                None => {\n                    let mut keys = self.get_keys(caller);\n                    if keys.len() >= MAX_KEYS_PER_ACCOUNT as usize {\n                        return Err(Error::TooManyKeys);\n                    }\n                    keys.push(key.clone());\n                    self.keys.insert(caller, &keys);\n                }\n            }\n            self.entries\n                .insert((caller, key.clone()), &Entry { value, deposit });\n            self.env().emit_event(Insert {\n                owner: caller,\n                key,\n                deposit,\n            });\n            Ok(())\n        }\n\n        /// Removes the caller''s `key` and pays back its deposit.\n        #[ink(message)]\n        pub fn remove(&mut self, key: Vec<u8>) -> Result<(), Error> {\n            let caller = self.env().caller();\n            let refund = self\n                .entries\n                .take((caller, key.clone()))\n                .ok_or(Error::KeyNotFound)?\n                .deposit;\n            let mut keys = self.get_keys(caller);\n            keys.retain(|k| *k != key);\n            if keys.is_empty() {\n                self.keys.remove(caller);\n            } else {\n                self.keys.insert(caller, &keys);\n            }\n            self.refund(caller, refund)?;\n            self.env().emit_event(Remove {\n                owner: caller,\n                key,\n                refund,\n            });\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn get(&self, owner: AccountId, key: Vec<u8>) -> Option<Vec<u8>> {\n            self.entries.get((owner, key)).map(|entry| entry.value)\n        }\n\n        /// Returns the keys `owner` has stored values under.\n        #[ink(message)]\n        pub fn get_keys(&self, owner: AccountId) -> Vec<Vec<u8>> {\n            self.keys.get(owner).unwrap_or_default()\n        }\n\n        /// Returns the deposit held for `owner`''s `key`.\n        #[ink(message)]\n        pub fn get_deposit(&self, owner: AccountId, key: Vec<u8>) -> Balance {\n            self.entries\n                .get((owner, key))\n                .map_or(0, |entry| entry.deposit)\n        }\n\n        #[ink(message)]\n        pub fn get_deposit_per_byte(&self) -> Balance {\n            self.deposit_per_byte\n        }\n\n        /// Returns `(max_key_len, max_value_len)`.\n        #[ink(message)]\n        pub fn get_limits(&self) -> (u32, u32) {\n            (self.max_key_len, self.max_value_len)\n        }\n\n        fn deposit_for(&self, bytes: usize) -> Result<Balance, Error> {\n            (bytes as Balance)\n                .checked_mul(self.deposit_per_byte)\n                .ok_or(Error::Overflow)\n        }\n\n        fn refund(&self, to: AccountId, amount: Balance) -> Result<(), Error> {\n            if amount == 0 {\n                return Ok(());\n            }\n            self.env()\n                .transfer(to, amount)\n                .map_err(|_| Error::TransferFailed)\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "storage"]}');
+
+INSERT INTO questions (id, name, title, description, difficulty, frequency, rating, metadata)
+VALUES ('e2ceeb1a-d704-11ed-afa1-0242ac120002', 'decentralized-oracle', 'Decentralized Oracle', 'Create a decentralized oracle smart contract that allows users to request external data and receive it within the contract. Each request carries a key and a fee, and a whitelisted set of at most 32 reporters (`MAX_REPORTERS`) submits values for it. Keys longer than 64 bytes (`MAX_KEY_LEN`) are rejected with `KeyTooLong`, and too many reporters with `TooManyReporters`. Once a quorum of reporters answered, the request is finalized at the median of their values and the fee is split between them as rewards they can withdraw. A request that does not reach the quorum within the request timeout, which must be at least one block, can be cancelled by its requester for a refund, and a key''s value is never replaced by that of an earlier request. Implement request_data, provide_data, cancel_request and get_data functions.', 'hard', 15, 5, '{"starterCode": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::decentralized_oracle::{\n    CancelRequest, DecentralizedOracle, Error, FinalizeData, ProvideData, Request, RequestData,\n    MAX_KEY_LEN, MAX_REPORTERS,\n};\n\n#[ink::contract]\nmod decentralized_oracle {\n    use ink::prelude::string::String;\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    #[ink(storage)]\n    pub struct DecentralizedOracle {\n        // TODO, add your code here\n    }\n\n    // TODO, add the types re-exported above\n\n    impl DecentralizedOracle {\n        #[ink(constructor)]\n        pub fn new(reporters: Vec<AccountId>, quorum: u32, request_timeout: BlockNumber) -> Result<Self, Error> {\n            // TODO, add your code here\n        }\n\n        // TODO, add your messages here\n    }\n}\n"}, "codeSolution": {"substrate_rust": "#![cfg_attr(not(feature = \"std\"), no_std, no_main)]\n\npub use self::decentralized_oracle::{\n    CancelRequest, DecentralizedOracle, Error, FinalizeData, ProvideData, Request, RequestData,\n    MAX_KEY_LEN, MAX_REPORTERS,\n};\n\n#[ink::contract]\nmod decentralized_oracle {\n    use ink::prelude::string::String;\n    use ink::prelude::vec::Vec;\n    use ink::storage::Mapping;\n\n    /// The longest key that may be requested, in bytes. Every request stores its key.\n    pub const MAX_KEY_LEN: u32 = 64;\n    /// The most reporters an oracle may have, they are stored in a single cell.\n    pub const MAX_REPORTERS: u32 = 32;\n\n    /// A request for the value of `key`, paid for with `fee`.\n    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(\n        feature = \"std\",\n        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)\n    )]\n    pub struct Request {\n        pub requester: AccountId,\n        pub key: String,\n        pub fee: Balance,\n        /// Reports are accepted up to, but excluding, this block. Once it is reached\n        /// without a quorum, the requester can cancel the request.\n        pub deadline: BlockNumber,\n        /// The values submitted so far and the reporters that submitted them.\n        pub reports: Vec<(AccountId, u128)>,\n        /// The median of the reports, once `quorum` reporters submitted a value.\n        pub value: Option<u128>,\n    }\n\n    /// An oracle answering data requests through a fixed set of reporters.\n    ///\n    /// A request is finalized at the median of the first `quorum` values reported\n    /// for it, and its fee is split between those reporters, who withdraw their\n    /// rewards later on. A request that does not reach the quorum within\n    /// `request_timeout` blocks can be cancelled, paying its fee back.\n    #[ink(storage)]\n    #[derive(Default)]\n    pub struct DecentralizedOracle {\n        reporters: Vec<AccountId>,\n        quorum: u32,\n        request_timeout: BlockNumber,\n        requests: Mapping<u32, Request>,\n        request_count: u32,\n        /// The value of each key along with the request it was finalized for, a value\n        /// is only replaced by that of a later request.\n        data: Mapping<String, (u32, u128)>,\n        /// The fees each reporter earned and did not withdraw yet.\n        rewards: Mapping<AccountId, Balance>,\n    }\n\n    #[ink(event)]\n    pub struct RequestData {\n        #[ink(topic)]\n        pub requester: AccountId,\n        pub request_id: u32,\n        pub key: String,\n        pub fee: Balance,\n    }\n\n    #[ink(event)]\n    pub struct ProvideData {\n        #[ink(topic)]\n        pub reporter: AccountId,\n        pub request_id: u32,\n        pub value: u128,\n    }\n\n    #[ink(event)]\n    pub struct FinalizeData {\n        pub request_id: u32,\n        pub key: String,\n        pub value: u128,\n    }\n\n    #[ink(event)]\n    pub struct CancelRequest {\n        #[ink(topic)]\n        pub requester: AccountId,\n        pub request_id: u32,\n        /// The fee paid back to the requester.\n        pub refund: Balance,\n    }\n\n    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]\n    #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]\n    pub enum Error {\n        /// The quorum must be at least one and at most the number of reporters.\n        InvalidQuorum,\n        /// Requests must stay open for at least one block.\n        InvalidTimeout,\n        DuplicateReporter,\n        /// More than `MAX_REPORTERS` reporters.\n        TooManyReporters,\n        /// The key is longer than `MAX_KEY_LEN` bytes.\n        KeyTooLong,\n        /// A request must be paid for.\n        InsufficientFee,\n        NotReporter,\n        /// Only the requester can cancel a request.\n        NotRequester,\n        RequestNotFound,\n        AlreadyReported,\n        AlreadyFinalized,\n        /// The deadline of the request was reached, it no longer accepts reports.\n        RequestExpired,\n        /// The request can only be cancelled once its deadline was reached.\n        RequestNotExpired,\n        NothingToWithdraw,\n        TransferFailed,\n        /// A request id, deadline or reward would leave the range of its type.\n        Overflow,\n    }\n\n    impl DecentralizedOracle {\n        /// Creates an oracle served by `reporters`, finalizing requests once `quorum`\n        /// of them reported within `request_timeout` blocks.\n        #[ink(constructor)]\n        pub fn new(\n            reporters: Vec<AccountId>,\n            quorum: u32,\n            request_timeout: BlockNumber,\n        ) -> Result<Self, Error> {\n            if reporters.len() > MAX_REPORTERS as usize {\n                return Err(Error::TooManyReporters);\n            }\n            let mut oracle = Self::default();\n            for reporter in reporters {\n                if oracle.reporters.contains(&reporter) {\n                    return Err(Error::DuplicateReporter);\n                }\n                oracle.reporters.push(reporter);\n            }\n            if quorum == 0 || quorum as usize > oracle.reporters.len() {\n                return Err(Error::InvalidQuorum);\n            }\n            if request_timeout == 0 {\n                return Err(Error::InvalidTimeout);\n            }\n            oracle.quorum = quorum;\n            oracle.request_timeout = request_timeout;\n            Ok(oracle)\n        }\n\n        /// Requests the value of `key`, paying the value sent along as the fee.\n        /// Returns the id of the request.\n        #[ink(message, payable)]\n        pub fn request_data(&mut self, key: String) -> Result<u32, Error> {\n            if key.len() > MAX_KEY_LEN as usize {\n                return Err(Error::KeyTooLong);\n            }\n            let fee = self.env().transferred_value();\n            if fee == 0 {\n                return Err(Error::InsufficientFee);\n            }\n            let deadline = self\n                .env()\n                .block_number()\n                .checked_add(self.request_timeout)\n                .ok_or(Error::Overflow)?;\n            let request_id = self.request_count;\n            self.request_count = request_id.checked_add(1).ok_or(Error::Overflow)?;\n\n            let requester = self.env().caller();\n            self.requests.insert(\n                request_id,\n                &Request {\n                    requester,\n                    key: key.clone(),\n                    fee,\n                    deadline,\n                    reports: Vec::new(),\n                    value: None,\n                },\n            );\n            self.env().emit_event(RequestData {\n                requester,\n                request_id,\n                key,\n                fee,\n            });\n            Ok(request_id)\n        }\n\n        /// Reports `value` for request `request_id`, finalizing the request if this is\n        /// the last report the quorum needs.\n        #[ink(message)]\n        pub fn provide_data(&mut self, request_id: u32, value: u128) -> Result<(), Error> {\n            let reporter = self.env().caller();\n            if !self.reporters.contains(&reporter) {\n                return Err(Error::NotReporter);\n            }\n            let mut request = self\n                .requests\n                .get(request_id)\n                .ok_or(Error::RequestNotFound)?;\n            if request.value.is_some() {\n                return Err(Error::AlreadyFinalized);\n            }\n            if self.env().block_number() >= request.deadline {\n                return Err(Error::RequestExpired);\n            }\n            if request.reports.iter().any(|(by, _)| *by == reporter) {\n                return Err(Error::AlreadyReported);\n            }\n\n            request.reports.push((reporter, value));\n            self.env().emit_event(ProvideData {\n                reporter,\n                request_id,\n                value,\n            });\n            if request.reports.len() == self.quorum as usize {\n                self.finalize(request_id, &mut request)?;\n            }\n            self.requests.insert(request_id, &request);\n            Ok(())\n        }\n\n        /// Cancels request `request_id`, which did not reach the quorum before its\n        /// deadline, and pays its fee back to the requester.\n        #[ink(message)]\n        pub fn cancel_request(&mut self, request_id: u32) -> Result<(), Error> {\n            let request = self\n                .requests\n                .get(request_id)\n                .ok_or(Error::RequestNotFound)?;\n            let requester = self.env().caller();\n            if requester != request.requester {\n                return Err(Error::NotRequester);\n            }\n            if request.value.is_some() {\n                return Err(Error::AlreadyFinalized);\n            }\n            if self.env().block_number() < request.deadline {\n                return Err(Error::RequestNotExpired);\n            }\n\n            self.env()\n                .transfer(requester, request.fee)\n                .map_err(|_| Error::TransferFailed)?;\n            self.requests.remove(request_id);\n            self.env().emit_event(CancelRequest {\n                requester,\n                request_id,\n                refund: request.fee,\n            });\n            Ok(())\n        }\n\n        /// Returns the value of `key` finalized for the latest request.\n        #[ink(message)]\n        pub fn get_data(&self, key: String) -> Option<u128> {\n            self.data.get(key).map(|(_, value)| value)\n        }\n\n        /// Pays the caller the fees they earned as a reporter.\n        #[ink(message)]\n        pub fn withdraw_rewards(&mut self) -> Result<(), Error> {\n            let caller = self.env().caller();\n            let amount = self.rewards.take(caller).ok_or(Error::NothingToWithdraw)?;\n            if self.env().transfer(caller, amount).is_err() {\n                self.rewards.insert(caller, &amount);\n                return Err(Error::TransferFailed);\n            }\n            Ok(())\n        }\n\n        #[ink(message)]\n        pub fn get_request(&self, request_id: u32) -> Option<Request> {\n            self.requests.get(request_id)\n        }\n\n        #[ink(message)]\n        pub fn get_rewards(&self, reporter: AccountId) -> Balance {\n            self.rewards.get(reporter).unwrap_or(0)\n        }\n\n        #[ink(message)]\n        pub fn get_reporters(&self) -> Vec<AccountId> {\n            self.reporters.clone()\n        }\n\n        #[ink(message)]\n        pub fn get_quorum(&self) -> u32 {\n            self.quorum\n        }\n\n        #[ink(message)]\n        pub fn get_request_timeout(&self) -> BlockNumber {\n            self.request_timeout\n        }\n\n        /// Settles `request` at the median of its reports and splits its fee between\n        /// the reporters, the first reporter also receiving what does not divide evenly.\n        ///\n        /// The value of the request''s key is only replaced if no later request for it\n        /// was finalized first.\n        fn finalize(&mut self, request_id: u32, request: &mut Request) -> Result<(), Error> {\n            let mut values: Vec<u128> = request.reports.iter().map(|(_, value)| *value).collect();\n            values.sort_unstable();\n            let middle = values.len() / 2;\n            let value = if values.len().is_multiple_of(2) {\n                let (low, high) = (values[middle - 1], values[middle]);\n                low + (high - low) / 2\n            } else {\n                values[middle]\n            };\n\n            let reporters = request.reports.len() as Balance;\n            let share = request.fee / reporters;\n            let remainder = request.fee % reporters;\n            let mut rewards = Vec::with_capacity(request.reports.len());\n            for (index, (reporter, _)) in request.reports.iter().enumerate() {\n                let reward = if index == 0 { share + remainder } else { share };\n                let earned = self\n                    .get_rewards(*reporter)\n                    .checked_add(reward)\n                    .ok_or(Error::Overflow)?;\n                rewards.push((*reporter, earned));\n            }\n            for (reporter, earned) in rewards {\n                self.rewards.insert(reporter, &earned);\n            }\n\n            request.value = Some(value);\n            let is_latest = self\n                .data\n                .get(&request.key)\n                .is_none_or(|(latest, _)| request_id > latest);\n            if is_latest {\n                self.data.insert(&request.key, &(request_id, value));\n            }\n            self.env().emit_event(FinalizeData {\n                request_id,\n                key: request.key.clone(),\n                value,\n            });\n            Ok(())\n        }\n    }\n}\n"}, "category": "Ink!", "tags": ["smart contract", "decentralized oracle"]}');
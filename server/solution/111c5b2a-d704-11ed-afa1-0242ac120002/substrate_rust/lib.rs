@@ -0,0 +1,166 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::simple_auction::{AuctionEnded, Error, HighestBidIncreased, SimpleAuction};
+
+#[ink::contract]
+mod simple_auction {
+    use ink::storage::Mapping;
+
+    /// An open auction paying the highest bid to its deployer, the beneficiary.
+    ///
+    /// Outbid bidders are not refunded right away, their bids are credited to them
+    /// and paid out once they call `withdraw`.
+    #[ink(storage)]
+    pub struct SimpleAuction {
+        beneficiary: AccountId,
+        /// Bids are accepted up to, but excluding, this timestamp.
+        auction_end: Timestamp,
+        highest_bidder: Option<AccountId>,
+        highest_bid: Balance,
+        /// The outbid amounts each bidder can withdraw.
+        pending_returns: Mapping<AccountId, Balance>,
+        ended: bool,
+    }
+
+    #[ink(event)]
+    pub struct HighestBidIncreased {
+        #[ink(topic)]
+        pub bidder: AccountId,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct AuctionEnded {
+        /// The highest bidder, or `None` if nobody placed a bid.
+        #[ink(topic)]
+        pub winner: Option<AccountId>,
+        pub amount: Balance,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// The auction end was reached, no more bids are accepted.
+        BiddingClosed,
+        /// A bid must be higher than the current highest bid.
+        BidTooLow,
+        AuctionNotEnded,
+        AlreadyFinalized,
+        NothingToWithdraw,
+        TransferFailed,
+        /// The outbid bidder's pending returns would exceed `Balance::MAX`.
+        Overflow,
+    }
+
+    impl SimpleAuction {
+        /// Opens an auction for the caller, accepting bids until `auction_end`.
+        #[ink(constructor)]
+        pub fn new(auction_end: Timestamp) -> Self {
+            Self {
+                beneficiary: Self::env().caller(),
+                auction_end,
+                highest_bidder: None,
+                highest_bid: 0,
+                pending_returns: Mapping::default(),
+                ended: false,
+            }
+        }
+
+        /// Bids the value sent along with the call.
+        ///
+        /// The previous highest bid, including one of the caller's own, becomes
+        /// withdrawable by its bidder.
+        #[ink(message, payable)]
+        pub fn place_bid(&mut self) -> Result<(), Error> {
+            if self.env().block_timestamp() >= self.auction_end {
+                return Err(Error::BiddingClosed);
+            }
+            let amount = self.env().transferred_value();
+            if amount <= self.highest_bid {
+                return Err(Error::BidTooLow);
+            }
+
+            if let Some(outbid) = self.highest_bidder {
+                let pending = self
+                    .get_pending_return(outbid)
+                    .checked_add(self.highest_bid)
+                    .ok_or(Error::Overflow)?;
+                self.pending_returns.insert(outbid, &pending);
+            }
+            let bidder = self.env().caller();
+            self.highest_bidder = Some(bidder);
+            self.highest_bid = amount;
+            self.env()
+                .emit_event(HighestBidIncreased { bidder, amount });
+            Ok(())
+        }
+
+        /// Pays the caller back the bids they were outbid on.
+        #[ink(message)]
+        pub fn withdraw(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let amount = self
+                .pending_returns
+                .take(caller)
+                .ok_or(Error::NothingToWithdraw)?;
+            if self.env().transfer(caller, amount).is_err() {
+                self.pending_returns.insert(caller, &amount);
+                return Err(Error::TransferFailed);
+            }
+            Ok(())
+        }
+
+        /// Ends the auction and pays the highest bid to the beneficiary, callable by
+        /// anyone once the auction end was reached.
+        #[ink(message)]
+        pub fn finalize_auction(&mut self) -> Result<(), Error> {
+            if self.env().block_timestamp() < self.auction_end {
+                return Err(Error::AuctionNotEnded);
+            }
+            if self.ended {
+                return Err(Error::AlreadyFinalized);
+            }
+            if self.highest_bidder.is_some() {
+                self.env()
+                    .transfer(self.beneficiary, self.highest_bid)
+                    .map_err(|_| Error::TransferFailed)?;
+            }
+            self.ended = true;
+            self.env().emit_event(AuctionEnded {
+                winner: self.highest_bidder,
+                amount: self.highest_bid,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_highest_bidder(&self) -> Option<AccountId> {
+            self.highest_bidder
+        }
+
+        #[ink(message)]
+        pub fn get_highest_bid(&self) -> Balance {
+            self.highest_bid
+        }
+
+        #[ink(message)]
+        pub fn get_pending_return(&self, bidder: AccountId) -> Balance {
+            self.pending_returns.get(bidder).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        pub fn get_auction_end(&self) -> Timestamp {
+            self.auction_end
+        }
+
+        #[ink(message)]
+        pub fn get_beneficiary(&self) -> AccountId {
+            self.beneficiary
+        }
+
+        #[ink(message)]
+        pub fn is_ended(&self) -> bool {
+            self.ended
+        }
+    }
+}
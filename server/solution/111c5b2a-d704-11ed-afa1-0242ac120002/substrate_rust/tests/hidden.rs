@@ -0,0 +1,57 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{balance_of, set_block_timestamp, set_callee, set_caller, transfer_in};
+use simple_auction::{Error, SimpleAuction};
+
+const BOB: AccountId = AccountId([0x2; 32]);
+const CHARLIE: AccountId = AccountId([0x3; 32]);
+const CONTRACT: AccountId = AccountId([0xFF; 32]);
+const AUCTION_END: u64 = 1_000;
+
+fn setup() -> SimpleAuction {
+    set_callee(CONTRACT);
+    SimpleAuction::new(AUCTION_END)
+}
+
+fn bid(auction: &mut SimpleAuction, bidder: AccountId, amount: u128) -> Result<(), Error> {
+    set_caller(bidder);
+    transfer_in(amount);
+    auction.place_bid()
+}
+
+#[ink::test]
+fn test_pending_returns_add_up() {
+    let mut auction = setup();
+    bid(&mut auction, BOB, 100).unwrap();
+    bid(&mut auction, CHARLIE, 200).unwrap();
+    bid(&mut auction, BOB, 300).unwrap();
+    bid(&mut auction, CHARLIE, 400).unwrap();
+
+    // both of bob's bids were outbid and are returned in one withdrawal
+    assert_eq!(auction.get_pending_return(BOB), 400);
+    assert_eq!(auction.get_pending_return(CHARLIE), 200);
+    set_caller(BOB);
+    assert_eq!(auction.withdraw(), Ok(()));
+    assert_eq!(balance_of(BOB), 1000);
+
+    // the contract holds charlie's highest bid and outbid amount
+    set_block_timestamp(AUCTION_END);
+    assert_eq!(auction.finalize_auction(), Ok(()));
+    assert_eq!(balance_of(CONTRACT), 200);
+}
+
+#[ink::test]
+fn test_finalize_twice_pays_once() {
+    let mut auction = setup();
+    bid(&mut auction, BOB, 100).unwrap();
+    let beneficiary = auction.get_beneficiary();
+    let before = balance_of(beneficiary);
+
+    set_block_timestamp(AUCTION_END + 1);
+    assert_eq!(auction.finalize_auction(), Ok(()));
+    for caller in [BOB, CHARLIE, beneficiary] {
+        set_caller(caller);
+        assert_eq!(auction.finalize_auction(), Err(Error::AlreadyFinalized));
+    }
+    assert_eq!(balance_of(beneficiary), before + 100);
+    assert_eq!(bid(&mut auction, CHARLIE, 200), Err(Error::BiddingClosed));
+}
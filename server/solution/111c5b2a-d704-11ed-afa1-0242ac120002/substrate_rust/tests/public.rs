@@ -0,0 +1,167 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{
+    balance_of, event, set_block_timestamp, set_callee, set_caller, transfer_in,
+};
+use simple_auction::{AuctionEnded, Error, HighestBidIncreased, SimpleAuction};
+
+/// Deploys the auction and is its beneficiary.
+const ALICE: AccountId = AccountId([0x1; 32]);
+/// Starts with a balance of 1000 in the off-chain environment.
+const BOB: AccountId = AccountId([0x2; 32]);
+/// Starts with a balance of 1000 in the off-chain environment.
+const CHARLIE: AccountId = AccountId([0x3; 32]);
+/// The off-chain environment deploys at alice's account unless told otherwise, which
+/// would turn paying the beneficiary into a transfer to the contract itself.
+const CONTRACT: AccountId = AccountId([0xFF; 32]);
+const AUCTION_END: u64 = 1_000;
+
+fn setup() -> SimpleAuction {
+    set_callee(CONTRACT);
+    SimpleAuction::new(AUCTION_END)
+}
+
+/// Bids `amount` as `bidder`.
+fn bid(auction: &mut SimpleAuction, bidder: AccountId, amount: u128) -> Result<(), Error> {
+    set_caller(bidder);
+    transfer_in(amount);
+    auction.place_bid()
+}
+
+#[ink::test]
+fn test_new() {
+    let auction = setup();
+    assert_eq!(auction.get_beneficiary(), ALICE);
+    assert_eq!(auction.get_auction_end(), AUCTION_END);
+    assert_eq!(auction.get_highest_bidder(), None);
+    assert_eq!(auction.get_highest_bid(), 0);
+    assert!(!auction.is_ended());
+}
+
+#[ink::test]
+fn test_place_bid() {
+    let mut auction = setup();
+    assert_eq!(bid(&mut auction, BOB, 100), Ok(()));
+    assert_eq!(auction.get_highest_bidder(), Some(BOB));
+    assert_eq!(auction.get_highest_bid(), 100);
+    assert_eq!(balance_of(BOB), 900);
+    assert_eq!(auction.get_pending_return(BOB), 0);
+
+    leetcoin_test_kit::assert_events([event(HighestBidIncreased {
+        bidder: BOB,
+        amount: 100,
+    })]);
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_topic(&events[0], &BOB);
+}
+
+#[ink::test]
+fn test_bid_too_low() {
+    let mut auction = setup();
+    assert_eq!(bid(&mut auction, BOB, 0), Err(Error::BidTooLow));
+    bid(&mut auction, BOB, 100).unwrap();
+
+    assert_eq!(bid(&mut auction, BOB, 100), Err(Error::BidTooLow));
+    assert_eq!(bid(&mut auction, CHARLIE, 100), Err(Error::BidTooLow));
+    assert_eq!(bid(&mut auction, CHARLIE, 99), Err(Error::BidTooLow));
+    assert_eq!(auction.get_highest_bidder(), Some(BOB));
+    assert_eq!(auction.get_highest_bid(), 100);
+    assert_eq!(leetcoin_test_kit::recorded_events().len(), 1);
+}
+
+#[ink::test]
+fn test_outbid_and_withdraw() {
+    let mut auction = setup();
+    bid(&mut auction, BOB, 100).unwrap();
+    bid(&mut auction, CHARLIE, 150).unwrap();
+    assert_eq!(auction.get_highest_bidder(), Some(CHARLIE));
+    assert_eq!(auction.get_pending_return(BOB), 100);
+
+    // The highest bid stays locked until the auction is finalized.
+    assert_eq!(auction.withdraw(), Err(Error::NothingToWithdraw));
+
+    set_caller(BOB);
+    assert_eq!(auction.withdraw(), Ok(()));
+    assert_eq!(balance_of(BOB), 1000);
+    assert_eq!(auction.get_pending_return(BOB), 0);
+    assert_eq!(auction.withdraw(), Err(Error::NothingToWithdraw));
+}
+
+#[ink::test]
+fn test_self_outbid() {
+    let mut auction = setup();
+    bid(&mut auction, BOB, 100).unwrap();
+    assert_eq!(bid(&mut auction, BOB, 250), Ok(()));
+    assert_eq!(auction.get_highest_bidder(), Some(BOB));
+    assert_eq!(auction.get_highest_bid(), 250);
+    assert_eq!(auction.get_pending_return(BOB), 100);
+
+    assert_eq!(auction.withdraw(), Ok(()));
+    assert_eq!(balance_of(BOB), 750);
+    assert!(matches!(
+        leetcoin_test_kit::decode_events::<HighestBidIncreased>()[..],
+        [
+            HighestBidIncreased { amount: 100, .. },
+            HighestBidIncreased { amount: 250, .. }
+        ]
+    ));
+}
+
+#[ink::test]
+fn test_late_bid() {
+    let mut auction = setup();
+    set_block_timestamp(AUCTION_END - 1);
+    assert_eq!(bid(&mut auction, BOB, 100), Ok(()));
+
+    set_block_timestamp(AUCTION_END);
+    assert_eq!(bid(&mut auction, CHARLIE, 200), Err(Error::BiddingClosed));
+    assert_eq!(auction.get_highest_bidder(), Some(BOB));
+    assert_eq!(auction.get_pending_return(BOB), 0);
+}
+
+#[ink::test]
+fn test_finalize_auction() {
+    let mut auction = setup();
+    bid(&mut auction, BOB, 100).unwrap();
+    bid(&mut auction, CHARLIE, 200).unwrap();
+    let alice_balance = balance_of(ALICE);
+
+    set_block_timestamp(AUCTION_END - 1);
+    assert_eq!(auction.finalize_auction(), Err(Error::AuctionNotEnded));
+
+    set_block_timestamp(AUCTION_END);
+    assert_eq!(auction.finalize_auction(), Ok(()));
+    assert!(auction.is_ended());
+    assert_eq!(balance_of(ALICE), alice_balance + 200);
+    assert_eq!(auction.finalize_auction(), Err(Error::AlreadyFinalized));
+
+    // Outbid bidders can still withdraw after the auction ended.
+    set_caller(BOB);
+    assert_eq!(auction.withdraw(), Ok(()));
+    assert_eq!(balance_of(BOB), 1000);
+
+    let events = leetcoin_test_kit::recorded_events();
+    assert_eq!(events.len(), 3);
+    leetcoin_test_kit::assert_signature::<AuctionEnded>(&events[2]);
+    leetcoin_test_kit::assert_topic(&events[2], &CHARLIE);
+    assert!(matches!(
+        leetcoin_test_kit::decode_events::<AuctionEnded>()[..],
+        [AuctionEnded {
+            winner: Some(CHARLIE),
+            amount: 200
+        }]
+    ));
+}
+
+#[ink::test]
+fn test_finalize_without_bids() {
+    let mut auction = setup();
+    set_block_timestamp(AUCTION_END);
+    assert_eq!(auction.finalize_auction(), Ok(()));
+    assert!(matches!(
+        leetcoin_test_kit::decode_events::<AuctionEnded>()[..],
+        [AuctionEnded {
+            winner: None,
+            amount: 0
+        }]
+    ));
+}
@@ -0,0 +1,53 @@
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{Args, Contract, Event, ReplayError, ToValue, Value};
+use simple_auction::{AuctionEnded, HighestBidIncreased, SimpleAuction};
+
+/// Binds `testcase.txt` to [`SimpleAuction`].
+struct Replay(SimpleAuction);
+
+impl Contract for Replay {
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => Ok(Replay(SimpleAuction::new(args.u64(0)?))),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "place_bid" => Ok(self.0.place_bid().to_value()),
+            "withdraw" => Ok(self.0.withdraw().to_value()),
+            "finalize_auction" => Ok(self.0.finalize_auction().to_value()),
+            "get_highest_bidder" => Ok(self.0.get_highest_bidder().to_value()),
+            "get_highest_bid" => Ok(self.0.get_highest_bid().to_value()),
+            "get_pending_return" => Ok(self.0.get_pending_return(args.account(0)?).to_value()),
+            "get_auction_end" => Ok(self.0.get_auction_end().to_value()),
+            "get_beneficiary" => Ok(self.0.get_beneficiary().to_value()),
+            "is_ended" => Ok(self.0.is_ended().to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        if let Some(event) = decode_event::<HighestBidIncreased>(event) {
+            return Some(
+                Event::new("HighestBidIncreased")
+                    .field("bidder", event.bidder)
+                    .field("amount", event.amount),
+            );
+        }
+        if let Some(event) = decode_event::<AuctionEnded>(event) {
+            return Some(
+                Event::new("AuctionEnded")
+                    .field("winner", event.winner)
+                    .field("amount", event.amount),
+            );
+        }
+        None
+    }
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
@@ -1,45 +1,104 @@
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-use ink_lang as ink;
+pub use self::simple_token::{Error, SimpleToken, Transfer};
 
 #[ink::contract]
 mod simple_token {
-    use ink_prelude::vec::Vec;
+    use ink::storage::Mapping;
 
     #[ink(storage)]
     pub struct SimpleToken {
-        balances: ink_prelude::collections::HashMap<AccountId, Balance>,
+        balances: Mapping<AccountId, Balance>,
+        total_supply: Balance,
+        /// Minting stops once the total supply would exceed the cap.
+        cap: Option<Balance>,
+        owner: AccountId,
+        /// Accounts the owner allowed to mint.
+        minters: Mapping<AccountId, ()>,
     }
 
     #[ink(event)]
     pub struct Transfer {
         #[ink(topic)]
-        from: Option<AccountId>,
+        pub from: Option<AccountId>,
         #[ink(topic)]
-        to: Option<AccountId>,
-        value: Balance,
+        pub to: Option<AccountId>,
+        pub value: Balance,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// Only the owner, or a minter for `mint`, may call this.
+        Unauthorized,
+        /// Minting would raise the total supply above the cap.
+        CapExceeded,
+        InsufficientBalance,
+        /// The total supply would leave the range of `Balance`.
+        Overflow,
     }
 
     impl SimpleToken {
+        /// Creates the token with the caller as its owner and an optional supply cap.
         #[ink(constructor)]
-        pub fn new() -> Self {
+        pub fn new(cap: Option<Balance>) -> Self {
             Self {
-                balances: Default::default(),
+                balances: Mapping::new(),
+                total_supply: 0,
+                cap,
+                owner: Self::env().caller(),
+                minters: Mapping::new(),
             }
         }
 
+        /// Creates `amount` new tokens for `to`, called by the owner or a minter.
         #[ink(message)]
-        pub fn mint(&mut self, to: AccountId, amount: Balance) {
-            let sender = self.env().caller();
-            self.balances
-                .entry(to)
-                .and_modify(|balance| *balance += amount)
-                .or_insert(amount);
+        pub fn mint(&mut self, to: AccountId, amount: Balance) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner && !self.is_minter(caller) {
+                return Err(Error::Unauthorized);
+            }
+            let total_supply = self
+                .total_supply
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+            if self.cap.is_some_and(|cap| total_supply > cap) {
+                return Err(Error::CapExceeded);
+            }
+            let to_balance = self
+                .balance_of(to)
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+            self.total_supply = total_supply;
+            self.balances.insert(to, &to_balance);
             self.env().emit_event(Transfer {
                 from: None,
                 to: Some(to),
                 value: amount,
             });
+            Ok(())
+        }
+
+        /// Destroys `amount` of the caller's tokens, lowering the total supply.
+        #[ink(message)]
+        pub fn burn(&mut self, amount: Balance) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let balance = self.balance_of(caller);
+            if balance < amount {
+                return Err(Error::InsufficientBalance);
+            }
+            let total_supply = self
+                .total_supply
+                .checked_sub(amount)
+                .ok_or(Error::Overflow)?;
+            self.balances.insert(caller, &(balance - amount));
+            self.total_supply = total_supply;
+            self.env().emit_event(Transfer {
+                from: Some(caller),
+                to: None,
+                value: amount,
+            });
+            Ok(())
         }
 
         #[ink(message)]
@@ -49,11 +108,10 @@ mod simple_token {
             if sender_balance < amount {
                 return false;
             }
-            self.balances.insert(sender, sender_balance - amount);
-            self.balances
-                .entry(to)
-                .and_modify(|balance| *balance += amount)
-                .or_insert(amount);
+            self.balances.insert(sender, &(sender_balance - amount));
+            // Cannot saturate, the balances add up to the total supply.
+            let to_balance = self.balance_of(to).saturating_add(amount);
+            self.balances.insert(to, &to_balance);
             self.env().emit_event(Transfer {
                 from: Some(sender),
                 to: Some(to),
@@ -62,85 +120,51 @@ mod simple_token {
             true
         }
 
+        /// Lets `account` mint, called by the owner.
+        #[ink(message)]
+        pub fn add_minter(&mut self, account: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.minters.insert(account, &());
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn remove_minter(&mut self, account: AccountId) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.minters.remove(account);
+            Ok(())
+        }
+
         #[ink(message)]
         pub fn balance_of(&self, owner: AccountId) -> Balance {
-            self.balances.get(&owner).cloned().unwrap_or(0)
+            self.balances.get(owner).unwrap_or(0)
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-
-        use ink_lang as ink;
-
-        #[ink::test]
-        fn test_mint() {
-            let mut token = SimpleToken::new();
-            let initial_balance = token.balance_of(AccountId::from([0x1; 32]));
-            assert_eq!(initial_balance, 0);
-
-            let amount = 100;
-            let to = AccountId::from([0x2; 32]);
-            token.mint(to, amount);
-            assert_eq!(token.balance_of(to), amount);
-
-            let transfer_event = ink_env::test::emitted_events()
-                .find(|event| matches!(event.event, "Transfer"))
-                .expect("no transfer event was emitted");
-            assert_eq!(transfer_event.event, "Transfer");
-            assert_eq!(transfer_event.args[0].clone().into_account_id(), None);
-            assert_eq!(transfer_event.args[1].clone().into_account_id(), Some(to));
-            assert_eq!(transfer_event.args[2].clone().into_balance(), amount);
+        #[ink(message)]
+        pub fn total_supply(&self) -> Balance {
+            self.total_supply
         }
 
-        #[ink::test]
-        fn test_transfer() {
-            let mut token = SimpleToken::new();
-            let sender = AccountId::from([0x1; 32]);
-            let recipient = AccountId::from([0x2; 32]);
-            let amount = 100;
-            token.mint(sender, amount);
-
-            let transfer_success = token.transfer(recipient, amount);
-            assert!(transfer_success);
-            assert_eq!(token.balance_of(sender), 0);
-            assert_eq!(token.balance_of(recipient), amount);
-
-            let transfer_event = ink_env::test::emitted_events()
-                .find(|event| matches!(event.event, "Transfer"))
-                .expect("no transfer event was emitted");
-            assert_eq!(transfer_event.event, "Transfer");
-            assert_eq!(transfer_event.args[0].clone().into_account_id(), Some(sender));
-            assert_eq!(transfer_event.args[1].clone().into_account_id(), Some(recipient));
-            assert_eq!(transfer_event.args[2].clone().into_balance(), amount);
+        #[ink(message)]
+        pub fn get_cap(&self) -> Option<Balance> {
+            self.cap
         }
 
-        #[ink::test]
-        fn test_insufficient_balance_transfer() {
-            let mut token = SimpleToken::new();
-            let sender = AccountId::from([0x1; 32]);
-            let recipient = AccountId::from([0x2; 32]);
-            let amount = 100;
-            token.mint(sender, amount);
-
-            let transfer_success = token.transfer(recipient, amount + 1);
-            assert!(!transfer_success);
-            assert_eq!(token.balance_of(sender), amount);
-            assert_eq!(token.balance_of(recipient), 0);
-
-            let emitted_events = ink_env::test::emitted_events();
-            assert_eq!(emitted_events.len(), 0);
+        #[ink(message)]
+        pub fn get_owner(&self) -> AccountId {
+            self.owner
         }
 
-        #[ink::test]
-        fn test_balance_of() {
-            let mut token = SimpleToken::new();
-            let account = AccountId::from([0x1; 32]);
-            let amount = 100;
-            token.mint(account, amount);
+        #[ink(message)]
+        pub fn is_minter(&self, account: AccountId) -> bool {
+            self.minters.contains(account)
+        }
 
-            assert_eq!(token.balance_of(account), amount);
+        fn ensure_owner(&self) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::Unauthorized);
+            }
+            Ok(())
         }
     }
 }
@@ -0,0 +1,93 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::event;
+use proptest::prelude::*;
+use simple_token::{Error, SimpleToken, Transfer};
+
+#[ink::test]
+fn test_insufficient_balance_transfer() {
+    let mut token = SimpleToken::new(None);
+    let sender = AccountId::from([0x1; 32]);
+    let recipient = AccountId::from([0x2; 32]);
+    let amount = 100;
+    token.mint(sender, amount).unwrap();
+
+    let transfer_success = token.transfer(recipient, amount + 1);
+    assert!(!transfer_success);
+    assert_eq!(token.balance_of(sender), amount);
+    assert_eq!(token.balance_of(recipient), 0);
+
+    // only the mint is recorded
+    leetcoin_test_kit::assert_events([event(Transfer {
+        from: None,
+        to: Some(sender),
+        value: amount,
+    })]);
+}
+
+#[ink::test]
+fn test_burn_more_than_balance() {
+    let mut token = SimpleToken::new(None);
+    let account = AccountId::from([0x1; 32]);
+    token.mint(account, 100).unwrap();
+
+    assert_eq!(token.burn(101), Err(Error::InsufficientBalance));
+    assert_eq!(token.burn(100), Ok(()));
+    assert_eq!(token.burn(1), Err(Error::InsufficientBalance));
+    assert_eq!(token.total_supply(), 0);
+    assert_eq!(leetcoin_test_kit::decode_events::<Transfer>().len(), 2);
+}
+
+proptest! {
+    /// Minting amounts near `Balance::MAX` either fits or fails without changing any
+    /// balance, so the supply never wraps around.
+    #[test]
+    fn test_mint_never_wraps(amounts in prop::collection::vec(u128::MAX - 1_000..=u128::MAX, 1..8)) {
+        leetcoin_test_kit::off_chain(|| {
+            let mut token = SimpleToken::new(None);
+            let accounts = [AccountId::from([0x1; 32]), AccountId::from([0x2; 32])];
+            for (i, amount) in amounts.iter().enumerate() {
+                let account = accounts[i % 2];
+                let (supply, balance) = (token.total_supply(), token.balance_of(account));
+                match supply.checked_add(*amount) {
+                    Some(expected) => {
+                        assert_eq!(token.mint(account, *amount), Ok(()));
+                        assert_eq!(token.total_supply(), expected);
+                        assert_eq!(token.balance_of(account), balance + amount);
+                    }
+                    None => {
+                        assert_eq!(token.mint(account, *amount), Err(Error::Overflow));
+                        assert_eq!(token.total_supply(), supply);
+                        assert_eq!(token.balance_of(account), balance);
+                    }
+                }
+            }
+            let balances = accounts.map(|account| token.balance_of(account));
+            assert_eq!(balances[0] + balances[1], token.total_supply());
+        });
+    }
+
+    /// Transfers and burns keep the balances adding up to the total supply.
+    #[test]
+    fn test_balances_add_up(
+        supply in u128::MAX - 1_000..=u128::MAX,
+        transfers in prop::collection::vec(any::<u128>(), 0..8),
+        burn in any::<u128>(),
+    ) {
+        leetcoin_test_kit::off_chain(|| {
+            let alice = AccountId::from([0x1; 32]);
+            let bob = AccountId::from([0x2; 32]);
+            let mut token = SimpleToken::new(None);
+            token.mint(alice, supply).unwrap();
+            for amount in transfers {
+                let expected = amount <= token.balance_of(alice);
+                assert_eq!(token.transfer(bob, amount), expected);
+            }
+            let balance = token.balance_of(alice);
+            assert_eq!(token.burn(burn).is_ok(), burn <= balance);
+            assert_eq!(
+                token.balance_of(alice) + token.balance_of(bob),
+                token.total_supply()
+            );
+        });
+    }
+}
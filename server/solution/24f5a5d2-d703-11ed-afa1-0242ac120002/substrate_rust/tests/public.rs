@@ -0,0 +1,154 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{event, set_caller};
+use simple_token::{Error, SimpleToken, Transfer};
+
+/// The default caller, which deploys and owns the token.
+const OWNER: AccountId = AccountId([0x1; 32]);
+
+#[ink::test]
+fn test_mint() {
+    let mut token = SimpleToken::new(None);
+    let initial_balance = token.balance_of(AccountId::from([0x1; 32]));
+    assert_eq!(initial_balance, 0);
+
+    let amount = 100;
+    let to = AccountId::from([0x2; 32]);
+    assert_eq!(token.mint(to, amount), Ok(()));
+    assert_eq!(token.balance_of(to), amount);
+    assert_eq!(token.total_supply(), amount);
+
+    leetcoin_test_kit::assert_events([event(Transfer {
+        from: None,
+        to: Some(to),
+        value: amount,
+    })]);
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_signature::<Transfer>(&events[0]);
+    leetcoin_test_kit::assert_topic(&events[0], &to);
+}
+
+#[ink::test]
+fn test_mint_unauthorized() {
+    let mut token = SimpleToken::new(None);
+    assert_eq!(token.get_owner(), OWNER);
+    let account = AccountId::from([0x2; 32]);
+
+    set_caller(account);
+    assert_eq!(token.mint(account, 100), Err(Error::Unauthorized));
+    assert_eq!(token.add_minter(account), Err(Error::Unauthorized));
+    assert_eq!(token.balance_of(account), 0);
+    assert_eq!(token.total_supply(), 0);
+    leetcoin_test_kit::assert_no_events();
+}
+
+#[ink::test]
+fn test_minter() {
+    let mut token = SimpleToken::new(None);
+    let minter = AccountId::from([0x2; 32]);
+    let to = AccountId::from([0x3; 32]);
+
+    assert_eq!(token.add_minter(minter), Ok(()));
+    assert!(token.is_minter(minter));
+    set_caller(minter);
+    assert_eq!(token.mint(to, 100), Ok(()));
+    assert_eq!(token.balance_of(to), 100);
+
+    set_caller(OWNER);
+    assert_eq!(token.remove_minter(minter), Ok(()));
+    assert!(!token.is_minter(minter));
+    set_caller(minter);
+    assert_eq!(token.mint(to, 100), Err(Error::Unauthorized));
+    assert_eq!(token.total_supply(), 100);
+}
+
+#[ink::test]
+fn test_cap() {
+    let mut token = SimpleToken::new(Some(150));
+    let account = AccountId::from([0x2; 32]);
+    assert_eq!(token.get_cap(), Some(150));
+
+    assert_eq!(token.mint(account, 100), Ok(()));
+    assert_eq!(token.mint(account, 51), Err(Error::CapExceeded));
+    assert_eq!(token.mint(account, 50), Ok(()));
+    assert_eq!(token.total_supply(), 150);
+    assert_eq!(token.mint(account, 1), Err(Error::CapExceeded));
+    assert_eq!(leetcoin_test_kit::recorded_events().len(), 2);
+}
+
+#[ink::test]
+fn test_mint_overflow() {
+    let mut token = SimpleToken::new(None);
+    let account = AccountId::from([0x2; 32]);
+
+    assert_eq!(token.mint(account, u128::MAX - 1), Ok(()));
+    assert_eq!(token.mint(OWNER, 2), Err(Error::Overflow));
+    assert_eq!(token.mint(OWNER, 1), Ok(()));
+    assert_eq!(token.total_supply(), u128::MAX);
+    assert_eq!(token.balance_of(OWNER), 1);
+    assert_eq!(token.mint(account, 1), Err(Error::Overflow));
+}
+
+#[ink::test]
+fn test_burn() {
+    let mut token = SimpleToken::new(Some(100));
+    let account = AccountId::from([0x2; 32]);
+    token.mint(account, 100).unwrap();
+
+    set_caller(account);
+    assert_eq!(token.burn(101), Err(Error::InsufficientBalance));
+    assert_eq!(token.burn(40), Ok(()));
+    assert_eq!(token.balance_of(account), 60);
+    assert_eq!(token.total_supply(), 60);
+
+    // Burning makes room under the cap again.
+    set_caller(OWNER);
+    assert_eq!(token.mint(account, 40), Ok(()));
+
+    let events = leetcoin_test_kit::decode_events::<Transfer>();
+    assert_eq!(events.len(), 3);
+    assert_eq!(
+        (events[1].from, events[1].to, events[1].value),
+        (Some(account), None, 40)
+    );
+}
+
+#[ink::test]
+fn test_transfer() {
+    let mut token = SimpleToken::new(None);
+    let sender = AccountId::from([0x1; 32]);
+    let recipient = AccountId::from([0x2; 32]);
+    let amount = 100;
+    token.mint(sender, amount).unwrap();
+
+    let transfer_success = token.transfer(recipient, amount);
+    assert!(transfer_success);
+    assert_eq!(token.balance_of(sender), 0);
+    assert_eq!(token.balance_of(recipient), amount);
+    assert_eq!(token.total_supply(), amount);
+
+    leetcoin_test_kit::assert_events([
+        event(Transfer {
+            from: None,
+            to: Some(sender),
+            value: amount,
+        }),
+        event(Transfer {
+            from: Some(sender),
+            to: Some(recipient),
+            value: amount,
+        }),
+    ]);
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_topic(&events[1], &sender);
+    leetcoin_test_kit::assert_topic(&events[1], &recipient);
+}
+
+#[ink::test]
+fn test_balance_of() {
+    let mut token = SimpleToken::new(None);
+    let account = AccountId::from([0x1; 32]);
+    let amount = 100;
+    token.mint(account, amount).unwrap();
+
+    assert_eq!(token.balance_of(account), amount);
+}
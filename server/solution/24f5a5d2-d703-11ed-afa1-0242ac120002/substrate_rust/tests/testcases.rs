@@ -0,0 +1,49 @@
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{Args, Contract, Event, ReplayError, ToValue, Value};
+use simple_token::{SimpleToken, Transfer};
+
+/// Binds `testcase.txt` to [`SimpleToken`].
+struct Replay(SimpleToken);
+
+impl Contract for Replay {
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => Ok(Replay(SimpleToken::new(args.optional_balance(0)?))),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "mint" => Ok(self.0.mint(args.account(0)?, args.balance(1)?).to_value()),
+            "burn" => Ok(self.0.burn(args.balance(0)?).to_value()),
+            "transfer" => Ok(self
+                .0
+                .transfer(args.account(0)?, args.balance(1)?)
+                .to_value()),
+            "add_minter" => Ok(self.0.add_minter(args.account(0)?).to_value()),
+            "remove_minter" => Ok(self.0.remove_minter(args.account(0)?).to_value()),
+            "balance_of" => Ok(self.0.balance_of(args.account(0)?).to_value()),
+            "total_supply" => Ok(self.0.total_supply().to_value()),
+            "get_cap" => Ok(self.0.get_cap().to_value()),
+            "get_owner" => Ok(self.0.get_owner().to_value()),
+            "is_minter" => Ok(self.0.is_minter(args.account(0)?).to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        let event = decode_event::<Transfer>(event)?;
+        Some(
+            Event::new("Transfer")
+                .field("from", event.from)
+                .field("to", event.to)
+                .field("value", event.value),
+        )
+    }
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
@@ -0,0 +1,208 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::key_value_store::{
+    Error, Insert, KeyValueStore, Remove, MAX_KEYS_PER_ACCOUNT, MAX_KEY_LEN, MAX_VALUE_LEN,
+};
+
+#[ink::contract]
+mod key_value_store {
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// The highest `max_key_len` the store may be created with.
+    pub const MAX_KEY_LEN: u32 = 128;
+    /// The highest `max_value_len` the store may be created with.
+    pub const MAX_VALUE_LEN: u32 = 4096;
+    /// The most keys an account may hold, its key list is stored in a single cell.
+    pub const MAX_KEYS_PER_ACCOUNT: u32 = 32;
+
+    /// Stores byte values under byte keys, separately for every account.
+    ///
+    /// Writing an entry requires a deposit proportional to the bytes it takes up,
+    /// which is paid back once the entry is removed or overwritten.
+    #[ink(storage)]
+    pub struct KeyValueStore {
+        entries: Mapping<EntryKey, Entry>,
+        /// The keys of each account, in the order they were first written.
+        keys: Mapping<AccountId, Vec<Vec<u8>>>,
+        max_key_len: u32,
+        max_value_len: u32,
+        deposit_per_byte: Balance,
+    }
+
+    /// An account and one of its keys.
+    type EntryKey = (AccountId, Vec<u8>);
+
+    /// A stored value along with the deposit paid for it.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    struct Entry {
+        value: Vec<u8>,
+        deposit: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Insert {
+        #[ink(topic)]
+        pub owner: AccountId,
+        pub key: Vec<u8>,
+        pub deposit: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Remove {
+        #[ink(topic)]
+        pub owner: AccountId,
+        pub key: Vec<u8>,
+        /// The deposit paid back to the owner.
+        pub refund: Balance,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        KeyTooLong,
+        ValueTooLong,
+        /// The limits passed to the constructor are above `MAX_KEY_LEN` or
+        /// `MAX_VALUE_LEN`.
+        LimitTooLarge,
+        /// The caller already holds `MAX_KEYS_PER_ACCOUNT` keys.
+        TooManyKeys,
+        /// The value sent along does not match the deposit for the entry.
+        IncorrectDeposit,
+        KeyNotFound,
+        TransferFailed,
+        /// The deposit would exceed `Balance::MAX`.
+        Overflow,
+    }
+
+    impl KeyValueStore {
+        #[ink(constructor)]
+        pub fn new(
+            max_key_len: u32,
+            max_value_len: u32,
+            deposit_per_byte: Balance,
+        ) -> Result<Self, Error> {
+            if max_key_len > MAX_KEY_LEN || max_value_len > MAX_VALUE_LEN {
+                return Err(Error::LimitTooLarge);
+            }
+            Ok(Self {
+                entries: Mapping::new(),
+                keys: Mapping::new(),
+                max_key_len,
+                max_value_len,
+                deposit_per_byte,
+            })
+        }
+
+        /// Stores `value` under the caller's `key`. The deposit for the new entry must
+        /// be sent along, while the deposit for a value it replaces is paid back.
+        #[ink(message, payable)]
+        pub fn insert(&mut self, key: Vec<u8>, value: Vec<u8>) -> Result<(), Error> {
+            if key.len() > self.max_key_len as usize {
+                return Err(Error::KeyTooLong);
+            }
+            if value.len() > self.max_value_len as usize {
+                return Err(Error::ValueTooLong);
+            }
+            let deposit = self.deposit_for(key.len() + value.len())?;
+            if self.env().transferred_value() != deposit {
+                return Err(Error::IncorrectDeposit);
+            }
+
+            let caller = self.env().caller();
+            match self.entries.get((caller, key.clone())) {
+                Some(entry) => self.refund(caller, entry.deposit)?,
+                None => {
+                    let mut keys = self.get_keys(caller);
+                    if keys.len() >= MAX_KEYS_PER_ACCOUNT as usize {
+                        return Err(Error::TooManyKeys);
+                    }
+                    keys.push(key.clone());
+                    self.keys.insert(caller, &keys);
+                }
+            }
+            self.entries
+                .insert((caller, key.clone()), &Entry { value, deposit });
+            self.env().emit_event(Insert {
+                owner: caller,
+                key,
+                deposit,
+            });
+            Ok(())
+        }
+
+        /// Removes the caller's `key` and pays back its deposit.
+        #[ink(message)]
+        pub fn remove(&mut self, key: Vec<u8>) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let refund = self
+                .entries
+                .take((caller, key.clone()))
+                .ok_or(Error::KeyNotFound)?
+                .deposit;
+            let mut keys = self.get_keys(caller);
+            keys.retain(|k| *k != key);
+            if keys.is_empty() {
+                self.keys.remove(caller);
+            } else {
+                self.keys.insert(caller, &keys);
+            }
+            self.refund(caller, refund)?;
+            self.env().emit_event(Remove {
+                owner: caller,
+                key,
+                refund,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get(&self, owner: AccountId, key: Vec<u8>) -> Option<Vec<u8>> {
+            self.entries.get((owner, key)).map(|entry| entry.value)
+        }
+
+        /// Returns the keys `owner` has stored values under.
+        #[ink(message)]
+        pub fn get_keys(&self, owner: AccountId) -> Vec<Vec<u8>> {
+            self.keys.get(owner).unwrap_or_default()
+        }
+
+        /// Returns the deposit held for `owner`'s `key`.
+        #[ink(message)]
+        pub fn get_deposit(&self, owner: AccountId, key: Vec<u8>) -> Balance {
+            self.entries
+                .get((owner, key))
+                .map_or(0, |entry| entry.deposit)
+        }
+
+        #[ink(message)]
+        pub fn get_deposit_per_byte(&self) -> Balance {
+            self.deposit_per_byte
+        }
+
+        /// Returns `(max_key_len, max_value_len)`.
+        #[ink(message)]
+        pub fn get_limits(&self) -> (u32, u32) {
+            (self.max_key_len, self.max_value_len)
+        }
+
+        fn deposit_for(&self, bytes: usize) -> Result<Balance, Error> {
+            (bytes as Balance)
+                .checked_mul(self.deposit_per_byte)
+                .ok_or(Error::Overflow)
+        }
+
+        fn refund(&self, to: AccountId, amount: Balance) -> Result<(), Error> {
+            if amount == 0 {
+                return Ok(());
+            }
+            self.env()
+                .transfer(to, amount)
+                .map_err(|_| Error::TransferFailed)
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use ink::primitives::AccountId;
+use key_value_store::{Error, KeyValueStore, MAX_KEYS_PER_ACCOUNT, MAX_KEY_LEN, MAX_VALUE_LEN};
+use leetcoin_test_kit::{
+    balance_of, callee, default_accounts, set_balance, set_caller, transfer_in,
+};
+
+const BOB: AccountId = AccountId([0x2; 32]);
+
+#[ink::test]
+fn test_deposits_add_up() {
+    let contract = callee();
+    set_balance(contract, 1_000_000);
+    set_caller(BOB);
+    let mut store = KeyValueStore::new(4, 4, 1).unwrap();
+
+    for (key, value) in [(&b"a"[..], &b"1"[..]), (b"bb", b"22"), (b"a", b"333")] {
+        let deposit = (key.len() + value.len()) as u128;
+        transfer_in(deposit);
+        store.insert(key.to_vec(), value.to_vec()).unwrap();
+    }
+    // The first deposit for `a` was paid back when it was overwritten.
+    let held = store.get_deposit(BOB, b"a".to_vec()) + store.get_deposit(BOB, b"bb".to_vec());
+    assert_eq!(held, 8);
+    assert_eq!(balance_of(BOB), 1000 - held);
+
+    store.remove(b"a".to_vec()).unwrap();
+    store.remove(b"bb".to_vec()).unwrap();
+    assert_eq!(balance_of(BOB), 1000);
+    assert_eq!(store.get_keys(BOB), Vec::<Vec<u8>>::new());
+}
+
+#[ink::test]
+fn test_deposit_overflow() {
+    let mut store = KeyValueStore::new(4, 4, u128::MAX).unwrap();
+    assert_eq!(store.insert(vec![], vec![]), Ok(()));
+    assert_eq!(store.insert(vec![1], vec![1]), Err(Error::Overflow));
+}
+
+#[ink::test]
+fn test_limits_are_bounded() {
+    for (max_key_len, max_value_len) in [(MAX_KEY_LEN + 1, 4), (4, MAX_VALUE_LEN + 1)] {
+        assert_eq!(
+            KeyValueStore::new(max_key_len, max_value_len, 1).err(),
+            Some(Error::LimitTooLarge)
+        );
+    }
+    assert!(KeyValueStore::new(MAX_KEY_LEN, MAX_VALUE_LEN, 1).is_ok());
+}
+
+#[ink::test]
+fn test_keys_per_account_are_bounded() {
+    let mut store = KeyValueStore::new(4, 4, 0).unwrap();
+    for key in 0..MAX_KEYS_PER_ACCOUNT {
+        store.insert(key.to_le_bytes().to_vec(), vec![]).unwrap();
+    }
+    assert_eq!(
+        store.insert(MAX_KEYS_PER_ACCOUNT.to_le_bytes().to_vec(), vec![]),
+        Err(Error::TooManyKeys)
+    );
+    // Existing keys can still be overwritten, and removing one makes room.
+    assert_eq!(store.insert(0u32.to_le_bytes().to_vec(), vec![1]), Ok(()));
+    store.remove(0u32.to_le_bytes().to_vec()).unwrap();
+    assert_eq!(
+        store.insert(MAX_KEYS_PER_ACCOUNT.to_le_bytes().to_vec(), vec![]),
+        Ok(())
+    );
+    let caller = default_accounts().alice;
+    assert_eq!(store.get_keys(caller).len(), MAX_KEYS_PER_ACCOUNT as usize);
+}
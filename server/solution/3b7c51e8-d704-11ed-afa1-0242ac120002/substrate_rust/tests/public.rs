@@ -0,0 +1,124 @@
+use ink::primitives::AccountId;
+use key_value_store::{Error, Insert, KeyValueStore, Remove};
+use leetcoin_test_kit::{
+    balance_of, callee, event, set_balance, set_caller, set_value_transferred, transfer_in,
+};
+
+/// Starts with a balance of 1000 in the off-chain environment.
+const BOB: AccountId = AccountId([0x2; 32]);
+const CHARLIE: AccountId = AccountId([0x3; 32]);
+
+/// Keys of up to 8 bytes, values of up to 16 bytes and a deposit of 10 per byte.
+fn setup() -> KeyValueStore {
+    let contract = callee();
+    set_balance(contract, 1_000_000);
+    set_caller(BOB);
+    KeyValueStore::new(8, 16, 10).unwrap()
+}
+
+/// Calls `insert` as the current caller, sending `deposit` along.
+fn insert(store: &mut KeyValueStore, key: &[u8], value: &[u8], deposit: u128) -> Result<(), Error> {
+    transfer_in(deposit);
+    store.insert(key.to_vec(), value.to_vec())
+}
+
+#[ink::test]
+fn test_insert() {
+    let mut store = setup();
+    assert_eq!(store.get_limits(), (8, 16));
+    assert_eq!(store.get_deposit_per_byte(), 10);
+
+    assert_eq!(insert(&mut store, b"name", b"bob", 70), Ok(()));
+    assert_eq!(store.get(BOB, b"name".to_vec()), Some(b"bob".to_vec()));
+    assert_eq!(store.get_deposit(BOB, b"name".to_vec()), 70);
+    assert_eq!(balance_of(BOB), 930);
+
+    leetcoin_test_kit::assert_events([event(Insert {
+        owner: BOB,
+        key: b"name".to_vec(),
+        deposit: 70,
+    })]);
+}
+
+#[ink::test]
+fn test_insert_requires_deposit() {
+    let mut store = setup();
+
+    set_value_transferred(69);
+    assert_eq!(
+        store.insert(b"name".to_vec(), b"bob".to_vec()),
+        Err(Error::IncorrectDeposit)
+    );
+    set_value_transferred(71);
+    assert_eq!(
+        store.insert(b"name".to_vec(), b"bob".to_vec()),
+        Err(Error::IncorrectDeposit)
+    );
+    assert_eq!(store.get(BOB, b"name".to_vec()), None);
+    assert_eq!(store.get_keys(BOB), Vec::<Vec<u8>>::new());
+    leetcoin_test_kit::assert_no_events();
+}
+
+#[ink::test]
+fn test_size_limits() {
+    let mut store = setup();
+    set_value_transferred(0);
+
+    assert_eq!(store.insert(vec![0; 9], vec![]), Err(Error::KeyTooLong));
+    assert_eq!(
+        store.insert(vec![0; 8], vec![0; 17]),
+        Err(Error::ValueTooLong)
+    );
+    assert_eq!(insert(&mut store, &[0; 8], &[0; 16], 240), Ok(()));
+}
+
+#[ink::test]
+fn test_overwrite_refunds_previous_deposit() {
+    let mut store = setup();
+    insert(&mut store, b"name", b"bob", 70).unwrap();
+
+    assert_eq!(insert(&mut store, b"name", b"robert", 100), Ok(()));
+    assert_eq!(store.get(BOB, b"name".to_vec()), Some(b"robert".to_vec()));
+    assert_eq!(store.get_deposit(BOB, b"name".to_vec()), 100);
+    assert_eq!(balance_of(BOB), 900);
+    // Overwriting does not list the key twice.
+    assert_eq!(store.get_keys(BOB), [b"name".to_vec()]);
+}
+
+#[ink::test]
+fn test_remove() {
+    let mut store = setup();
+    insert(&mut store, b"a", b"1", 20).unwrap();
+    insert(&mut store, b"b", b"22", 30).unwrap();
+    assert_eq!(store.get_keys(BOB), [b"a".to_vec(), b"b".to_vec()]);
+
+    assert_eq!(store.remove(b"a".to_vec()), Ok(()));
+    assert_eq!(store.get(BOB, b"a".to_vec()), None);
+    assert_eq!(store.get_keys(BOB), [b"b".to_vec()]);
+    assert_eq!(balance_of(BOB), 970);
+    assert_eq!(store.remove(b"a".to_vec()), Err(Error::KeyNotFound));
+
+    let events = leetcoin_test_kit::recorded_events();
+    assert_eq!(events.len(), 3);
+    assert_eq!(
+        leetcoin_test_kit::decode_event::<Remove>(&events[2]).map(|event| event.refund),
+        Some(20)
+    );
+}
+
+#[ink::test]
+fn test_keys_are_per_account() {
+    let mut store = setup();
+    insert(&mut store, b"name", b"bob", 70).unwrap();
+
+    set_caller(CHARLIE);
+    assert_eq!(store.get_keys(CHARLIE), Vec::<Vec<u8>>::new());
+    assert_eq!(store.remove(b"name".to_vec()), Err(Error::KeyNotFound));
+    assert_eq!(insert(&mut store, b"name", b"charlie", 110), Ok(()));
+
+    assert_eq!(store.get(BOB, b"name".to_vec()), Some(b"bob".to_vec()));
+    assert_eq!(
+        store.get(CHARLIE, b"name".to_vec()),
+        Some(b"charlie".to_vec())
+    );
+}
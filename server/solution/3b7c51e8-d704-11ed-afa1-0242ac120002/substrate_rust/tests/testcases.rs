@@ -0,0 +1,58 @@
+use key_value_store::{Insert, KeyValueStore, Remove};
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{Args, Contract, Event, ReplayError, ToValue, Value};
+
+/// Binds `testcase.txt` to [`KeyValueStore`].
+struct Replay(KeyValueStore);
+
+impl Contract for Replay {
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => KeyValueStore::new(args.u32(0)?, args.u32(1)?, args.balance(2)?)
+                .map(Replay)
+                .map_err(|err| ReplayError::new(format!("`new` failed with {:?}", err))),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "insert" => Ok(self.0.insert(args.bytes(0)?, args.bytes(1)?).to_value()),
+            "remove" => Ok(self.0.remove(args.bytes(0)?).to_value()),
+            "get" => Ok(self.0.get(args.account(0)?, args.bytes(1)?).to_value()),
+            "get_keys" => Ok(self.0.get_keys(args.account(0)?).to_value()),
+            "get_deposit" => Ok(self
+                .0
+                .get_deposit(args.account(0)?, args.bytes(1)?)
+                .to_value()),
+            "get_deposit_per_byte" => Ok(self.0.get_deposit_per_byte().to_value()),
+            "get_limits" => Ok(self.0.get_limits().to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        if let Some(event) = decode_event::<Insert>(event) {
+            return Some(
+                Event::new("Insert")
+                    .field("owner", event.owner)
+                    .field("key", event.key)
+                    .field("deposit", event.deposit),
+            );
+        }
+        if let Some(event) = decode_event::<Remove>(event) {
+            return Some(
+                Event::new("Remove")
+                    .field("owner", event.owner)
+                    .field("key", event.key)
+                    .field("refund", event.refund),
+            );
+        }
+        None
+    }
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
@@ -0,0 +1,182 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::timed_lock::{Error, Lock, LockFunds, TimedLock, UnlockFunds, MAX_LOCKS_PER_ACCOUNT};
+
+#[ink::contract]
+mod timed_lock {
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// The most locks an account may hold at once, their ids are stored in a single
+    /// cell.
+    pub const MAX_LOCKS_PER_ACCOUNT: u32 = 32;
+
+    /// Holds funds sent along with `lock` until their release time, after which only
+    /// the account that locked them can take them back.
+    ///
+    /// An account can hold up to `MAX_LOCKS_PER_ACCOUNT` locks, each with its own
+    /// release time.
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct TimedLock {
+        locks: Mapping<u32, Lock>,
+        /// The ids of each account's locks, in the order they were created.
+        lock_ids: Mapping<AccountId, Vec<u32>>,
+        next_id: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Lock {
+        pub owner: AccountId,
+        pub amount: Balance,
+        /// The funds can be unlocked from this timestamp on.
+        pub release_time: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct LockFunds {
+        #[ink(topic)]
+        pub owner: AccountId,
+        pub id: u32,
+        pub amount: Balance,
+        pub release_time: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct UnlockFunds {
+        #[ink(topic)]
+        pub owner: AccountId,
+        pub id: u32,
+        pub amount: Balance,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// No value was sent along to lock.
+        ZeroAmount,
+        /// The release time would exceed `Timestamp::MAX`.
+        InvalidDuration,
+        /// The caller already holds `MAX_LOCKS_PER_ACCOUNT` locks.
+        TooManyLocks,
+        LockNotFound,
+        /// Only the account that created a lock can unlock it.
+        Unauthorized,
+        /// The release time of the lock was not reached yet.
+        StillLocked,
+        TransferFailed,
+        /// Every lock id was used up.
+        Overflow,
+    }
+
+    impl TimedLock {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Locks the value sent along for `duration`, returning the id of the new lock.
+        #[ink(message, payable)]
+        pub fn lock(&mut self, duration: Timestamp) -> Result<u32, Error> {
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(Error::ZeroAmount);
+            }
+            let release_time = self
+                .env()
+                .block_timestamp()
+                .checked_add(duration)
+                .ok_or(Error::InvalidDuration)?;
+            let owner = self.env().caller();
+            let mut ids = self.get_locks(owner);
+            if ids.len() >= MAX_LOCKS_PER_ACCOUNT as usize {
+                return Err(Error::TooManyLocks);
+            }
+            let id = self.next_id;
+            self.next_id = id.checked_add(1).ok_or(Error::Overflow)?;
+
+            self.locks.insert(
+                id,
+                &Lock {
+                    owner,
+                    amount,
+                    release_time,
+                },
+            );
+            ids.push(id);
+            self.lock_ids.insert(owner, &ids);
+            self.env().emit_event(LockFunds {
+                owner,
+                id,
+                amount,
+                release_time,
+            });
+            Ok(id)
+        }
+
+        /// Pays the funds of lock `id` back to its owner once its release time was reached.
+        #[ink(message)]
+        pub fn unlock(&mut self, id: u32) -> Result<(), Error> {
+            let lock = self.locks.get(id).ok_or(Error::LockNotFound)?;
+            let caller = self.env().caller();
+            if caller != lock.owner {
+                return Err(Error::Unauthorized);
+            }
+            if self.env().block_timestamp() < lock.release_time {
+                return Err(Error::StillLocked);
+            }
+
+            self.env()
+                .transfer(caller, lock.amount)
+                .map_err(|_| Error::TransferFailed)?;
+            self.locks.remove(id);
+            let mut ids = self.get_locks(caller);
+            ids.retain(|lock_id| *lock_id != id);
+            if ids.is_empty() {
+                self.lock_ids.remove(caller);
+            } else {
+                self.lock_ids.insert(caller, &ids);
+            }
+            self.env().emit_event(UnlockFunds {
+                owner: caller,
+                id,
+                amount: lock.amount,
+            });
+            Ok(())
+        }
+
+        /// Returns the time left until lock `id` can be unlocked, zero once it can.
+        #[ink(message)]
+        pub fn get_remaining_time(&self, id: u32) -> Option<Timestamp> {
+            let now = self.env().block_timestamp();
+            self.locks
+                .get(id)
+                .map(|lock| lock.release_time.saturating_sub(now))
+        }
+
+        #[ink(message)]
+        pub fn get_lock(&self, id: u32) -> Option<Lock> {
+            self.locks.get(id)
+        }
+
+        /// Returns the ids of the locks `owner` has not unlocked yet.
+        #[ink(message)]
+        pub fn get_locks(&self, owner: AccountId) -> Vec<u32> {
+            self.lock_ids.get(owner).unwrap_or_default()
+        }
+
+        /// Returns the total amount `owner` has locked.
+        #[ink(message)]
+        pub fn get_locked_balance(&self, owner: AccountId) -> Balance {
+            self.get_locks(owner)
+                .into_iter()
+                .filter_map(|id| self.locks.get(id))
+                .map(|lock| lock.amount)
+                .sum()
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{balance_of, set_block_timestamp, set_caller, transfer_in};
+use timed_lock::{Error, TimedLock, MAX_LOCKS_PER_ACCOUNT};
+
+const BOB: AccountId = AccountId([0x2; 32]);
+const CHARLIE: AccountId = AccountId([0x3; 32]);
+
+fn lock_as(timed_lock: &mut TimedLock, owner: AccountId, amount: u128, duration: u64) -> u32 {
+    set_caller(owner);
+    transfer_in(amount);
+    timed_lock.lock(duration).unwrap()
+}
+
+#[ink::test]
+fn test_locks_of_several_accounts() {
+    let mut timed_lock = TimedLock::new();
+    let bob_lock = lock_as(&mut timed_lock, BOB, 100, 50);
+    let charlie_lock = lock_as(&mut timed_lock, CHARLIE, 200, 50);
+    assert_ne!(bob_lock, charlie_lock);
+    assert_eq!(timed_lock.get_locks(BOB), vec![bob_lock]);
+    assert_eq!(timed_lock.get_locks(CHARLIE), vec![charlie_lock]);
+
+    // charlie cannot take bob's funds even once they are released
+    set_block_timestamp(50);
+    assert_eq!(timed_lock.unlock(bob_lock), Err(Error::Unauthorized));
+    assert_eq!(timed_lock.unlock(charlie_lock), Ok(()));
+    assert_eq!(timed_lock.get_locked_balance(BOB), 100);
+    assert_eq!(timed_lock.get_locked_balance(CHARLIE), 0);
+    assert_eq!(balance_of(CHARLIE), 1000);
+}
+
+#[ink::test]
+fn test_release_time_counts_from_lock() {
+    let mut timed_lock = TimedLock::new();
+    set_block_timestamp(1_000);
+    let first = lock_as(&mut timed_lock, BOB, 100, 100);
+    set_block_timestamp(1_050);
+    let second = lock_as(&mut timed_lock, BOB, 100, 100);
+
+    assert_eq!(timed_lock.get_remaining_time(first), Some(50));
+    assert_eq!(timed_lock.get_remaining_time(second), Some(100));
+    set_block_timestamp(1_100);
+    assert_eq!(timed_lock.unlock(first), Ok(()));
+    assert_eq!(timed_lock.unlock(second), Err(Error::StillLocked));
+}
+
+#[ink::test]
+fn test_locks_per_account_are_bounded() {
+    let mut timed_lock = TimedLock::new();
+    for _ in 0..MAX_LOCKS_PER_ACCOUNT {
+        lock_as(&mut timed_lock, BOB, 10, 0);
+    }
+    transfer_in(10);
+    assert_eq!(timed_lock.lock(0), Err(Error::TooManyLocks));
+
+    // Other accounts are not affected, and unlocking makes room again.
+    lock_as(&mut timed_lock, CHARLIE, 10, 0);
+    set_caller(BOB);
+    timed_lock.unlock(0).unwrap();
+    lock_as(&mut timed_lock, BOB, 10, 0);
+    assert_eq!(
+        timed_lock.get_locks(BOB).len(),
+        MAX_LOCKS_PER_ACCOUNT as usize
+    );
+}
@@ -0,0 +1,144 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{balance_of, event, set_block_timestamp, set_caller, transfer_in};
+use timed_lock::{Error, Lock, LockFunds, TimedLock, UnlockFunds};
+
+/// Starts with a balance of 1000 in the off-chain environment.
+const BOB: AccountId = AccountId([0x2; 32]);
+const CHARLIE: AccountId = AccountId([0x3; 32]);
+
+fn setup() -> TimedLock {
+    set_caller(BOB);
+    TimedLock::new()
+}
+
+/// Calls `lock` as the current caller, sending `amount` along.
+fn lock(timed_lock: &mut TimedLock, amount: u128, duration: u64) -> Result<u32, Error> {
+    transfer_in(amount);
+    timed_lock.lock(duration)
+}
+
+#[ink::test]
+fn test_lock() {
+    let mut timed_lock = setup();
+    set_block_timestamp(100);
+    assert_eq!(lock(&mut timed_lock, 300, 60), Ok(0));
+    assert_eq!(
+        timed_lock.get_lock(0),
+        Some(Lock {
+            owner: BOB,
+            amount: 300,
+            release_time: 160,
+        })
+    );
+    assert_eq!(timed_lock.get_locks(BOB), vec![0]);
+    assert_eq!(timed_lock.get_locked_balance(BOB), 300);
+    assert_eq!(balance_of(BOB), 700);
+
+    leetcoin_test_kit::assert_events([event(LockFunds {
+        owner: BOB,
+        id: 0,
+        amount: 300,
+        release_time: 160,
+    })]);
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_topic(&events[0], &BOB);
+}
+
+#[ink::test]
+fn test_lock_requires_value() {
+    let mut timed_lock = setup();
+    assert_eq!(lock(&mut timed_lock, 0, 60), Err(Error::ZeroAmount));
+
+    set_block_timestamp(1);
+    assert_eq!(
+        lock(&mut timed_lock, 100, u64::MAX),
+        Err(Error::InvalidDuration)
+    );
+    assert!(timed_lock.get_locks(BOB).is_empty());
+    leetcoin_test_kit::assert_no_events();
+}
+
+#[ink::test]
+fn test_unlock() {
+    let mut timed_lock = setup();
+    lock(&mut timed_lock, 300, 60).unwrap();
+
+    set_block_timestamp(59);
+    assert_eq!(timed_lock.unlock(0), Err(Error::StillLocked));
+    assert_eq!(balance_of(BOB), 700);
+
+    set_block_timestamp(60);
+    assert_eq!(timed_lock.unlock(0), Ok(()));
+    assert_eq!(balance_of(BOB), 1000);
+    assert_eq!(timed_lock.get_lock(0), None);
+    assert!(timed_lock.get_locks(BOB).is_empty());
+    assert_eq!(timed_lock.unlock(0), Err(Error::LockNotFound));
+
+    assert!(matches!(
+        leetcoin_test_kit::decode_events::<UnlockFunds>()[..],
+        [UnlockFunds {
+            id: 0,
+            amount: 300,
+            ..
+        }]
+    ));
+}
+
+#[ink::test]
+fn test_unlock_unauthorized() {
+    let mut timed_lock = setup();
+    lock(&mut timed_lock, 300, 60).unwrap();
+
+    set_block_timestamp(60);
+    set_caller(CHARLIE);
+    assert_eq!(timed_lock.unlock(0), Err(Error::Unauthorized));
+    assert_eq!(balance_of(CHARLIE), 1000);
+    assert_eq!(timed_lock.get_locked_balance(BOB), 300);
+}
+
+#[ink::test]
+fn test_get_remaining_time() {
+    let mut timed_lock = setup();
+    assert_eq!(timed_lock.get_remaining_time(0), None);
+
+    set_block_timestamp(1_000);
+    lock(&mut timed_lock, 100, 500).unwrap();
+    assert_eq!(timed_lock.get_remaining_time(0), Some(500));
+
+    set_block_timestamp(1_200);
+    assert_eq!(timed_lock.get_remaining_time(0), Some(300));
+
+    set_block_timestamp(1_500);
+    assert_eq!(timed_lock.get_remaining_time(0), Some(0));
+    set_block_timestamp(2_000);
+    assert_eq!(timed_lock.get_remaining_time(0), Some(0));
+
+    timed_lock.unlock(0).unwrap();
+    assert_eq!(timed_lock.get_remaining_time(0), None);
+}
+
+#[ink::test]
+fn test_multiple_locks() {
+    let mut timed_lock = setup();
+    assert_eq!(lock(&mut timed_lock, 100, 300), Ok(0));
+    assert_eq!(lock(&mut timed_lock, 200, 100), Ok(1));
+    assert_eq!(lock(&mut timed_lock, 300, 200), Ok(2));
+    assert_eq!(timed_lock.get_locks(BOB), vec![0, 1, 2]);
+    assert_eq!(timed_lock.get_locked_balance(BOB), 600);
+
+    // Each lock is released on its own schedule.
+    set_block_timestamp(150);
+    assert_eq!(timed_lock.unlock(0), Err(Error::StillLocked));
+    assert_eq!(timed_lock.unlock(1), Ok(()));
+    assert_eq!(timed_lock.unlock(2), Err(Error::StillLocked));
+    assert_eq!(timed_lock.get_locks(BOB), vec![0, 2]);
+    assert_eq!(timed_lock.get_locked_balance(BOB), 400);
+    assert_eq!(balance_of(BOB), 600);
+
+    set_block_timestamp(300);
+    assert_eq!(timed_lock.unlock(2), Ok(()));
+    assert_eq!(timed_lock.unlock(0), Ok(()));
+    assert!(timed_lock.get_locks(BOB).is_empty());
+    assert_eq!(balance_of(BOB), 1000);
+    assert_eq!(leetcoin_test_kit::decode_events::<UnlockFunds>().len(), 3);
+}
@@ -0,0 +1,65 @@
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{json, Args, Contract, Event, ReplayError, ToValue, Value};
+use timed_lock::{Lock, LockFunds, TimedLock, UnlockFunds};
+
+/// Binds `testcase.txt` to [`TimedLock`].
+struct Replay(TimedLock);
+
+fn lock_value(lock: &Lock) -> Value {
+    json!({
+        "owner": lock.owner.to_value(),
+        "amount": lock.amount.to_value(),
+        "release_time": lock.release_time,
+    })
+}
+
+impl Contract for Replay {
+    fn construct(constructor: &str, _args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => Ok(Replay(TimedLock::new())),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "lock" => Ok(self.0.lock(args.u64(0)?).to_value()),
+            "unlock" => Ok(self.0.unlock(args.u32(0)?).to_value()),
+            "get_remaining_time" => Ok(self.0.get_remaining_time(args.u32(0)?).to_value()),
+            "get_lock" => Ok(self
+                .0
+                .get_lock(args.u32(0)?)
+                .as_ref()
+                .map_or(Value::Null, lock_value)),
+            "get_locks" => Ok(self.0.get_locks(args.account(0)?).to_value()),
+            "get_locked_balance" => Ok(self.0.get_locked_balance(args.account(0)?).to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        if let Some(event) = decode_event::<LockFunds>(event) {
+            return Some(
+                Event::new("LockFunds")
+                    .field("owner", event.owner)
+                    .field("id", event.id)
+                    .field("amount", event.amount)
+                    .field("release_time", event.release_time),
+            );
+        }
+        if let Some(event) = decode_event::<UnlockFunds>(event) {
+            return Some(
+                Event::new("UnlockFunds")
+                    .field("owner", event.owner)
+                    .field("id", event.id)
+                    .field("amount", event.amount),
+            );
+        }
+        None
+    }
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
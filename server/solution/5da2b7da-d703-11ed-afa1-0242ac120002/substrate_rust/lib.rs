@@ -1,76 +1,631 @@
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-use ink_lang as ink;
+pub use self::voting::{
+    Ballot, CastBallot, ChangeBallot, ChangeVote, CommitVote, Delegate, Error, GetResults,
+    ProposeOption, Tally, Transfer, Vote, Voter, Voting, Weighting, MAX_OPTIONS,
+    MAX_OPTION_NAME_LEN, MAX_RUNOFF_VOTERS,
+};
 
 #[ink::contract]
 mod voting {
-    use ink_prelude::vec::Vec;
+    use ink::env::hash::Blake2x256;
+    use ink::prelude::string::String;
+    use ink::prelude::vec;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
 
+    /// The most options a poll may have, all of them are stored in a single cell.
+    pub const MAX_OPTIONS: u32 = 16;
+    /// The longest option name in bytes.
+    pub const MAX_OPTION_NAME_LEN: u32 = 64;
+    /// The most accounts that may cast a ballot of their own in an instant runoff
+    /// poll, as finalizing it loads all of their ballots.
+    pub const MAX_RUNOFF_VOTERS: u32 = 256;
+
+    /// A poll run by its deployer, the chairperson.
+    ///
+    /// The chairperson proposes the options before `voting_start`. Votes are accepted
+    /// from block `voting_start` up to, but excluding, block `voting_end`, after which
+    /// anyone can finalize the poll. While voting is open, a voter can change their
+    /// vote or delegate their weight to another account instead of voting.
+    ///
+    /// A poll deployed with a `reveal_end` is secret: voters only commit to a hash of
+    /// their ballot while voting is open, and reveal the ballot from `voting_end` up to
+    /// `reveal_end`. Ballots that are not revealed in time are not counted.
+    ///
+    /// An instant runoff poll accepts ballots from at most `MAX_RUNOFF_VOTERS`
+    /// accounts, so that finalizing it stays within a single call. Further accounts
+    /// can still delegate their weight to one of them.
+    ///
+    /// A weighted poll keeps a registry of token balances, which the chairperson mints
+    /// and the holders transfer at any time. A vote counts with the voter's balance as
+    /// it was when voting started, so tokens transferred during the vote count once.
     #[ink(storage)]
     pub struct Voting {
-        options: ink_prelude::collections::Vec<(String, u32)>,
-        voters: ink_prelude::collections::HashMap<AccountId, bool>,
+        chairperson: AccountId,
+        /// Each option with its count, see [`Tally`] for what is counted.
+        options: Vec<(String, Balance)>,
+        voters: Mapping<AccountId, Voter>,
+        weighting: Weighting,
+        /// The token balance of each account in a weighted poll.
+        balances: Mapping<AccountId, Balance>,
+        /// The balance an account held when voting started, recorded the first time it
+        /// changes afterwards. Accounts without one still hold that balance.
+        snapshots: Mapping<AccountId, Balance>,
+        total_supply: Balance,
+        tally: Tally,
+        /// The accounts that cast a ballot of their own in an instant runoff poll, to
+        /// run the rounds from when it is finalized, indexed in the order they voted.
+        ballot_holders: Mapping<u32, AccountId>,
+        ballot_holder_count: u32,
+        voting_start: BlockNumber,
+        voting_end: BlockNumber,
+        reveal_end: Option<BlockNumber>,
+        /// The committed hashes of the ballots in a secret poll, until they are revealed.
+        commitments: Mapping<AccountId, Hash>,
+        finalized: bool,
+        /// The index of the winning option, once finalized.
+        winner: Option<u32>,
+    }
+
+    /// How much a vote counts.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum Weighting {
+        /// Every account's vote counts as 1.
+        #[default]
+        OnePerAccount,
+        /// Votes count with the account's balance in the poll's token registry when
+        /// voting started, accounts without one cannot vote.
+        Registered,
+    }
+
+    /// How the ballots are counted and the winner is picked. Ties always go to the
+    /// earliest proposed option.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum Tally {
+        /// Voters cast a [`Ballot::Choice`] and the option with the most weight wins.
+        #[default]
+        Plurality,
+        /// Voters cast [`Ballot::Votes`], paying the square of the votes they give an
+        /// option out of a budget of `credits` per unit of weight. The option with the
+        /// most votes wins.
+        Quadratic { credits: Balance },
+        /// Voters cast a [`Ballot::Ranking`]. The options count the weight of the
+        /// ballots ranking them first, and the last one is eliminated until an option
+        /// holds a majority of the ballots that still rank one of the remaining options.
+        InstantRunoff,
+    }
+
+    /// What a voter votes for, which must match the [`Tally`] of the poll.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum Ballot {
+        /// The index of a single option.
+        Choice(u32),
+        /// The number of votes given to each option, by option index. Options past
+        /// the end of the list get none.
+        Votes(Vec<u32>),
+        /// Option indices in order of preference. Options left out are not ranked.
+        Ranking(Vec<u32>),
+    }
+
+    /// What an account did with its vote.
+    #[derive(Debug, Default, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Voter {
+        /// The weight other accounts delegated to this one, counted with its own weight
+        /// when it votes.
+        pub delegated_weight: Balance,
+        /// The ballot this account cast itself.
+        pub ballot: Option<Ballot>,
+        /// The account this one delegated its weight to, instead of voting.
+        pub delegate: Option<AccountId>,
     }
 
     #[ink(event)]
     pub struct ProposeOption {
         #[ink(topic)]
-        name: String,
+        pub name: String,
     }
 
     #[ink(event)]
     pub struct Vote {
         #[ink(topic)]
-        voter: AccountId,
+        pub voter: AccountId,
+        #[ink(topic)]
+        pub option_index: u32,
+        pub weight: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ChangeVote {
+        #[ink(topic)]
+        pub voter: AccountId,
+        pub from_option: u32,
+        pub to_option: u32,
+        pub weight: Balance,
+    }
+
+    #[ink(event)]
+    pub struct CastBallot {
+        #[ink(topic)]
+        pub voter: AccountId,
+        pub ballot: Ballot,
+        pub weight: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ChangeBallot {
         #[ink(topic)]
-        option_index: u32,
+        pub voter: AccountId,
+        /// The new ballot.
+        pub ballot: Ballot,
+        pub weight: Balance,
     }
 
+    /// Emitted when a voter commits to a ballot in a secret poll, which is emitted as
+    /// [`CastBallot`] once revealed.
+    #[ink(event)]
+    pub struct CommitVote {
+        #[ink(topic)]
+        pub voter: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Delegate {
+        #[ink(topic)]
+        pub delegator: AccountId,
+        /// The end of the delegation chain, which received the weight.
+        #[ink(topic)]
+        pub delegate: AccountId,
+        pub weight: Balance,
+    }
+
+    /// Emitted when tokens of a weighted poll are minted, with no `from`, or
+    /// transferred.
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub value: Balance,
+    }
+
+    /// Emitted once, when the poll is finalized.
     #[ink(event)]
     pub struct GetResults {
-        results: Vec<(String, u32)>,
+        pub results: Vec<(String, Balance)>,
+        pub winner: Option<u32>,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// Only the chairperson may propose options and mint tokens.
+        Unauthorized,
+        /// `voting_start` must come before `voting_end`, and `voting_end` before
+        /// `reveal_end`.
+        InvalidPeriod,
+        /// Options can only be proposed before voting starts.
+        ProposalsClosed,
+        /// The poll already has `MAX_OPTIONS` options.
+        TooManyOptions,
+        /// The option name is longer than `MAX_OPTION_NAME_LEN` bytes.
+        NameTooLong,
+        /// Only a weighted poll keeps a token registry.
+        NotRegistered,
+        InsufficientBalance,
+        /// The caller held no tokens when voting started in a weighted poll and has no
+        /// weight delegated to it.
+        NoVotingPower,
+        VotingNotStarted,
+        VotingClosed,
+        /// The caller already voted or delegated.
+        AlreadyVoted,
+        /// The caller has no vote of its own to change, or no commitment to reveal.
+        NotVoted,
+        /// The delegation chain would lead back to the caller.
+        DelegationCycle,
+        OptionNotFound,
+        /// The ballot does not match the tally of the poll, votes for nothing or ranks
+        /// an option twice.
+        InvalidBallot,
+        /// The quadratic cost of the votes exceeds the caller's credits.
+        InsufficientCredits,
+        /// Ballots of a secret poll must be committed and revealed.
+        SecretPoll,
+        /// Only ballots of a secret poll are committed and revealed.
+        PublicPoll,
+        /// Ballots are revealed once voting ended.
+        RevealNotStarted,
+        RevealClosed,
+        /// The revealed ballot and salt do not hash to the caller's commitment.
+        CommitmentMismatch,
+        /// A count, a delegated weight or the token supply would exceed `Balance::MAX`.
+        Overflow,
+        /// `MAX_RUNOFF_VOTERS` accounts already cast a ballot in the instant runoff
+        /// poll.
+        TooManyVoters,
+        /// The poll can only be finalized once voting ended.
+        VotingNotEnded,
+        AlreadyFinalized,
     }
 
     impl Voting {
         #[ink(constructor)]
-        pub fn new() -> Self {
-            Self {
-                options: ink_prelude::collections::Vec::new(),
-                voters: ink_prelude::collections::HashMap::new(),
+        pub fn new(
+            voting_start: BlockNumber,
+            voting_end: BlockNumber,
+            weighting: Weighting,
+            tally: Tally,
+            reveal_end: Option<BlockNumber>,
+        ) -> Result<Self, Error> {
+            if voting_start >= voting_end || reveal_end.is_some_and(|end| voting_end >= end) {
+                return Err(Error::InvalidPeriod);
             }
+            Ok(Self {
+                chairperson: Self::env().caller(),
+                options: Vec::new(),
+                voters: Mapping::new(),
+                weighting,
+                balances: Mapping::new(),
+                snapshots: Mapping::new(),
+                total_supply: 0,
+                tally,
+                ballot_holders: Mapping::new(),
+                ballot_holder_count: 0,
+                voting_start,
+                voting_end,
+                reveal_end,
+                commitments: Mapping::new(),
+                finalized: false,
+                winner: None,
+            })
         }
 
         #[ink(message)]
-        pub fn propose_option(&mut self, name: String) {
+        pub fn propose_option(&mut self, name: String) -> Result<(), Error> {
+            if self.env().caller() != self.chairperson {
+                return Err(Error::Unauthorized);
+            }
+            if self.env().block_number() >= self.voting_start {
+                return Err(Error::ProposalsClosed);
+            }
+            if self.options.len() >= MAX_OPTIONS as usize {
+                return Err(Error::TooManyOptions);
+            }
+            if name.len() > MAX_OPTION_NAME_LEN as usize {
+                return Err(Error::NameTooLong);
+            }
             self.options.push((name.clone(), 0));
             self.env().emit_event(ProposeOption { name });
+            Ok(())
         }
 
+        /// Mints `value` tokens of a weighted poll to `to`, called by the chairperson.
         #[ink(message)]
-        pub fn vote(&mut self, option_index: u32) -> bool {
+        pub fn mint(&mut self, to: AccountId, value: Balance) -> Result<(), Error> {
+            if self.env().caller() != self.chairperson {
+                return Err(Error::Unauthorized);
+            }
+            if self.weighting != Weighting::Registered {
+                return Err(Error::NotRegistered);
+            }
+            // Checked once here, so that no balance or sum of weights can overflow.
+            self.total_supply = self
+                .total_supply
+                .checked_add(value)
+                .ok_or(Error::Overflow)?;
+            self.set_balance(to, self.balance_of(to) + value);
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(to),
+                value,
+            });
+            Ok(())
+        }
+
+        /// Transfers `value` of the caller's tokens of a weighted poll to `to`. Once
+        /// voting started, this no longer changes the weight of either account.
+        #[ink(message)]
+        pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<(), Error> {
+            if self.weighting != Weighting::Registered {
+                return Err(Error::NotRegistered);
+            }
+            let from = self.env().caller();
+            let from_balance = self.balance_of(from);
+            if from_balance < value {
+                return Err(Error::InsufficientBalance);
+            }
+            self.set_balance(from, from_balance - value);
+            self.set_balance(to, self.balance_of(to) + value);
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: Some(to),
+                value,
+            });
+            Ok(())
+        }
+
+        /// Votes for `option_index` in a plurality poll, with the caller's own weight
+        /// and the weight delegated to it.
+        #[ink(message)]
+        pub fn vote(&mut self, option_index: u32) -> Result<(), Error> {
+            self.ensure_public_voting_open()?;
+            let weight = self.cast(Ballot::Choice(option_index))?;
+            self.env().emit_event(Vote {
+                voter: self.env().caller(),
+                option_index,
+                weight,
+            });
+            Ok(())
+        }
+
+        /// Moves the caller's vote in a plurality poll, with all the weight delegated
+        /// to it, to `option_index`.
+        #[ink(message)]
+        pub fn change_vote(&mut self, option_index: u32) -> Result<(), Error> {
+            self.ensure_public_voting_open()?;
+            let (previous, weight) = self.change(Ballot::Choice(option_index))?;
+            let Ballot::Choice(from_option) = previous else {
+                unreachable!("a plurality poll only holds choices");
+            };
+            self.env().emit_event(ChangeVote {
+                voter: self.env().caller(),
+                from_option,
+                to_option: option_index,
+                weight,
+            });
+            Ok(())
+        }
+
+        /// Casts a ballot of the kind the poll's [`Tally`] counts.
+        #[ink(message)]
+        pub fn cast_ballot(&mut self, ballot: Ballot) -> Result<(), Error> {
+            self.ensure_public_voting_open()?;
+            let weight = self.cast(ballot.clone())?;
+            self.env().emit_event(CastBallot {
+                voter: self.env().caller(),
+                ballot,
+                weight,
+            });
+            Ok(())
+        }
+
+        /// Replaces the caller's ballot.
+        #[ink(message)]
+        pub fn change_ballot(&mut self, ballot: Ballot) -> Result<(), Error> {
+            self.ensure_public_voting_open()?;
+            let (_, weight) = self.change(ballot.clone())?;
+            self.env().emit_event(ChangeBallot {
+                voter: self.env().caller(),
+                ballot,
+                weight,
+            });
+            Ok(())
+        }
+
+        /// Commits the caller to a ballot in a secret poll, replacing its previous
+        /// commitment. `commitment` is the Blake2x256 hash of the SCALE encoded
+        /// `(ballot, salt)`, with a random 32 byte salt kept until the reveal.
+        #[ink(message)]
+        pub fn commit_vote(&mut self, commitment: Hash) -> Result<(), Error> {
+            if self.reveal_end.is_none() {
+                return Err(Error::PublicPoll);
+            }
+            self.ensure_voting_open()?;
+            let sender = self.env().caller();
+            if self
+                .voters
+                .get(sender)
+                .unwrap_or_default()
+                .delegate
+                .is_some()
+            {
+                return Err(Error::AlreadyVoted);
+            }
+            self.commitments.insert(sender, &commitment);
+            self.env().emit_event(CommitVote { voter: sender });
+            Ok(())
+        }
+
+        /// Reveals the ballot the caller committed to, which is then counted as if it
+        /// was cast in a public poll.
+        #[ink(message)]
+        pub fn reveal_vote(&mut self, ballot: Ballot, salt: [u8; 32]) -> Result<(), Error> {
+            let Some(reveal_end) = self.reveal_end else {
+                return Err(Error::PublicPoll);
+            };
+            let block = self.env().block_number();
+            if block < self.voting_end {
+                return Err(Error::RevealNotStarted);
+            }
+            if block >= reveal_end {
+                return Err(Error::RevealClosed);
+            }
+            let sender = self.env().caller();
+            let commitment = self.commitments.get(sender).ok_or(Error::NotVoted)?;
+            let hash = self.env().hash_encoded::<Blake2x256, _>(&(&ballot, salt));
+            if Hash::from(hash) != commitment {
+                return Err(Error::CommitmentMismatch);
+            }
+            let weight = self.cast(ballot.clone())?;
+            self.commitments.remove(sender);
+            self.env().emit_event(CastBallot {
+                voter: sender,
+                ballot,
+                weight,
+            });
+            Ok(())
+        }
+
+        /// Hands the caller's weight, including the weight delegated to it, to `to`.
+        ///
+        /// The weight goes to the end of `to`'s delegation chain. It is added to that
+        /// account's ballot right away if it already voted, and follows its ballot when
+        /// it changes. In a quadratic poll the weight adds to the delegate's credits
+        /// instead, which it can spend by changing its ballot. In a secret poll the weight
+        /// counts with the delegate's ballot once it is revealed.
+        #[ink(message)]
+        pub fn delegate(&mut self, to: AccountId) -> Result<(), Error> {
+            self.ensure_voting_open()?;
             let sender = self.env().caller();
-            if self.voters.contains_key(&sender) {
-                return false;
+            let mut voter = self.voters.get(sender).unwrap_or_default();
+            if voter.ballot.is_some()
+                || voter.delegate.is_some()
+                || self.commitments.contains(sender)
+            {
+                return Err(Error::AlreadyVoted);
+            }
+            let mut delegate = to;
+            let mut delegate_voter = loop {
+                if delegate == sender {
+                    return Err(Error::DelegationCycle);
+                }
+                let next = self.voters.get(delegate).unwrap_or_default();
+                match next.delegate {
+                    Some(account) => delegate = account,
+                    None => break next,
+                }
+            };
+            let weight = self.voting_power(sender, &voter)?;
+            delegate_voter.delegated_weight = delegate_voter
+                .delegated_weight
+                .checked_add(weight)
+                .ok_or(Error::Overflow)?;
+            match &delegate_voter.ballot {
+                Some(Ballot::Votes(_)) | None => {}
+                Some(ballot) => self.add_counts(&Self::counts(ballot, weight))?,
             }
-            if let Some((name, count)) = self.options.get_mut(option_index as usize) {
-                *count += 1;
-                self.voters.insert(sender, true);
-                self.env().emit_event(Vote {
-                    voter: sender,
-                    option_index,
-                });
-                return true;
+            self.voters.insert(delegate, &delegate_voter);
+            voter.delegate = Some(delegate);
+            self.voters.insert(sender, &voter);
+            self.env().emit_event(Delegate {
+                delegator: sender,
+                delegate,
+                weight,
+            });
+            Ok(())
+        }
+
+        /// Closes the poll and picks the winner as the poll's [`Tally`] describes.
+        /// There is no winner if nobody voted. A secret poll is finalized once the
+        /// reveal ended, ignoring the commitments that were not revealed.
+        #[ink(message)]
+        pub fn finalize(&mut self) -> Result<(), Error> {
+            if self.finalized {
+                return Err(Error::AlreadyFinalized);
             }
-            false
+            if self.env().block_number() < self.reveal_end.unwrap_or(self.voting_end) {
+                return Err(Error::VotingNotEnded);
+            }
+            self.winner = match self.tally {
+                Tally::Plurality | Tally::Quadratic { .. } => self.most_counted(),
+                Tally::InstantRunoff => self.instant_runoff(),
+            };
+            self.finalized = true;
+            self.env().emit_event(GetResults {
+                results: self.options.clone(),
+                winner: self.winner,
+            });
+            Ok(())
         }
 
+        /// Returns whether `voter` voted or delegated its weight.
         #[ink(message)]
         pub fn has_voted(&self, voter: AccountId) -> bool {
-            self.voters.contains_key(&voter)
+            let voter = self.voters.get(voter).unwrap_or_default();
+            voter.ballot.is_some() || voter.delegate.is_some()
         }
 
+        /// Returns the ballot `account`'s weight counts for, following its delegation
+        /// chain, or `None` if neither it nor its delegate voted yet.
         #[ink(message)]
-        pub fn get_option_count(&self, option_index: u32) -> u32 {
+        pub fn get_vote(&self, account: AccountId) -> Option<Ballot> {
+            let mut voter = self.voters.get(account).unwrap_or_default();
+            while let Some(delegate) = voter.delegate {
+                voter = self.voters.get(delegate).unwrap_or_default();
+            }
+            voter.ballot
+        }
+
+        /// Returns the commitment `account` has yet to reveal in a secret poll.
+        #[ink(message)]
+        pub fn get_commitment(&self, account: AccountId) -> Option<Hash> {
+            self.commitments.get(account)
+        }
+
+        #[ink(message)]
+        pub fn get_voter(&self, account: AccountId) -> Voter {
+            self.voters.get(account).unwrap_or_default()
+        }
+
+        /// Returns how much a vote by `account` counts, not including the weight
+        /// delegated to it. In a weighted poll this is its current balance until voting
+        /// starts, and its balance at that point from then on.
+        #[ink(message)]
+        pub fn get_weight(&self, account: AccountId) -> Balance {
+            match self.weighting {
+                Weighting::OnePerAccount => 1,
+                Weighting::Registered => self
+                    .snapshots
+                    .get(account)
+                    .unwrap_or_else(|| self.balance_of(account)),
+            }
+        }
+
+        /// Returns the current token balance of `account` in a weighted poll.
+        #[ink(message)]
+        pub fn balance_of(&self, account: AccountId) -> Balance {
+            self.balances.get(account).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        pub fn total_supply(&self) -> Balance {
+            self.total_supply
+        }
+
+        #[ink(message)]
+        pub fn get_weighting(&self) -> Weighting {
+            self.weighting
+        }
+
+        #[ink(message)]
+        pub fn get_tally(&self) -> Tally {
+            self.tally
+        }
+
+        /// Returns the credits `account` can spend on a quadratic ballot, its weight and
+        /// the weight delegated to it times the credits per unit of weight. It is 0 in
+        /// the other tallies.
+        #[ink(message)]
+        pub fn get_credits(&self, account: AccountId) -> Balance {
+            let Tally::Quadratic { credits } = self.tally else {
+                return 0;
+            };
+            let voter = self.voters.get(account).unwrap_or_default();
+            self.get_weight(account)
+                .saturating_add(voter.delegated_weight)
+                .saturating_mul(credits)
+        }
+
+        #[ink(message)]
+        pub fn get_option_count(&self, option_index: u32) -> Balance {
             if let Some((_, count)) = self.options.get(option_index as usize) {
                 *count
             } else {
@@ -79,114 +634,277 @@ mod voting {
         }
 
         #[ink(message)]
-        pub fn get_options(&self) -> Vec<(String, u32)> {
+        pub fn get_options(&self) -> Vec<(String, Balance)> {
             self.options.clone()
         }
 
+        /// Returns the current counts, while voting is still going on as well. In an
+        /// instant runoff poll these are the first preferences.
         #[ink(message)]
-        pub fn get_results(&self) -> Vec<(String, u32)> {
+        pub fn get_results(&self) -> Vec<(String, Balance)> {
             self.options.clone()
         }
 
-    }
+        /// Returns the index of the winning option, `None` until the poll is finalized
+        /// or if nobody voted.
+        #[ink(message)]
+        pub fn get_winner(&self) -> Option<u32> {
+            self.winner
+        }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-
-        use ink_lang as ink;
-
-        #[ink::test]
-        fn test_propose_option() {
-            let mut voting = Voting::new();
-            let option_name = "Option 1".to_string();
-            voting.propose_option(option_name.clone());
-
-            let options = voting.get_options();
-            assert_eq!(options.len(), 1);
-            let (name, count) = options[0];
-            assert_eq!(name, option_name);
-            assert_eq!(count, 0);
-
-            let propose_option_event = ink_env::test::emitted_events()
-                .find(|event| matches!(event.event, "ProposeOption"))
-                .expect("no ProposeOption event was emitted");
-            assert_eq!(propose_option_event.event, "ProposeOption");
-            assert_eq!(propose_option_event.args[0].clone().into_string().unwrap(), option_name);
-        }
-
-        #[ink::test]
-        fn test_vote() {
-            let mut voting = Voting::new();
-            let option_name = "Option 1".to_string();
-            voting.propose_option(option_name.clone());
-
-            let voter = AccountId::from([0x1; 32]);
-            let vote_success = voting.vote(0, {ink_env::test::Call::new(voter, 0)});
-            assert!(vote_success);
-            assert!(voting.has_voted(voter));
-            assert_eq!(voting.get_option_count(0), 1);
-
-            let vote_event = ink_env::test::emitted_events()
-                .find(|event| matches!(event.event, "Vote"))
-                .expect("no Vote event was emitted");
-            assert_eq!(vote_event.event, "Vote");
-            assert_eq!(vote_event.args[0].clone().into_account_id(), Some(voter));
-            assert_eq!(vote_event.args[1].clone().into_u32(), 0);
-        }
-
-        #[ink::test]
-        fn test_vote_twice() {
-            let mut voting = Voting::new();
-            let option_name = "Option 1".to_string();
-            voting.propose_option(option_name.clone());
-
-            let voter = AccountId::from([0x1; 32]);
-            let vote_success = voting.vote(0, {ink_env::test::Call::new(voter, 0)});
-            assert!(vote_success);
-            assert!(voting.has_voted(voter));
-            assert_eq!(voting.get_option_count(0), 1);
-
-            let vote_success = voting.vote(0, {ink_env::test::Call::new(voter, 0)});
-            assert!(!vote_success);
-            assert!(voting.has_voted(voter));
-            assert_eq!(voting.get_option_count(0), 1);
-
-            let emitted_events = ink_env::test::emitted_events();
-            assert_eq!(emitted_events.len(), 1);
-        }
-
-        #[ink::test]
-        fn test_get_results() {
-            let mut voting = Voting::new();
-            let option_name_1 = "Option 1".to_string();
-            let option_name_2 = "Option 2".to_string();
-            voting.propose_option(option_name_1.clone());
-            voting.propose_option(option_name_2.clone());
-
-            let voter_1 = AccountId::from([0x1; 32]);
-            let voter_2 = AccountId::from([0x2; 32]);
-            let voter_3 = AccountId::from([0x3; 32]);
-            voting.vote(1, {ink_env::test::Call::new(voter_1, 0)});
-            voting.vote(1, {ink_env::test::Call::new(voter_2, 0)});
-            voting.vote(1, {ink_env::test::Call::new(voter_3, 0)});
-            voting.vote(1, {ink_env::test::Call::new(voter_3, 0)});
-            voting.vote(1, {ink_env::test::Call::new(voter_3, 0)});
-
-            let results = voting.get_results();
-            assert_eq!(results.len(), 2);
-            let (name_1, count_1) = results[0];
-            let (name_2, count_2) = results[1];
-            assert_eq!(name_1, option_name_1);
-            assert_eq!(name_2, option_name_2);
-            assert_eq!(count_1, 1);
-            assert_eq!(count_2, 4);
-
-            let get_results_event = ink_env::test::emitted_events()
-                .find(|event| matches!(event.event, "GetResults"))
-                .expect("no GetResults event was emitted");
-            assert_eq!(get_results_event.event, "GetResults");
-            assert_eq!(get_results_event.args[0].clone().into_vec().unwrap().len(), 2);
+        #[ink(message)]
+        pub fn is_finalized(&self) -> bool {
+            self.finalized
+        }
+
+        #[ink(message)]
+        pub fn get_chairperson(&self) -> AccountId {
+            self.chairperson
+        }
+
+        /// Returns `(voting_start, voting_end)`.
+        #[ink(message)]
+        pub fn get_voting_period(&self) -> (BlockNumber, BlockNumber) {
+            (self.voting_start, self.voting_end)
+        }
+
+        /// Returns the block the reveal of a secret poll ends at, `None` for a public
+        /// poll.
+        #[ink(message)]
+        pub fn get_reveal_end(&self) -> Option<BlockNumber> {
+            self.reveal_end
+        }
+
+        /// Records the caller's first ballot and returns the weight it counts with.
+        fn cast(&mut self, ballot: Ballot) -> Result<Balance, Error> {
+            let sender = self.env().caller();
+            let mut voter = self.voters.get(sender).unwrap_or_default();
+            if voter.ballot.is_some() || voter.delegate.is_some() {
+                return Err(Error::AlreadyVoted);
+            }
+            let weight = self.voting_power(sender, &voter)?;
+            self.check_ballot(&ballot, weight)?;
+            let ballot_holder_count = self
+                .ballot_holder_count
+                .checked_add(1)
+                .ok_or(Error::Overflow)?;
+            if self.tally == Tally::InstantRunoff && ballot_holder_count > MAX_RUNOFF_VOTERS {
+                return Err(Error::TooManyVoters);
+            }
+            self.add_counts(&Self::counts(&ballot, weight))?;
+            if self.tally == Tally::InstantRunoff {
+                self.ballot_holders
+                    .insert(self.ballot_holder_count, &sender);
+                self.ballot_holder_count = ballot_holder_count;
+            }
+            voter.ballot = Some(ballot);
+            self.voters.insert(sender, &voter);
+            Ok(weight)
+        }
+
+        /// Replaces the caller's ballot and returns the previous one with the weight
+        /// both count with.
+        fn change(&mut self, ballot: Ballot) -> Result<(Ballot, Balance), Error> {
+            let sender = self.env().caller();
+            let mut voter = self.voters.get(sender).unwrap_or_default();
+            let previous = voter.ballot.take().ok_or(Error::NotVoted)?;
+            let weight = self.voting_power(sender, &voter)?;
+            self.check_ballot(&ballot, weight)?;
+            let previous_counts = Self::counts(&previous, weight);
+            for (option_index, amount) in &previous_counts {
+                // Cannot underflow, the same amount was added when the ballot was cast
+                // or the weight delegated.
+                self.options[*option_index as usize].1 -= amount;
+            }
+            if let Err(err) = self.add_counts(&Self::counts(&ballot, weight)) {
+                for (option_index, amount) in &previous_counts {
+                    let count = &mut self.options[*option_index as usize].1;
+                    // Cannot saturate, the amount was subtracted above.
+                    *count = count.saturating_add(*amount);
+                }
+                return Err(err);
+            }
+            voter.ballot = Some(ballot);
+            self.voters.insert(sender, &voter);
+            Ok((previous, weight))
+        }
+
+        fn ensure_public_voting_open(&self) -> Result<(), Error> {
+            if self.reveal_end.is_some() {
+                return Err(Error::SecretPoll);
+            }
+            self.ensure_voting_open()
+        }
+
+        fn ensure_voting_open(&self) -> Result<(), Error> {
+            let block = self.env().block_number();
+            if block < self.voting_start {
+                return Err(Error::VotingNotStarted);
+            }
+            if block >= self.voting_end {
+                return Err(Error::VotingClosed);
+            }
+            Ok(())
+        }
+
+        /// Records the balance `account` held when voting started before changing it for
+        /// the first time since.
+        fn set_balance(&mut self, account: AccountId, balance: Balance) {
+            if self.env().block_number() >= self.voting_start && !self.snapshots.contains(account) {
+                self.snapshots.insert(account, &self.balance_of(account));
+            }
+            self.balances.insert(account, &balance);
+        }
+
+        /// Returns the weight `account` votes or delegates with, its own and the weight
+        /// delegated to it.
+        fn voting_power(&self, account: AccountId, voter: &Voter) -> Result<Balance, Error> {
+            let weight = self
+                .get_weight(account)
+                .checked_add(voter.delegated_weight)
+                .ok_or(Error::Overflow)?;
+            if weight == 0 {
+                return Err(Error::NoVotingPower);
+            }
+            Ok(weight)
+        }
+
+        /// Checks that `ballot` can be counted by the poll's tally for a voter with
+        /// `weight`.
+        fn check_ballot(&self, ballot: &Ballot, weight: Balance) -> Result<(), Error> {
+            let options = self.options.len();
+            match (self.tally, ballot) {
+                (Tally::Plurality, Ballot::Choice(option_index)) => {
+                    if *option_index as usize >= options {
+                        return Err(Error::OptionNotFound);
+                    }
+                }
+                (Tally::Quadratic { credits }, Ballot::Votes(votes)) => {
+                    if votes.len() > options {
+                        return Err(Error::OptionNotFound);
+                    }
+                    if votes.iter().all(|votes| *votes == 0) {
+                        return Err(Error::InvalidBallot);
+                    }
+                    // A cost past `Balance::MAX` exceeds any budget as well.
+                    let cost = votes
+                        .iter()
+                        .try_fold(0, |cost: Balance, votes| {
+                            cost.checked_add(Balance::from(*votes).pow(2))
+                        })
+                        .ok_or(Error::InsufficientCredits)?;
+                    if cost > weight.saturating_mul(credits) {
+                        return Err(Error::InsufficientCredits);
+                    }
+                }
+                (Tally::InstantRunoff, Ballot::Ranking(ranking)) => {
+                    if ranking.is_empty() {
+                        return Err(Error::InvalidBallot);
+                    }
+                    if ranking
+                        .iter()
+                        .any(|option_index| *option_index as usize >= options)
+                    {
+                        return Err(Error::OptionNotFound);
+                    }
+                    for (position, option_index) in ranking.iter().enumerate() {
+                        if ranking[..position].contains(option_index) {
+                            return Err(Error::InvalidBallot);
+                        }
+                    }
+                }
+                _ => return Err(Error::InvalidBallot),
+            }
+            Ok(())
+        }
+
+        /// Returns what a checked `ballot` adds to each option's count. Quadratic votes
+        /// do not scale with the weight, which only buys credits.
+        fn counts(ballot: &Ballot, weight: Balance) -> Vec<(u32, Balance)> {
+            match ballot {
+                Ballot::Choice(option_index) => vec![(*option_index, weight)],
+                Ballot::Votes(votes) => votes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, votes)| **votes > 0)
+                    .map(|(option_index, votes)| (option_index as u32, Balance::from(*votes)))
+                    .collect(),
+                Ballot::Ranking(ranking) => vec![(ranking[0], weight)],
+            }
+        }
+
+        /// Adds `counts` to the options, all of them or none if one would overflow.
+        fn add_counts(&mut self, counts: &[(u32, Balance)]) -> Result<(), Error> {
+            for (option_index, amount) in counts {
+                self.options[*option_index as usize]
+                    .1
+                    .checked_add(*amount)
+                    .ok_or(Error::Overflow)?;
+            }
+            for (option_index, amount) in counts {
+                let count = &mut self.options[*option_index as usize].1;
+                // Cannot saturate, checked above.
+                *count = count.saturating_add(*amount);
+            }
+            Ok(())
+        }
+
+        /// Returns the option with the highest count, the earliest proposed one on a
+        /// tie.
+        fn most_counted(&self) -> Option<u32> {
+            let mut winner: Option<(u32, Balance)> = None;
+            for (index, (_, count)) in self.options.iter().enumerate() {
+                if *count > winner.map_or(0, |(_, most)| most) {
+                    winner = Some((index as u32, *count));
+                }
+            }
+            winner.map(|(index, _)| index)
+        }
+
+        /// Runs the instant runoff rounds. Of the options tied for the fewest ballots,
+        /// the latest proposed one is eliminated, so that ties go to the earliest.
+        fn instant_runoff(&self) -> Option<u32> {
+            let ballots: Vec<(Vec<u32>, Balance)> = (0..self.ballot_holder_count)
+                .filter_map(|index| {
+                    let account = self.ballot_holders.get(index)?;
+                    let voter = self.voters.get(account)?;
+                    let Some(Ballot::Ranking(ranking)) = voter.ballot else {
+                        return None;
+                    };
+                    let weight = self
+                        .get_weight(account)
+                        .saturating_add(voter.delegated_weight);
+                    Some((ranking, weight))
+                })
+                .collect();
+            let mut eliminated = vec![false; self.options.len()];
+            loop {
+                // Saturating, as the weights of a poll stay far below `Balance::MAX`
+                // in total.
+                let mut tallies: Vec<Balance> = vec![0; self.options.len()];
+                let mut total: Balance = 0;
+                for (ranking, weight) in &ballots {
+                    if let Some(option_index) = ranking
+                        .iter()
+                        .find(|option_index| !eliminated[**option_index as usize])
+                    {
+                        let tally = &mut tallies[*option_index as usize];
+                        *tally = tally.saturating_add(*weight);
+                        total = total.saturating_add(*weight);
+                    }
+                }
+                if total == 0 {
+                    return None;
+                }
+                if let Some(index) = tallies.iter().position(|tally| *tally > total - *tally) {
+                    return Some(index as u32);
+                }
+                let last = (0..tallies.len())
+                    .rev()
+                    .filter(|index| !eliminated[*index])
+                    .min_by_key(|index| tallies[*index])?;
+                eliminated[last] = true;
+            }
         }
     }
 }
@@ -0,0 +1,466 @@
+use std::collections::HashSet;
+
+use ink::env::hash::Blake2x256;
+use ink::primitives::{AccountId, Hash};
+use leetcoin_test_kit::{event, set_block_number, set_caller};
+use proptest::prelude::*;
+use voting::{Ballot, Error, ProposeOption, Tally, Vote, Voting, Weighting};
+
+#[ink::test]
+fn test_vote_twice() {
+    let mut voting = Voting::new(1, 10, Weighting::OnePerAccount, Tally::Plurality, None).unwrap();
+    let option_name = "Option 1".to_string();
+    voting.propose_option(option_name.clone()).unwrap();
+    set_block_number(1);
+
+    let voter = AccountId::from([0x1; 32]);
+    set_caller(voter);
+    assert_eq!(voting.vote(0), Ok(()));
+    assert!(voting.has_voted(voter));
+    assert_eq!(voting.get_option_count(0), 1);
+
+    assert_eq!(voting.vote(0), Err(Error::AlreadyVoted));
+    assert!(voting.has_voted(voter));
+    assert_eq!(voting.get_option_count(0), 1);
+
+    // the proposal and the first vote
+    leetcoin_test_kit::assert_events([
+        event(ProposeOption { name: option_name }),
+        event(Vote {
+            voter,
+            option_index: 0,
+            weight: 1,
+        }),
+    ]);
+}
+
+#[ink::test]
+fn test_finalize_without_votes() {
+    let mut voting = Voting::new(1, 10, Weighting::OnePerAccount, Tally::Plurality, None).unwrap();
+    voting.propose_option("Option 1".to_string()).unwrap();
+
+    set_block_number(10);
+    assert_eq!(voting.finalize(), Ok(()));
+    assert_eq!(voting.get_winner(), None);
+    assert_eq!(voting.vote(0), Err(Error::VotingClosed));
+    assert_eq!(
+        voting.propose_option("Option 2".to_string()),
+        Err(Error::ProposalsClosed)
+    );
+}
+
+#[ink::test]
+fn test_mint_overflow() {
+    let mut voting = Voting::new(1, 10, Weighting::Registered, Tally::Plurality, None).unwrap();
+    voting.propose_option("Option 1".to_string()).unwrap();
+    let bob = AccountId::from([0x2; 32]);
+    let charlie = AccountId::from([0x3; 32]);
+    voting.mint(bob, u128::MAX - 1).unwrap();
+    assert_eq!(voting.mint(charlie, 2), Err(Error::Overflow));
+    assert_eq!(voting.balance_of(charlie), 0);
+    assert_eq!(voting.total_supply(), u128::MAX - 1);
+    voting.mint(charlie, 1).unwrap();
+    set_block_number(1);
+
+    // The whole supply fits in a single count.
+    set_caller(bob);
+    voting.vote(0).unwrap();
+    set_caller(charlie);
+    voting.vote(0).unwrap();
+    assert_eq!(voting.get_option_count(0), u128::MAX);
+}
+
+#[ink::test]
+fn test_change_vote_with_transferred_tokens() {
+    let mut voting = Voting::new(1, 10, Weighting::Registered, Tally::Plurality, None).unwrap();
+    voting.propose_option("Yes".to_string()).unwrap();
+    voting.propose_option("No".to_string()).unwrap();
+    let bob = AccountId::from([0x2; 32]);
+    let charlie = AccountId::from([0x3; 32]);
+    let django = AccountId::from([0x4; 32]);
+    voting.mint(bob, u128::MAX - 1).unwrap();
+    voting.mint(charlie, 1).unwrap();
+    set_block_number(1);
+
+    set_caller(bob);
+    voting.vote(0).unwrap();
+    voting.transfer(charlie, u128::MAX - 1).unwrap();
+    set_caller(charlie);
+    voting.vote(1).unwrap();
+    voting.transfer(django, u128::MAX).unwrap();
+    assert_eq!(voting.change_vote(0), Ok(()));
+    assert_eq!(voting.get_vote(charlie), Some(Ballot::Choice(0)));
+    assert_eq!(
+        voting.get_results(),
+        [("Yes".to_string(), u128::MAX), ("No".to_string(), 0)]
+    );
+
+    // django received every token after voting started, which gives no weight.
+    set_caller(django);
+    assert_eq!(voting.balance_of(django), u128::MAX);
+    assert_eq!(voting.delegate(bob), Err(Error::NoVotingPower));
+    assert!(!voting.has_voted(django));
+    assert_eq!(voting.get_voter(bob).delegated_weight, 0);
+}
+
+#[ink::test]
+fn test_delegate_outside_voting_period() {
+    let mut voting = Voting::new(1, 10, Weighting::OnePerAccount, Tally::Plurality, None).unwrap();
+    voting.propose_option("Yes".to_string()).unwrap();
+    let charlie = AccountId::from([0x3; 32]);
+    set_caller(AccountId::from([0x2; 32]));
+
+    assert_eq!(voting.delegate(charlie), Err(Error::VotingNotStarted));
+    set_block_number(10);
+    assert_eq!(voting.delegate(charlie), Err(Error::VotingClosed));
+    assert_eq!(voting.get_voter(charlie).delegated_weight, 0);
+}
+
+/// Deploys an instant runoff poll with `options` options and moves to the first block
+/// of the voting period.
+fn instant_runoff(options: u32) -> Voting {
+    let mut voting =
+        Voting::new(1, 10, Weighting::OnePerAccount, Tally::InstantRunoff, None).unwrap();
+    for index in 0..options {
+        voting.propose_option(format!("Option {}", index)).unwrap();
+    }
+    set_block_number(1);
+    voting
+}
+
+#[ink::test]
+fn test_instant_runoff_without_ballots() {
+    let mut voting = instant_runoff(2);
+    set_block_number(10);
+    voting.finalize().unwrap();
+    assert_eq!(voting.get_winner(), None);
+}
+
+#[ink::test]
+fn test_instant_runoff_delegation() {
+    let mut voting = instant_runoff(3);
+    let bob = AccountId::from([0x2; 32]);
+    for (voter, ranking) in [(0x2, vec![2, 1]), (0x3, vec![0]), (0x4, vec![1])] {
+        set_caller(AccountId::from([voter; 32]));
+        voting.cast_ballot(Ballot::Ranking(ranking)).unwrap();
+    }
+    // Two delegations make bob's ballot the heaviest, but it ranks C first.
+    for delegator in [0x5, 0x6] {
+        set_caller(AccountId::from([delegator; 32]));
+        voting.delegate(bob).unwrap();
+    }
+    assert_eq!(voting.get_option_count(2), 3);
+
+    // With the delegated weight, bob's ballot has the majority on its own.
+    set_block_number(10);
+    voting.finalize().unwrap();
+    assert_eq!(voting.get_winner(), Some(2));
+}
+
+#[ink::test]
+fn test_instant_runoff_change_ballot() {
+    let mut voting = instant_runoff(2);
+    let bob = AccountId::from([0x2; 32]);
+    set_caller(bob);
+    voting.cast_ballot(Ballot::Ranking(vec![0, 1])).unwrap();
+    set_caller(AccountId::from([0x3; 32]));
+    voting.delegate(bob).unwrap();
+
+    set_caller(bob);
+    voting.change_ballot(Ballot::Ranking(vec![1])).unwrap();
+    assert_eq!(
+        voting.get_results(),
+        [("Option 0".to_string(), 0), ("Option 1".to_string(), 2)]
+    );
+    assert_eq!(voting.change_vote(0), Err(Error::InvalidBallot));
+
+    set_block_number(10);
+    voting.finalize().unwrap();
+    assert_eq!(voting.get_winner(), Some(1));
+}
+
+#[ink::test]
+fn test_quadratic_cost_overflow() {
+    let mut voting = Voting::new(
+        1,
+        10,
+        Weighting::Registered,
+        Tally::Quadratic { credits: u128::MAX },
+        None,
+    )
+    .unwrap();
+    voting.propose_option("Yes".to_string()).unwrap();
+    let bob = AccountId::from([0x2; 32]);
+    voting.mint(bob, 2).unwrap();
+    set_block_number(1);
+
+    // The budget saturates at `Balance::MAX`, which covers any number of votes.
+    assert_eq!(voting.get_credits(bob), u128::MAX);
+    set_caller(bob);
+    assert_eq!(voting.cast_ballot(Ballot::Votes(vec![u32::MAX])), Ok(()));
+    assert_eq!(voting.get_option_count(0), u128::from(u32::MAX));
+}
+
+/// Deploys a secret poll with `tally` over "Yes" and "No", open from block 1 to 10
+/// with the reveal ending at block 20, and moves to block 1.
+fn secret(tally: Tally) -> Voting {
+    let mut voting = Voting::new(1, 10, Weighting::OnePerAccount, tally, Some(20)).unwrap();
+    voting.propose_option("Yes".to_string()).unwrap();
+    voting.propose_option("No".to_string()).unwrap();
+    set_block_number(1);
+    voting
+}
+
+fn commitment(ballot: &Ballot, salt: [u8; 32]) -> Hash {
+    let mut hash = [0; 32];
+    ink::env::hash_encoded::<Blake2x256, _>(&(ballot, salt), &mut hash);
+    Hash::from(hash)
+}
+
+#[ink::test]
+fn test_commit_again() {
+    let mut voting = secret(Tally::Plurality);
+    let bob = AccountId::from([0x2; 32]);
+    set_caller(bob);
+    voting
+        .commit_vote(commitment(&Ballot::Choice(0), [0x1; 32]))
+        .unwrap();
+    voting
+        .commit_vote(commitment(&Ballot::Choice(1), [0x2; 32]))
+        .unwrap();
+
+    set_block_number(10);
+    assert_eq!(
+        voting.commit_vote(commitment(&Ballot::Choice(0), [0x3; 32])),
+        Err(Error::VotingClosed)
+    );
+    assert_eq!(
+        voting.reveal_vote(Ballot::Choice(0), [0x1; 32]),
+        Err(Error::CommitmentMismatch)
+    );
+    assert_eq!(voting.reveal_vote(Ballot::Choice(1), [0x2; 32]), Ok(()));
+    assert_eq!(
+        voting.reveal_vote(Ballot::Choice(1), [0x2; 32]),
+        Err(Error::NotVoted)
+    );
+    assert_eq!(voting.get_option_count(1), 1);
+}
+
+#[ink::test]
+fn test_secret_delegation() {
+    let mut voting = secret(Tally::Plurality);
+    let bob = AccountId::from([0x2; 32]);
+    let charlie = AccountId::from([0x3; 32]);
+    set_caller(bob);
+    voting
+        .commit_vote(commitment(&Ballot::Choice(1), [0x2; 32]))
+        .unwrap();
+    assert_eq!(voting.delegate(charlie), Err(Error::AlreadyVoted));
+
+    // charlie's weight is counted with bob's ballot once it is revealed.
+    set_caller(charlie);
+    voting.delegate(bob).unwrap();
+    assert_eq!(
+        voting.commit_vote(commitment(&Ballot::Choice(0), [0x3; 32])),
+        Err(Error::AlreadyVoted)
+    );
+    assert_eq!(voting.get_option_count(1), 0);
+
+    set_block_number(10);
+    set_caller(bob);
+    voting.reveal_vote(Ballot::Choice(1), [0x2; 32]).unwrap();
+    assert_eq!(voting.get_option_count(1), 2);
+    assert_eq!(voting.get_vote(charlie), Some(Ballot::Choice(1)));
+}
+
+#[ink::test]
+fn test_secret_ranking() {
+    let mut voting = secret(Tally::InstantRunoff);
+    let ballots = [
+        (0x2, Ballot::Ranking(vec![1, 0])),
+        (0x3, Ballot::Ranking(vec![0])),
+        (0x4, Ballot::Ranking(vec![0, 0])),
+    ];
+    for (id, ballot) in &ballots {
+        set_caller(AccountId::from([*id; 32]));
+        voting.commit_vote(commitment(ballot, [*id; 32])).unwrap();
+    }
+
+    // An invalid ballot can be committed to, but is rejected when revealed.
+    set_block_number(10);
+    for (id, ballot) in &ballots {
+        set_caller(AccountId::from([*id; 32]));
+        let result = voting.reveal_vote(ballot.clone(), [*id; 32]);
+        if *id == 0x4 {
+            assert_eq!(result, Err(Error::InvalidBallot));
+        } else {
+            assert_eq!(result, Ok(()));
+        }
+    }
+
+    set_block_number(20);
+    voting.finalize().unwrap();
+    assert_eq!(voting.get_winner(), Some(0));
+}
+
+proptest! {
+    /// However the votes come in, every count matches the accounts that voted for it
+    /// and no count moves past the number of voters.
+    #[test]
+    fn test_counts_match_voters(ballots in prop::collection::vec((1..=6u8, 0..4u32), 0..32)) {
+        leetcoin_test_kit::off_chain(|| {
+            let mut voting = Voting::new(1, 10, Weighting::OnePerAccount, Tally::Plurality, None).unwrap();
+            for name in ["Yes", "No", "Abstain"] {
+                voting.propose_option(name.to_string()).unwrap();
+            }
+            set_block_number(1);
+
+            let mut voters = HashSet::new();
+            let mut expected = [0u128; 3];
+            for (voter, option_index) in ballots {
+                let voter = AccountId::from([voter; 32]);
+                set_caller(voter);
+                let result = voting.vote(option_index);
+                if voters.contains(&voter) {
+                    assert_eq!(result, Err(Error::AlreadyVoted));
+                } else if option_index >= 3 {
+                    assert_eq!(result, Err(Error::OptionNotFound));
+                } else {
+                    assert_eq!(result, Ok(()));
+                    voters.insert(voter);
+                    expected[option_index as usize] += 1;
+                }
+            }
+
+            let counts: Vec<u128> = voting.get_results().into_iter().map(|(_, count)| count).collect();
+            assert_eq!(counts, expected);
+            assert_eq!(counts.iter().sum::<u128>() as usize, voters.len());
+        });
+    }
+
+    /// However accounts vote, change their votes and delegate, each option's count is
+    /// the number of accounts whose vote, directly or through their delegates, is for it.
+    #[test]
+    fn test_counts_follow_delegation(actions in prop::collection::vec((1..=6u8, 0..3u8, 0..7u8), 0..48)) {
+        leetcoin_test_kit::off_chain(|| {
+            let mut voting = Voting::new(1, 10, Weighting::OnePerAccount, Tally::Plurality, None).unwrap();
+            for name in ["Yes", "No", "Abstain"] {
+                voting.propose_option(name.to_string()).unwrap();
+            }
+            set_block_number(1);
+
+            for (voter, action, target) in actions {
+                set_caller(AccountId::from([voter; 32]));
+                // Rejected actions must leave everything as it was, which the
+                // counts below check as well.
+                let _ = match action {
+                    0 => voting.vote(u32::from(target % 4)),
+                    1 => voting.change_vote(u32::from(target % 4)),
+                    _ => voting.delegate(AccountId::from([target % 6 + 1; 32])),
+                };
+            }
+
+            let mut expected = [0u128; 3];
+            for voter in 1..=6u8 {
+                if let Some(Ballot::Choice(option_index)) = voting.get_vote(AccountId::from([voter; 32])) {
+                    expected[option_index as usize] += 1;
+                }
+            }
+            let counts: Vec<u128> = voting.get_results().into_iter().map(|(_, count)| count).collect();
+            assert_eq!(counts, expected);
+        });
+    }
+
+    /// An option ranked first on more than half of the ballots wins the instant
+    /// runoff in the first round, whatever the other ballots rank.
+    #[test]
+    fn test_instant_runoff_majority_wins(
+        rankings in prop::collection::vec(prop::sample::subsequence(vec![0u32, 1, 2, 3], 1..=4).prop_shuffle(), 1..=6),
+    ) {
+        leetcoin_test_kit::off_chain(|| {
+            let mut voting = instant_runoff(4);
+            let mut first = [0usize; 4];
+            for (voter, ranking) in (1..).zip(rankings.iter()) {
+                set_caller(AccountId::from([voter; 32]));
+                voting.cast_ballot(Ballot::Ranking(ranking.clone())).unwrap();
+                first[ranking[0] as usize] += 1;
+            }
+
+            set_block_number(10);
+            voting.finalize().unwrap();
+            let winner = voting.get_winner();
+            assert!(winner.is_some());
+            if let Some(majority) = first.iter().position(|count| count * 2 > rankings.len()) {
+                assert_eq!(winner, Some(majority as u32));
+            }
+        });
+    }
+
+    /// A quadratic ballot is accepted exactly when its cost fits the credits, and the
+    /// counts add up the votes of the accepted ballots.
+    #[test]
+    fn test_quadratic_budget(ballots in prop::collection::vec(prop::collection::vec(0..5u32, 0..5), 0..6)) {
+        leetcoin_test_kit::off_chain(|| {
+            let mut voting = Voting::new(1, 10, Weighting::OnePerAccount, Tally::Quadratic { credits: 16 }, None).unwrap();
+            for name in ["Yes", "No", "Abstain"] {
+                voting.propose_option(name.to_string()).unwrap();
+            }
+            set_block_number(1);
+
+            let mut expected = [0u128; 3];
+            for (voter, votes) in (1..).zip(ballots) {
+                set_caller(AccountId::from([voter; 32]));
+                let cost: u32 = votes.iter().map(|votes| votes * votes).sum();
+                let result = voting.cast_ballot(Ballot::Votes(votes.clone()));
+                if votes.len() > 3 {
+                    assert_eq!(result, Err(Error::OptionNotFound));
+                } else if cost == 0 {
+                    assert_eq!(result, Err(Error::InvalidBallot));
+                } else if cost > 16 {
+                    assert_eq!(result, Err(Error::InsufficientCredits));
+                } else {
+                    assert_eq!(result, Ok(()));
+                    for (index, votes) in votes.iter().enumerate() {
+                        expected[index] += u128::from(*votes);
+                    }
+                }
+            }
+
+            let counts: Vec<u128> = voting.get_results().into_iter().map(|(_, count)| count).collect();
+            assert_eq!(counts, expected);
+        });
+    }
+
+    /// Only the ballots revealed with the ballot and salt they were committed with are
+    /// counted.
+    #[test]
+    fn test_only_matching_reveals_count(ballots in prop::collection::vec((0..2u32, any::<bool>(), any::<bool>()), 0..6)) {
+        leetcoin_test_kit::off_chain(|| {
+            let mut voting = secret(Tally::Plurality);
+            for (id, (option_index, _, _)) in (1..).zip(&ballots) {
+                set_caller(AccountId::from([id; 32]));
+                voting.commit_vote(commitment(&Ballot::Choice(*option_index), [id; 32])).unwrap();
+            }
+
+            set_block_number(10);
+            let mut expected = [0u128; 2];
+            for (id, (option_index, reveal, honest)) in (1..).zip(&ballots) {
+                if !reveal {
+                    continue;
+                }
+                set_caller(AccountId::from([id; 32]));
+                let revealed = if *honest { *option_index } else { 1 - option_index };
+                let result = voting.reveal_vote(Ballot::Choice(revealed), [id; 32]);
+                if *honest {
+                    assert_eq!(result, Ok(()));
+                    expected[revealed as usize] += 1;
+                } else {
+                    assert_eq!(result, Err(Error::CommitmentMismatch));
+                }
+            }
+
+            let counts: Vec<u128> = voting.get_results().into_iter().map(|(_, count)| count).collect();
+            assert_eq!(counts, expected);
+        });
+    }
+}
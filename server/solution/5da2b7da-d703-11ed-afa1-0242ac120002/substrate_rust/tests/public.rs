@@ -0,0 +1,926 @@
+use ink::env::hash::Blake2x256;
+use ink::primitives::{AccountId, Hash};
+use leetcoin_test_kit::{event, set_block_number, set_callee, set_caller};
+use voting::{
+    Ballot, CastBallot, ChangeVote, CommitVote, Delegate, Error, GetResults, ProposeOption, Tally,
+    Transfer, Vote, Voter, Voting, Weighting, MAX_OPTIONS, MAX_OPTION_NAME_LEN, MAX_RUNOFF_VOTERS,
+};
+
+/// The default caller, which deploys the contract as the chairperson.
+const CHAIRPERSON: AccountId = AccountId([0x1; 32]);
+const VOTING_START: u32 = 1;
+const VOTING_END: u32 = 10;
+
+/// Proposes `options` and moves to the first block of the voting period.
+fn setup(options: &[&str]) -> Voting {
+    setup_tally(Tally::Plurality, options)
+}
+
+fn setup_tally(tally: Tally, options: &[&str]) -> Voting {
+    let mut voting = Voting::new(
+        VOTING_START,
+        VOTING_END,
+        Weighting::OnePerAccount,
+        tally,
+        None,
+    )
+    .unwrap();
+    for name in options {
+        voting.propose_option(name.to_string()).unwrap();
+    }
+    set_block_number(VOTING_START);
+    voting
+}
+
+#[ink::test]
+fn test_new() {
+    let voting = Voting::new(
+        VOTING_START,
+        VOTING_END,
+        Weighting::OnePerAccount,
+        Tally::Plurality,
+        None,
+    )
+    .unwrap();
+    assert_eq!(voting.get_chairperson(), CHAIRPERSON);
+    assert_eq!(voting.get_voting_period(), (VOTING_START, VOTING_END));
+    assert_eq!(voting.get_weighting(), Weighting::OnePerAccount);
+    assert_eq!(voting.get_tally(), Tally::Plurality);
+    assert_eq!(voting.get_reveal_end(), None);
+    assert_eq!(voting.get_weight(AccountId::from([0x2; 32])), 1);
+    assert!(!voting.is_finalized());
+
+    assert_eq!(
+        Voting::new(5, 5, Weighting::OnePerAccount, Tally::Plurality, None).err(),
+        Some(Error::InvalidPeriod)
+    );
+    assert_eq!(
+        Voting::new(6, 5, Weighting::OnePerAccount, Tally::Plurality, None).err(),
+        Some(Error::InvalidPeriod)
+    );
+}
+
+#[ink::test]
+fn test_propose_option() {
+    let mut voting = Voting::new(
+        VOTING_START,
+        VOTING_END,
+        Weighting::OnePerAccount,
+        Tally::Plurality,
+        None,
+    )
+    .unwrap();
+    let option_name = "Option 1".to_string();
+    assert_eq!(voting.propose_option(option_name.clone()), Ok(()));
+
+    let options = voting.get_options();
+    assert_eq!(options.len(), 1);
+    let (name, count) = options[0].clone();
+    assert_eq!(name, option_name);
+    assert_eq!(count, 0);
+
+    leetcoin_test_kit::assert_events([event(ProposeOption { name: option_name })]);
+}
+
+#[ink::test]
+fn test_propose_option_restricted() {
+    let mut voting = Voting::new(
+        VOTING_START,
+        VOTING_END,
+        Weighting::OnePerAccount,
+        Tally::Plurality,
+        None,
+    )
+    .unwrap();
+
+    set_caller(AccountId::from([0x2; 32]));
+    assert_eq!(
+        voting.propose_option("Option 1".to_string()),
+        Err(Error::Unauthorized)
+    );
+
+    set_caller(CHAIRPERSON);
+    set_block_number(VOTING_START);
+    assert_eq!(
+        voting.propose_option("Option 1".to_string()),
+        Err(Error::ProposalsClosed)
+    );
+    assert!(voting.get_options().is_empty());
+    leetcoin_test_kit::assert_no_events();
+}
+
+#[ink::test]
+fn test_propose_option_limits() {
+    let mut voting = Voting::new(
+        VOTING_START,
+        VOTING_END,
+        Weighting::OnePerAccount,
+        Tally::Plurality,
+        None,
+    )
+    .unwrap();
+
+    let long_name = "x".repeat(MAX_OPTION_NAME_LEN as usize + 1);
+    assert_eq!(voting.propose_option(long_name), Err(Error::NameTooLong));
+    let name = "x".repeat(MAX_OPTION_NAME_LEN as usize);
+    for _ in 0..MAX_OPTIONS {
+        assert_eq!(voting.propose_option(name.clone()), Ok(()));
+    }
+    assert_eq!(
+        voting.propose_option("One more".to_string()),
+        Err(Error::TooManyOptions)
+    );
+    assert_eq!(voting.get_options().len(), MAX_OPTIONS as usize);
+}
+
+#[ink::test]
+fn test_vote() {
+    let option_name = "Option 1".to_string();
+    let mut voting = setup(&[&option_name]);
+
+    let voter = AccountId::from([0x1; 32]);
+    set_caller(voter);
+    assert_eq!(voting.vote(0), Ok(()));
+    assert!(voting.has_voted(voter));
+    assert_eq!(voting.get_option_count(0), 1);
+
+    leetcoin_test_kit::assert_events([
+        event(ProposeOption { name: option_name }),
+        event(Vote {
+            voter,
+            option_index: 0,
+            weight: 1,
+        }),
+    ]);
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_topic(&events[1], &voter);
+    leetcoin_test_kit::assert_topic(&events[1], &0u32);
+}
+
+#[ink::test]
+fn test_vote_outside_voting_period() {
+    let mut voting = Voting::new(
+        VOTING_START,
+        VOTING_END,
+        Weighting::OnePerAccount,
+        Tally::Plurality,
+        None,
+    )
+    .unwrap();
+    voting.propose_option("Option 1".to_string()).unwrap();
+    let voter = AccountId::from([0x2; 32]);
+    set_caller(voter);
+
+    assert_eq!(voting.vote(0), Err(Error::VotingNotStarted));
+    set_block_number(VOTING_END - 1);
+    assert_eq!(voting.vote(0), Ok(()));
+
+    set_caller(AccountId::from([0x3; 32]));
+    set_block_number(VOTING_END);
+    assert_eq!(voting.vote(0), Err(Error::VotingClosed));
+    assert_eq!(voting.get_option_count(0), 1);
+}
+
+#[ink::test]
+fn test_vote_for_unknown_option() {
+    let mut voting = setup(&[]);
+    let voter = AccountId::from([0x1; 32]);
+    set_caller(voter);
+    assert_eq!(voting.vote(0), Err(Error::OptionNotFound));
+    assert!(!voting.has_voted(voter));
+    leetcoin_test_kit::assert_no_events();
+}
+
+#[ink::test]
+fn test_get_results() {
+    let option_name_1 = "Option 1".to_string();
+    let option_name_2 = "Option 2".to_string();
+    let mut voting = setup(&[&option_name_1, &option_name_2]);
+
+    let voter_1 = AccountId::from([0x1; 32]);
+    let voter_2 = AccountId::from([0x2; 32]);
+    let voter_3 = AccountId::from([0x3; 32]);
+    set_caller(voter_1);
+    voting.vote(1).unwrap();
+    set_caller(voter_2);
+    voting.vote(1).unwrap();
+    set_caller(voter_3);
+    voting.vote(1).unwrap();
+    assert_eq!(voting.vote(1), Err(Error::AlreadyVoted));
+    assert_eq!(voting.vote(0), Err(Error::AlreadyVoted));
+
+    let results = voting.get_results();
+    assert_eq!(results.len(), 2);
+    let (name_1, count_1) = results[0].clone();
+    let (name_2, count_2) = results[1].clone();
+    assert_eq!(name_1, option_name_1);
+    assert_eq!(name_2, option_name_2);
+    assert_eq!(count_1, 0);
+    assert_eq!(count_2, 3);
+
+    // Querying the results is not recorded.
+    assert_eq!(leetcoin_test_kit::decode_events::<Vote>().len(), 3);
+    assert!(leetcoin_test_kit::decode_events::<GetResults>().is_empty());
+}
+
+#[ink::test]
+fn test_finalize() {
+    let mut voting = setup(&["Yes", "No"]);
+    set_caller(AccountId::from([0x2; 32]));
+    voting.vote(1).unwrap();
+
+    assert_eq!(voting.finalize(), Err(Error::VotingNotEnded));
+    assert_eq!(voting.get_winner(), None);
+
+    set_block_number(VOTING_END);
+    assert_eq!(voting.finalize(), Ok(()));
+    assert!(voting.is_finalized());
+    assert_eq!(voting.get_winner(), Some(1));
+    assert_eq!(voting.finalize(), Err(Error::AlreadyFinalized));
+
+    let results = leetcoin_test_kit::decode_events::<GetResults>();
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].results,
+        [("Yes".to_string(), 0), ("No".to_string(), 1)]
+    );
+    assert_eq!(results[0].winner, Some(1));
+}
+
+#[ink::test]
+fn test_finalize_tie() {
+    let mut voting = setup(&["Yes", "No", "Maybe"]);
+    for (voter, option_index) in [(0x2, 2), (0x3, 1), (0x4, 1), (0x5, 2)] {
+        set_caller(AccountId::from([voter; 32]));
+        voting.vote(option_index).unwrap();
+    }
+
+    set_block_number(VOTING_END);
+    voting.finalize().unwrap();
+    // The earliest proposed of the tied options wins.
+    assert_eq!(voting.get_winner(), Some(1));
+}
+
+/// Deploys a weighted poll from the chairperson, mints `balances` before voting starts
+/// and moves to the first block of the voting period.
+fn setup_weighted(options: &[&str], balances: &[(AccountId, u128)]) -> Voting {
+    let mut voting = Voting::new(
+        VOTING_START,
+        VOTING_END,
+        Weighting::Registered,
+        Tally::Plurality,
+        None,
+    )
+    .unwrap();
+    for name in options {
+        voting.propose_option(name.to_string()).unwrap();
+    }
+    for (holder, balance) in balances {
+        voting.mint(*holder, *balance).unwrap();
+    }
+    set_block_number(VOTING_START);
+    voting
+}
+
+#[ink::test]
+fn test_weighted_vote() {
+    let bob = AccountId::from([0x2; 32]);
+    let charlie = AccountId::from([0x3; 32]);
+    let mut voting = setup_weighted(&["Yes", "No"], &[(bob, 300), (charlie, 200)]);
+    assert_eq!(voting.get_weighting(), Weighting::Registered);
+    assert_eq!(voting.get_weight(bob), 300);
+    assert_eq!(voting.total_supply(), 500);
+
+    set_caller(bob);
+    assert_eq!(voting.vote(1), Ok(()));
+    set_caller(charlie);
+    assert_eq!(voting.vote(0), Ok(()));
+    assert_eq!(voting.get_option_count(0), 200);
+    assert_eq!(voting.get_option_count(1), 300);
+
+    let votes = leetcoin_test_kit::decode_events::<Vote>();
+    assert_eq!(votes[0].weight, 300);
+    assert_eq!(votes[1].weight, 200);
+
+    set_block_number(VOTING_END);
+    voting.finalize().unwrap();
+    assert_eq!(voting.get_winner(), Some(1));
+}
+
+#[ink::test]
+fn test_weighted_vote_without_weight() {
+    let bob = AccountId::from([0x2; 32]);
+    let mut voting = setup_weighted(&["Yes"], &[(bob, 300)]);
+
+    let charlie = AccountId::from([0x3; 32]);
+    set_caller(charlie);
+    assert_eq!(voting.get_weight(charlie), 0);
+    assert_eq!(voting.vote(0), Err(Error::NoVotingPower));
+    assert!(!voting.has_voted(charlie));
+}
+
+#[ink::test]
+fn test_transfer() {
+    let bob = AccountId::from([0x2; 32]);
+    let charlie = AccountId::from([0x3; 32]);
+    let mut voting = Voting::new(
+        VOTING_START,
+        VOTING_END,
+        Weighting::Registered,
+        Tally::Plurality,
+        None,
+    )
+    .unwrap();
+    voting.mint(bob, 300).unwrap();
+
+    set_caller(bob);
+    assert_eq!(
+        voting.transfer(charlie, 301),
+        Err(Error::InsufficientBalance)
+    );
+    assert_eq!(voting.transfer(charlie, 100), Ok(()));
+    assert_eq!(voting.balance_of(bob), 200);
+    assert_eq!(voting.balance_of(charlie), 100);
+    // Until voting starts, the weights follow the balances.
+    assert_eq!(voting.get_weight(bob), 200);
+    assert_eq!(voting.get_weight(charlie), 100);
+
+    leetcoin_test_kit::assert_events([
+        event(Transfer {
+            from: None,
+            to: Some(bob),
+            value: 300,
+        }),
+        event(Transfer {
+            from: Some(bob),
+            to: Some(charlie),
+            value: 100,
+        }),
+    ]);
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_topic(&events[1], &bob);
+    leetcoin_test_kit::assert_topic(&events[1], &charlie);
+}
+
+#[ink::test]
+fn test_weights_are_a_snapshot() {
+    let bob = AccountId::from([0x2; 32]);
+    let charlie = AccountId::from([0x3; 32]);
+    let mut voting = setup_weighted(&["Yes", "No"], &[(bob, 500), (charlie, 200)]);
+
+    set_caller(bob);
+    voting.vote(0).unwrap();
+
+    // bob's tokens already counted for bob, so they do not count again for charlie.
+    voting.transfer(charlie, 300).unwrap();
+    assert_eq!(voting.balance_of(bob), 200);
+    assert_eq!(voting.balance_of(charlie), 500);
+    set_caller(charlie);
+    voting.vote(1).unwrap();
+    assert_eq!(voting.get_weight(bob), 500);
+    assert_eq!(voting.get_weight(charlie), 200);
+    assert_eq!(
+        voting.get_results(),
+        [("Yes".to_string(), 500), ("No".to_string(), 200)]
+    );
+
+    // Neither does minting once voting started, nor moving the tokens back.
+    set_caller(CHAIRPERSON);
+    voting.mint(charlie, 1_000).unwrap();
+    set_caller(charlie);
+    voting.transfer(bob, 1_500).unwrap();
+    assert_eq!(voting.get_weight(bob), 500);
+    assert_eq!(voting.get_weight(charlie), 200);
+
+    set_caller(bob);
+    voting.change_vote(1).unwrap();
+    assert_eq!(
+        voting.get_results(),
+        [("Yes".to_string(), 0), ("No".to_string(), 700)]
+    );
+}
+
+#[ink::test]
+fn test_mint_restricted() {
+    let bob = AccountId::from([0x2; 32]);
+    let mut voting = Voting::new(
+        VOTING_START,
+        VOTING_END,
+        Weighting::Registered,
+        Tally::Plurality,
+        None,
+    )
+    .unwrap();
+
+    set_caller(bob);
+    assert_eq!(voting.mint(bob, 1_000), Err(Error::Unauthorized));
+    assert_eq!(voting.balance_of(bob), 0);
+    assert_eq!(voting.get_weight(bob), 0);
+
+    set_caller(CHAIRPERSON);
+    set_callee(AccountId::from([0xF0; 32]));
+    let mut unweighted = Voting::new(
+        VOTING_START,
+        VOTING_END,
+        Weighting::OnePerAccount,
+        Tally::Plurality,
+        None,
+    )
+    .unwrap();
+    assert_eq!(unweighted.mint(bob, 1_000), Err(Error::NotRegistered));
+    set_caller(bob);
+    assert_eq!(
+        unweighted.transfer(CHAIRPERSON, 0),
+        Err(Error::NotRegistered)
+    );
+    assert_eq!(unweighted.get_weight(bob), 1);
+    leetcoin_test_kit::assert_no_events();
+}
+
+#[ink::test]
+fn test_change_vote() {
+    let mut voting = setup(&["Yes", "No"]);
+    let voter = AccountId::from([0x2; 32]);
+    set_caller(voter);
+    assert_eq!(voting.change_vote(1), Err(Error::NotVoted));
+
+    voting.vote(0).unwrap();
+    assert_eq!(voting.change_vote(1), Ok(()));
+    assert_eq!(voting.get_vote(voter), Some(Ballot::Choice(1)));
+    assert_eq!(
+        voting.get_results(),
+        [("Yes".to_string(), 0), ("No".to_string(), 1)]
+    );
+    assert_eq!(voting.change_vote(2), Err(Error::OptionNotFound));
+    assert_eq!(voting.get_vote(voter), Some(Ballot::Choice(1)));
+
+    let changes = leetcoin_test_kit::decode_events::<ChangeVote>();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].voter, voter);
+    assert_eq!((changes[0].from_option, changes[0].to_option), (0, 1));
+    assert_eq!(changes[0].weight, 1);
+
+    set_block_number(VOTING_END);
+    assert_eq!(voting.change_vote(0), Err(Error::VotingClosed));
+}
+
+#[ink::test]
+fn test_delegate_chain() {
+    let mut voting = setup(&["Yes", "No"]);
+    let bob = AccountId::from([0x2; 32]);
+    let charlie = AccountId::from([0x3; 32]);
+    let django = AccountId::from([0x4; 32]);
+    let eve = AccountId::from([0x5; 32]);
+
+    // django -> charlie, then bob -> django ends at charlie as well.
+    set_caller(django);
+    assert_eq!(voting.delegate(charlie), Ok(()));
+    set_caller(bob);
+    assert_eq!(voting.delegate(django), Ok(()));
+    assert!(voting.has_voted(bob));
+    assert_eq!(voting.get_vote(bob), None);
+    assert_eq!(
+        voting.get_voter(charlie),
+        Voter {
+            delegated_weight: 2,
+            ballot: None,
+            delegate: None,
+        }
+    );
+    assert_eq!(voting.vote(0), Err(Error::AlreadyVoted));
+
+    // charlie votes with the delegated weight, and so does eve's later delegation.
+    set_caller(charlie);
+    voting.vote(1).unwrap();
+    assert_eq!(voting.get_option_count(1), 3);
+    set_caller(eve);
+    voting.delegate(bob).unwrap();
+    assert_eq!(voting.get_option_count(1), 4);
+    assert_eq!(voting.get_vote(eve), Some(Ballot::Choice(1)));
+
+    // The delegated weight follows charlie's vote.
+    set_caller(charlie);
+    voting.change_vote(0).unwrap();
+    assert_eq!(
+        voting.get_results(),
+        [("Yes".to_string(), 4), ("No".to_string(), 0)]
+    );
+    assert_eq!(voting.get_vote(bob), Some(Ballot::Choice(0)));
+
+    let delegations: Vec<_> = leetcoin_test_kit::decode_events::<Delegate>()
+        .into_iter()
+        .map(|event| (event.delegator, event.delegate, event.weight))
+        .collect();
+    assert_eq!(
+        delegations,
+        [(django, charlie, 1), (bob, charlie, 1), (eve, charlie, 1)]
+    );
+}
+
+#[ink::test]
+fn test_delegate_cycle() {
+    let mut voting = setup(&["Yes"]);
+    let bob = AccountId::from([0x2; 32]);
+    let charlie = AccountId::from([0x3; 32]);
+    let django = AccountId::from([0x4; 32]);
+
+    set_caller(bob);
+    assert_eq!(voting.delegate(bob), Err(Error::DelegationCycle));
+    voting.delegate(charlie).unwrap();
+    set_caller(charlie);
+    voting.delegate(django).unwrap();
+    set_caller(django);
+    assert_eq!(voting.delegate(bob), Err(Error::DelegationCycle));
+    assert!(!voting.has_voted(django));
+
+    // django can still vote, with the weight of the whole chain.
+    assert_eq!(voting.vote(0), Ok(()));
+    assert_eq!(voting.get_option_count(0), 3);
+}
+
+#[ink::test]
+fn test_weighted_delegation() {
+    let bob = AccountId::from([0x2; 32]);
+    let charlie = AccountId::from([0x3; 32]);
+    let mut voting = setup_weighted(&["Yes"], &[(bob, 300)]);
+
+    // charlie has no weight of their own, but can vote with the weight bob delegated.
+    set_caller(charlie);
+    assert_eq!(voting.delegate(bob), Err(Error::NoVotingPower));
+    set_caller(bob);
+    voting.delegate(charlie).unwrap();
+    set_caller(charlie);
+    voting.vote(0).unwrap();
+    assert_eq!(voting.get_option_count(0), 300);
+}
+
+/// Casts `ballots` from bob, charlie, django, ... in that order.
+fn cast_ballots(voting: &mut Voting, ballots: Vec<Ballot>) {
+    for (voter, ballot) in (0x2..).zip(ballots) {
+        set_caller(AccountId::from([voter; 32]));
+        voting.cast_ballot(ballot).unwrap();
+    }
+}
+
+#[ink::test]
+fn test_ballot_must_match_tally() {
+    let mut voting = setup(&["Yes", "No"]);
+    set_caller(AccountId::from([0x2; 32]));
+    assert_eq!(
+        voting.cast_ballot(Ballot::Ranking(vec![0])),
+        Err(Error::InvalidBallot)
+    );
+    assert_eq!(voting.cast_ballot(Ballot::Choice(1)), Ok(()));
+    assert_eq!(voting.get_option_count(1), 1);
+}
+
+#[ink::test]
+fn test_invalid_ranking() {
+    let mut voting = setup_tally(Tally::InstantRunoff, &["Yes", "No"]);
+    set_caller(AccountId::from([0x2; 32]));
+    assert_eq!(voting.vote(0), Err(Error::InvalidBallot));
+    assert_eq!(
+        voting.cast_ballot(Ballot::Ranking(vec![])),
+        Err(Error::InvalidBallot)
+    );
+    assert_eq!(
+        voting.cast_ballot(Ballot::Ranking(vec![1, 0, 1])),
+        Err(Error::InvalidBallot)
+    );
+    assert_eq!(
+        voting.cast_ballot(Ballot::Ranking(vec![1, 2])),
+        Err(Error::OptionNotFound)
+    );
+    assert!(!voting.has_voted(AccountId::from([0x2; 32])));
+}
+
+#[ink::test]
+fn test_runoff_voter_limit() {
+    let mut voting = setup_tally(Tally::InstantRunoff, &["Yes", "No"]);
+    let account = |index: u32| {
+        let mut bytes = [0xA; 32];
+        bytes[..4].copy_from_slice(&index.to_le_bytes());
+        AccountId::from(bytes)
+    };
+    for index in 0..MAX_RUNOFF_VOTERS {
+        set_caller(account(index));
+        assert_eq!(voting.cast_ballot(Ballot::Ranking(vec![1, 0])), Ok(()));
+    }
+
+    set_caller(account(MAX_RUNOFF_VOTERS));
+    assert_eq!(
+        voting.cast_ballot(Ballot::Ranking(vec![0])),
+        Err(Error::TooManyVoters)
+    );
+    assert!(!voting.has_voted(account(MAX_RUNOFF_VOTERS)));
+    // Further accounts can still delegate to one that holds a ballot.
+    assert_eq!(voting.delegate(account(0)), Ok(()));
+    assert_eq!(
+        voting.get_option_count(1),
+        u128::from(MAX_RUNOFF_VOTERS) + 1
+    );
+
+    set_block_number(VOTING_END);
+    assert_eq!(voting.finalize(), Ok(()));
+    assert_eq!(voting.get_winner(), Some(1));
+}
+
+#[ink::test]
+fn test_quadratic() {
+    let mut voting = setup_tally(Tally::Quadratic { credits: 9 }, &["Yes", "No"]);
+    let bob = AccountId::from([0x2; 32]);
+    assert_eq!(voting.get_credits(bob), 9);
+
+    set_caller(bob);
+    assert_eq!(voting.vote(0), Err(Error::InvalidBallot));
+    assert_eq!(
+        voting.cast_ballot(Ballot::Votes(vec![3, 1])),
+        Err(Error::InsufficientCredits)
+    );
+    assert_eq!(
+        voting.cast_ballot(Ballot::Votes(vec![0, 0])),
+        Err(Error::InvalidBallot)
+    );
+    assert_eq!(
+        voting.cast_ballot(Ballot::Votes(vec![0, 0, 1])),
+        Err(Error::OptionNotFound)
+    );
+    assert!(!voting.has_voted(bob));
+
+    // 2 votes for each option cost 8 credits, and count for more than 3 votes for
+    // one option.
+    cast_ballots(
+        &mut voting,
+        vec![
+            Ballot::Votes(vec![3]),
+            Ballot::Votes(vec![2, 2]),
+            Ballot::Votes(vec![0, 3]),
+        ],
+    );
+    assert_eq!(
+        voting.get_results(),
+        [("Yes".to_string(), 5), ("No".to_string(), 5)]
+    );
+
+    let ballots = leetcoin_test_kit::decode_events::<CastBallot>();
+    assert_eq!(ballots[0].ballot, Ballot::Votes(vec![3]));
+    assert_eq!(ballots[0].weight, 1);
+
+    // The tie goes to the earliest proposed option.
+    set_block_number(VOTING_END);
+    voting.finalize().unwrap();
+    assert_eq!(voting.get_winner(), Some(0));
+}
+
+#[ink::test]
+fn test_quadratic_change_ballot() {
+    let mut voting = setup_tally(Tally::Quadratic { credits: 9 }, &["Yes", "No"]);
+    let bob = AccountId::from([0x2; 32]);
+    set_caller(bob);
+    voting.cast_ballot(Ballot::Votes(vec![3])).unwrap();
+
+    // charlie's weight adds to bob's credits rather than to the counts.
+    set_caller(AccountId::from([0x3; 32]));
+    voting.delegate(bob).unwrap();
+    assert_eq!(voting.get_credits(bob), 18);
+    assert_eq!(voting.get_option_count(0), 3);
+
+    set_caller(bob);
+    assert_eq!(
+        voting.change_ballot(Ballot::Votes(vec![4, 2])),
+        Err(Error::InsufficientCredits)
+    );
+    assert_eq!(voting.change_ballot(Ballot::Votes(vec![1, 4])), Ok(()));
+    assert_eq!(
+        voting.get_results(),
+        [("Yes".to_string(), 1), ("No".to_string(), 4)]
+    );
+    assert_eq!(voting.get_vote(bob), Some(Ballot::Votes(vec![1, 4])));
+}
+
+#[ink::test]
+fn test_quadratic_tie() {
+    let ballots = [
+        Ballot::Votes(vec![0, 0, 3]),
+        Ballot::Votes(vec![0, 2, 1]),
+        Ballot::Votes(vec![1, 1]),
+    ];
+    // The same ballots cast in any order, each time to a fresh contract, give the
+    // same winner.
+    for (contract, order) in [[0, 1, 2], [2, 1, 0], [1, 2, 0]].into_iter().enumerate() {
+        set_callee(AccountId::from([0xF0 + contract as u8; 32]));
+        set_block_number(0);
+        let mut voting = setup_tally(Tally::Quadratic { credits: 9 }, &["A", "B", "C"]);
+        cast_ballots(
+            &mut voting,
+            order.iter().map(|index| ballots[*index].clone()).collect(),
+        );
+        assert_eq!(
+            voting.get_results(),
+            [
+                ("A".to_string(), 1),
+                ("B".to_string(), 3),
+                ("C".to_string(), 4)
+            ]
+        );
+
+        set_caller(AccountId::from([0x5; 32]));
+        voting.cast_ballot(Ballot::Votes(vec![0, 1])).unwrap();
+
+        // B and C tie at 4, and B was proposed first.
+        set_block_number(VOTING_END);
+        voting.finalize().unwrap();
+        assert_eq!(voting.get_winner(), Some(1));
+    }
+}
+
+#[ink::test]
+fn test_instant_runoff() {
+    let mut voting = setup_tally(Tally::InstantRunoff, &["A", "B", "C"]);
+    cast_ballots(
+        &mut voting,
+        vec![
+            Ballot::Ranking(vec![0]),
+            Ballot::Ranking(vec![0, 2]),
+            Ballot::Ranking(vec![1]),
+            Ballot::Ranking(vec![1, 0]),
+            Ballot::Ranking(vec![2, 1]),
+        ],
+    );
+    // A plurality count would tie A with B, and A would win the tie.
+    assert_eq!(
+        voting.get_results(),
+        [
+            ("A".to_string(), 2),
+            ("B".to_string(), 2),
+            ("C".to_string(), 1)
+        ]
+    );
+
+    // C is eliminated and its ballot goes to B, which then holds 3 of the 5 ballots.
+    set_block_number(VOTING_END);
+    voting.finalize().unwrap();
+    assert_eq!(voting.get_winner(), Some(1));
+}
+
+#[ink::test]
+fn test_instant_runoff_tie() {
+    let mut voting = setup_tally(Tally::InstantRunoff, &["A", "B", "C"]);
+    cast_ballots(
+        &mut voting,
+        vec![
+            Ballot::Ranking(vec![1, 2]),
+            Ballot::Ranking(vec![2, 0]),
+            Ballot::Ranking(vec![0, 1]),
+            Ballot::Ranking(vec![1]),
+        ],
+    );
+
+    // A and C tie for the fewest ballots, so C is eliminated and its ballot goes to
+    // A. A and B then tie 2 to 2, so B is eliminated, and A wins as B's ballots rank
+    // no other option left.
+    set_block_number(VOTING_END);
+    voting.finalize().unwrap();
+    assert_eq!(voting.get_winner(), Some(0));
+}
+
+const REVEAL_END: u32 = 20;
+
+/// Deploys a secret plurality poll, proposes `options` and moves to the first block of
+/// the voting period.
+fn setup_secret(options: &[&str]) -> Voting {
+    let mut voting = Voting::new(
+        VOTING_START,
+        VOTING_END,
+        Weighting::OnePerAccount,
+        Tally::Plurality,
+        Some(REVEAL_END),
+    )
+    .unwrap();
+    for name in options {
+        voting.propose_option(name.to_string()).unwrap();
+    }
+    set_block_number(VOTING_START);
+    voting
+}
+
+/// Returns the commitment to `ballot` a voter sends to a secret poll.
+fn commitment(ballot: &Ballot, salt: [u8; 32]) -> Hash {
+    let mut hash = [0; 32];
+    ink::env::hash_encoded::<Blake2x256, _>(&(ballot, salt), &mut hash);
+    Hash::from(hash)
+}
+
+#[ink::test]
+fn test_commit_reveal() {
+    let mut voting = setup_secret(&["Yes", "No"]);
+    assert_eq!(voting.get_reveal_end(), Some(REVEAL_END));
+    let bob = AccountId::from([0x2; 32]);
+    let charlie = AccountId::from([0x3; 32]);
+    let django = AccountId::from([0x4; 32]);
+
+    // Each voter salts its ballot with its own account byte, for brevity.
+    for (id, option_index) in [(0x2, 1), (0x3, 0), (0x4, 0)] {
+        let voter = AccountId::from([id; 32]);
+        set_caller(voter);
+        let commitment = commitment(&Ballot::Choice(option_index), [id; 32]);
+        assert_eq!(voting.commit_vote(commitment), Ok(()));
+        assert_eq!(voting.get_commitment(voter), Some(commitment));
+    }
+    // Nothing about the ballots is known while voting is open.
+    assert_eq!(
+        voting.get_results(),
+        [("Yes".to_string(), 0), ("No".to_string(), 0)]
+    );
+    assert_eq!(leetcoin_test_kit::decode_events::<CommitVote>().len(), 3);
+    set_caller(bob);
+    assert_eq!(
+        voting.reveal_vote(Ballot::Choice(1), [0x2; 32]),
+        Err(Error::RevealNotStarted)
+    );
+
+    // django never reveals, so only bob's and charlie's ballots count.
+    set_block_number(VOTING_END);
+    assert_eq!(voting.reveal_vote(Ballot::Choice(1), [0x2; 32]), Ok(()));
+    assert_eq!(voting.get_commitment(bob), None);
+    assert_eq!(voting.get_vote(bob), Some(Ballot::Choice(1)));
+    set_caller(charlie);
+    assert_eq!(voting.reveal_vote(Ballot::Choice(0), [0x3; 32]), Ok(()));
+    assert_eq!(
+        voting.get_results(),
+        [("Yes".to_string(), 1), ("No".to_string(), 1)]
+    );
+    assert_eq!(voting.finalize(), Err(Error::VotingNotEnded));
+
+    set_block_number(REVEAL_END);
+    set_caller(django);
+    assert_eq!(
+        voting.reveal_vote(Ballot::Choice(0), [0x4; 32]),
+        Err(Error::RevealClosed)
+    );
+    assert_eq!(voting.finalize(), Ok(()));
+    assert_eq!(voting.get_winner(), Some(0));
+
+    let ballots = leetcoin_test_kit::decode_events::<CastBallot>();
+    assert_eq!(ballots.len(), 2);
+    assert_eq!(ballots[0].voter, bob);
+    assert_eq!(ballots[0].ballot, Ballot::Choice(1));
+}
+
+#[ink::test]
+fn test_reveal_mismatch() {
+    let mut voting = setup_secret(&["Yes", "No"]);
+    let bob = AccountId::from([0x2; 32]);
+    set_caller(bob);
+    voting
+        .commit_vote(commitment(&Ballot::Choice(1), [0x7; 32]))
+        .unwrap();
+
+    set_block_number(VOTING_END);
+    assert_eq!(
+        voting.reveal_vote(Ballot::Choice(0), [0x7; 32]),
+        Err(Error::CommitmentMismatch)
+    );
+    assert_eq!(
+        voting.reveal_vote(Ballot::Choice(1), [0x8; 32]),
+        Err(Error::CommitmentMismatch)
+    );
+    assert!(voting.get_commitment(bob).is_some());
+    assert!(!voting.has_voted(bob));
+
+    set_caller(AccountId::from([0x3; 32]));
+    assert_eq!(
+        voting.reveal_vote(Ballot::Choice(1), [0x7; 32]),
+        Err(Error::NotVoted)
+    );
+}
+
+#[ink::test]
+fn test_secret_poll_rejects_public_ballots() {
+    let mut voting = setup_secret(&["Yes"]);
+    set_caller(AccountId::from([0x2; 32]));
+    assert_eq!(voting.vote(0), Err(Error::SecretPoll));
+    assert_eq!(
+        voting.cast_ballot(Ballot::Choice(0)),
+        Err(Error::SecretPoll)
+    );
+
+    assert_eq!(
+        Voting::new(1, 10, Weighting::OnePerAccount, Tally::Plurality, Some(10)).err(),
+        Some(Error::InvalidPeriod)
+    );
+}
+
+#[ink::test]
+fn test_public_poll_rejects_commitments() {
+    let mut voting = setup(&["Yes"]);
+    set_caller(AccountId::from([0x2; 32]));
+    assert_eq!(
+        voting.commit_vote(commitment(&Ballot::Choice(0), [0; 32])),
+        Err(Error::PublicPoll)
+    );
+    assert_eq!(
+        voting.reveal_vote(Ballot::Choice(0), [0; 32]),
+        Err(Error::PublicPoll)
+    );
+}
@@ -0,0 +1,221 @@
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{Args, Contract, Event, ReplayError, ToValue, Value};
+use voting::{
+    Ballot, CastBallot, ChangeBallot, ChangeVote, CommitVote, Delegate, GetResults, ProposeOption,
+    Tally, Transfer, Vote, Voting, Weighting,
+};
+
+/// Binds `testcase.txt` to [`Voting`].
+struct Replay(Voting);
+
+impl Contract for Replay {
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => Voting::new(
+                args.u32(0)?,
+                args.u32(1)?,
+                weighting(args, 2)?,
+                tally(args, 3)?,
+                args.optional_u32(4)?,
+            )
+            .map(Replay)
+            .map_err(|err| ReplayError::new(format!("`new` failed with {:?}", err))),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "propose_option" => Ok(self.0.propose_option(args.string(0)?).to_value()),
+            "mint" => Ok(self.0.mint(args.account(0)?, args.balance(1)?).to_value()),
+            "transfer" => Ok(self
+                .0
+                .transfer(args.account(0)?, args.balance(1)?)
+                .to_value()),
+            "balance_of" => Ok(self.0.balance_of(args.account(0)?).to_value()),
+            "total_supply" => Ok(self.0.total_supply().to_value()),
+            "vote" => Ok(self.0.vote(args.u32(0)?).to_value()),
+            "change_vote" => Ok(self.0.change_vote(args.u32(0)?).to_value()),
+            "cast_ballot" => Ok(self.0.cast_ballot(ballot(args, 0)?).to_value()),
+            "change_ballot" => Ok(self.0.change_ballot(ballot(args, 0)?).to_value()),
+            "commit_vote" => Ok(self.0.commit_vote(args.hash(0)?).to_value()),
+            "reveal_vote" => Ok(self
+                .0
+                .reveal_vote(ballot(args, 0)?, args.hash(1)?.into())
+                .to_value()),
+            "delegate" => Ok(self.0.delegate(args.account(0)?).to_value()),
+            "has_voted" => Ok(self.0.has_voted(args.account(0)?).to_value()),
+            "get_vote" => Ok(self
+                .0
+                .get_vote(args.account(0)?)
+                .map(|ballot| ballot_value(&ballot))
+                .to_value()),
+            "get_commitment" => Ok(self.0.get_commitment(args.account(0)?).to_value()),
+            "get_weight" => Ok(self.0.get_weight(args.account(0)?).to_value()),
+            "get_weighting" => Ok(format!("{:?}", self.0.get_weighting()).to_value()),
+            "get_tally" => Ok(tally_value(self.0.get_tally())),
+            "get_credits" => Ok(self.0.get_credits(args.account(0)?).to_value()),
+            "get_option_count" => Ok(self.0.get_option_count(args.u32(0)?).to_value()),
+            "get_options" => Ok(self.0.get_options().to_value()),
+            "get_results" => Ok(self.0.get_results().to_value()),
+            "finalize" => Ok(self.0.finalize().to_value()),
+            "get_winner" => Ok(self.0.get_winner().to_value()),
+            "is_finalized" => Ok(self.0.is_finalized().to_value()),
+            "get_chairperson" => Ok(self.0.get_chairperson().to_value()),
+            "get_voting_period" => Ok(self.0.get_voting_period().to_value()),
+            "get_reveal_end" => Ok(self.0.get_reveal_end().to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        if let Some(event) = decode_event::<ProposeOption>(event) {
+            return Some(Event::new("ProposeOption").field("name", event.name));
+        }
+        if let Some(event) = decode_event::<Vote>(event) {
+            return Some(
+                Event::new("Vote")
+                    .field("voter", event.voter)
+                    .field("option_index", event.option_index)
+                    .field("weight", event.weight),
+            );
+        }
+        if let Some(event) = decode_event::<ChangeVote>(event) {
+            return Some(
+                Event::new("ChangeVote")
+                    .field("voter", event.voter)
+                    .field("from_option", event.from_option)
+                    .field("to_option", event.to_option)
+                    .field("weight", event.weight),
+            );
+        }
+        if let Some(event) = decode_event::<CastBallot>(event) {
+            return Some(
+                Event::new("CastBallot")
+                    .field("voter", event.voter)
+                    .field("ballot", ballot_value(&event.ballot))
+                    .field("weight", event.weight),
+            );
+        }
+        if let Some(event) = decode_event::<ChangeBallot>(event) {
+            return Some(
+                Event::new("ChangeBallot")
+                    .field("voter", event.voter)
+                    .field("ballot", ballot_value(&event.ballot))
+                    .field("weight", event.weight),
+            );
+        }
+        if let Some(event) = decode_event::<CommitVote>(event) {
+            return Some(Event::new("CommitVote").field("voter", event.voter));
+        }
+        if let Some(event) = decode_event::<Delegate>(event) {
+            return Some(
+                Event::new("Delegate")
+                    .field("delegator", event.delegator)
+                    .field("delegate", event.delegate)
+                    .field("weight", event.weight),
+            );
+        }
+        if let Some(event) = decode_event::<Transfer>(event) {
+            return Some(
+                Event::new("Transfer")
+                    .field("from", event.from)
+                    .field("to", event.to)
+                    .field("value", event.value),
+            );
+        }
+        if let Some(event) = decode_event::<GetResults>(event) {
+            return Some(
+                Event::new("GetResults")
+                    .field("results", event.results)
+                    .field("winner", event.winner),
+            );
+        }
+        None
+    }
+}
+
+/// Parses a [`Weighting`] written as its variant name.
+fn weighting(args: &Args, index: usize) -> Result<Weighting, ReplayError> {
+    match args.string(index)?.as_str() {
+        "OnePerAccount" => Ok(Weighting::OnePerAccount),
+        "Registered" => Ok(Weighting::Registered),
+        name => Err(ReplayError::unknown("weighting", name)),
+    }
+}
+
+/// Parses a [`Tally`] written as `"Plurality"`, `"InstantRunoff"` or
+/// `{"Quadratic": {"credits": 100}}`.
+fn tally(args: &Args, index: usize) -> Result<Tally, ReplayError> {
+    let value = args.value(index)?;
+    if let Some(credits) = value.pointer("/Quadratic/credits") {
+        let credits = credits
+            .as_u64()
+            .ok_or_else(|| ReplayError::new(format!("invalid credits in {}", value)))?;
+        return Ok(Tally::Quadratic {
+            credits: credits.into(),
+        });
+    }
+    match value.as_str() {
+        Some("Plurality") => Ok(Tally::Plurality),
+        Some("InstantRunoff") => Ok(Tally::InstantRunoff),
+        _ => Err(ReplayError::unknown("tally", &value.to_string())),
+    }
+}
+
+fn tally_value(tally: Tally) -> Value {
+    match tally {
+        Tally::Quadratic { credits } => Value::from_iter([(
+            "Quadratic",
+            Value::from_iter([("credits", credits.to_value())]),
+        )]),
+        tally => format!("{:?}", tally).to_value(),
+    }
+}
+
+/// Parses a [`Ballot`] written as `{"Choice": 0}`, `{"Votes": [3, 1]}` or
+/// `{"Ranking": [2, 0]}`.
+fn ballot(args: &Args, index: usize) -> Result<Ballot, ReplayError> {
+    let value = args.value(index)?;
+    let invalid = || ReplayError::new(format!("argument {} is not a ballot: {}", index, value));
+    let indices = |value: &Value| -> Result<Vec<u32>, ReplayError> {
+        value
+            .as_array()
+            .ok_or_else(invalid)?
+            .iter()
+            .map(|index| {
+                index
+                    .as_u64()
+                    .and_then(|index| u32::try_from(index).ok())
+                    .ok_or_else(invalid)
+            })
+            .collect()
+    };
+    if let Some(choice) = value.get("Choice") {
+        let choice = choice
+            .as_u64()
+            .and_then(|choice| u32::try_from(choice).ok())
+            .ok_or_else(invalid)?;
+        Ok(Ballot::Choice(choice))
+    } else if let Some(votes) = value.get("Votes") {
+        Ok(Ballot::Votes(indices(votes)?))
+    } else if let Some(ranking) = value.get("Ranking") {
+        Ok(Ballot::Ranking(indices(ranking)?))
+    } else {
+        Err(invalid())
+    }
+}
+
+fn ballot_value(ballot: &Ballot) -> Value {
+    let (kind, value) = match ballot {
+        Ballot::Choice(choice) => ("Choice", choice.to_value()),
+        Ballot::Votes(votes) => ("Votes", votes.to_value()),
+        Ballot::Ranking(ranking) => ("Ranking", ranking.to_value()),
+    };
+    Value::from_iter([(kind, value)])
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
@@ -1,24 +1,38 @@
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-use ink_lang as ink;
+pub use self::escrow::{
+    Error, Escrow, EscrowStatus, ExtendDeadline, LockFunds, ProposeExtension, RaiseDispute,
+    RefundFunds, ReleaseFunds, ResolveDispute,
+};
 
 #[ink::contract]
 mod escrow {
-    use ink_prelude::collections::HashMap;
-
     #[ink(storage)]
     pub struct Escrow {
         seller: AccountId,
         buyer: AccountId,
+        arbiter: AccountId,
         amount: Balance,
         status: EscrowStatus,
-        authorized_callers: HashMap<AccountId, bool>,
+        /// After this timestamp the buyer can take back locked funds.
+        deadline: Timestamp,
+        /// A later deadline proposed by one party, waiting for the other to agree.
+        extension: Option<(Timestamp, AccountId)>,
     }
 
-    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    /// The escrow moves from `AwaitingDeposit` to `Locked` once the buyer paid, and
+    /// ends in `Released` or `Refunded`. A locked escrow can be `Disputed` by either
+    /// party, after which the arbiter decides.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
     pub enum EscrowStatus {
+        #[default]
+        AwaitingDeposit,
         Locked,
+        Disputed,
         Released,
         Refunded,
     }
@@ -26,179 +40,283 @@ mod escrow {
     #[ink(event)]
     pub struct LockFunds {
         #[ink(topic)]
-        seller: AccountId,
+        pub seller: AccountId,
         #[ink(topic)]
-        buyer: AccountId,
+        pub buyer: AccountId,
         #[ink(topic)]
-        amount: Balance,
+        pub amount: Balance,
     }
 
     #[ink(event)]
     pub struct ReleaseFunds {
         #[ink(topic)]
-        seller: AccountId,
+        pub seller: AccountId,
         #[ink(topic)]
-        buyer: AccountId,
+        pub buyer: AccountId,
         #[ink(topic)]
-        amount: Balance,
+        pub amount: Balance,
     }
 
     #[ink(event)]
     pub struct RefundFunds {
         #[ink(topic)]
-        seller: AccountId,
+        pub seller: AccountId,
         #[ink(topic)]
-        buyer: AccountId,
+        pub buyer: AccountId,
         #[ink(topic)]
-        amount: Balance,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct RaiseDispute {
+        #[ink(topic)]
+        pub raised_by: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct ResolveDispute {
+        #[ink(topic)]
+        pub arbiter: AccountId,
+        /// Whether the funds went to the seller rather than back to the buyer.
+        pub released: bool,
+    }
+
+    #[ink(event)]
+    pub struct ProposeExtension {
+        #[ink(topic)]
+        pub proposed_by: AccountId,
+        pub deadline: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct ExtendDeadline {
+        pub deadline: Timestamp,
     }
 
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub enum Error {
         Unauthorized,
+        TransferFailed,
+        /// The escrow is not in a status that allows this call.
+        InvalidStatus,
+        /// The deposit does not match the agreed amount.
+        IncorrectAmount,
+        DeadlineNotReached,
+        /// A new deadline must be later than the current one.
+        InvalidDeadline,
+        /// The seller, the buyer and the arbiter are not three different accounts.
+        InvalidParties,
+        /// The agreed amount is zero.
+        ZeroAmount,
     }
 
     impl Escrow {
         #[ink(constructor)]
-        pub fn new(seller: AccountId, buyer: AccountId, amount: Balance) -> Self {
-            Self {
+        pub fn new(
+            seller: AccountId,
+            buyer: AccountId,
+            arbiter: AccountId,
+            amount: Balance,
+            deadline: Timestamp,
+        ) -> Result<Self, Error> {
+            // An arbiter who is also a party could settle a dispute in their own favor.
+            if seller == buyer || arbiter == seller || arbiter == buyer {
+                return Err(Error::InvalidParties);
+            }
+            if amount == 0 {
+                return Err(Error::ZeroAmount);
+            }
+            Ok(Self {
                 seller,
                 buyer,
+                arbiter,
                 amount,
-                status: EscrowStatus::Locked,
-                authorized_callers: HashMap::new(),
-            }
+                status: EscrowStatus::AwaitingDeposit,
+                deadline,
+                extension: None,
+            })
         }
 
-        #[ink(message)]
-        pub fn lock_funds(&mut self) {
+        /// Deposits the agreed amount, which must be sent along with the call by the buyer.
+        #[ink(message, payable)]
+        pub fn lock_funds(&mut self) -> Result<(), Error> {
+            if self.env().caller() != self.buyer {
+                return Err(Error::Unauthorized);
+            }
+            if self.status != EscrowStatus::AwaitingDeposit {
+                return Err(Error::InvalidStatus);
+            }
+            if self.env().transferred_value() != self.amount {
+                return Err(Error::IncorrectAmount);
+            }
+            self.status = EscrowStatus::Locked;
             self.env().emit_event(LockFunds {
                 seller: self.seller,
                 buyer: self.buyer,
                 amount: self.amount,
             });
+            Ok(())
         }
 
+        /// Pays the seller, called by the buyer once the deal went through.
         #[ink(message)]
         pub fn release_funds(&mut self) -> Result<(), Error> {
+            if self.env().caller() != self.buyer {
+                return Err(Error::Unauthorized);
+            }
+            self.release()
+        }
+
+        /// Pays the buyer back, called by the seller to cancel the deal.
+        #[ink(message)]
+        pub fn refund_funds(&mut self) -> Result<(), Error> {
+            if self.env().caller() != self.seller {
+                return Err(Error::Unauthorized);
+            }
+            self.refund()
+        }
+
+        #[ink(message)]
+        pub fn raise_dispute(&mut self) -> Result<(), Error> {
             let caller = self.env().caller();
-            if !self.authorized_callers.contains_key(&caller) || caller != self.buyer {
+            if caller != self.buyer && caller != self.seller {
                 return Err(Error::Unauthorized);
             }
-            self.env().transfer(self.seller, self.amount)?;
-            self.status = EscrowStatus::Released;
-            self.env().emit_event(ReleaseFunds {
-                seller: self.seller,
-                buyer: self.buyer,
-                amount: self.amount,
-            });
+            if self.status != EscrowStatus::Locked {
+                return Err(Error::InvalidStatus);
+            }
+            self.status = EscrowStatus::Disputed;
+            self.env().emit_event(RaiseDispute { raised_by: caller });
             Ok(())
         }
 
+        /// Settles a dispute, paying the seller if `release` is set and the buyer otherwise.
         #[ink(message)]
-        pub fn refund_funds(&mut self) -> Result<(), Error> {
+        pub fn resolve_dispute(&mut self, release: bool) -> Result<(), Error> {
             let caller = self.env().caller();
-            if !self.authorized_callers.contains_key(&caller) || caller != self.seller {
+            if caller != self.arbiter {
                 return Err(Error::Unauthorized);
             }
-            self.env().transfer(self.buyer, self.amount)?;
-            self.status = EscrowStatus::Refunded;
-            self.env().emit_event(RefundFunds {
-                seller: self.seller,
-                buyer: self.buyer,
-                amount: self.amount,
+            if self.status != EscrowStatus::Disputed {
+                return Err(Error::InvalidStatus);
+            }
+            if release {
+                self.release()?;
+            } else {
+                self.refund()?;
+            }
+            self.env().emit_event(ResolveDispute {
+                arbiter: caller,
+                released: release,
             });
             Ok(())
         }
 
+        /// Refunds the buyer once the deadline passed without the funds being released.
+        ///
+        /// This also works while a dispute is open, so that a dispute the arbiter never
+        /// settles cannot hold the funds past the deadline.
         #[ink(message)]
-        pub fn get_status(&self) -> EscrowStatus {
-            self.status
+        pub fn claim_refund_after_deadline(&mut self) -> Result<(), Error> {
+            if self.env().caller() != self.buyer {
+                return Err(Error::Unauthorized);
+            }
+            self.ensure_funded()?;
+            if self.env().block_timestamp() <= self.deadline {
+                return Err(Error::DeadlineNotReached);
+            }
+            self.refund()
         }
 
+        /// Moves the deadline to `deadline` once both the buyer and the seller asked for it.
+        ///
+        /// The first call only records the proposal, the other party then has to call
+        /// again with the same deadline.
         #[ink(message)]
-        pub fn authorize_caller(&mut self, caller: AccountId) {
-            let _ = self.authorized_callers.insert(caller, true);
+        pub fn extend_deadline(&mut self, deadline: Timestamp) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.buyer && caller != self.seller {
+                return Err(Error::Unauthorized);
+            }
+            if matches!(self.status, EscrowStatus::Released | EscrowStatus::Refunded) {
+                return Err(Error::InvalidStatus);
+            }
+            if deadline <= self.deadline {
+                return Err(Error::InvalidDeadline);
+            }
+
+            match self.extension {
+                Some((proposed, proposed_by)) if proposed == deadline && proposed_by != caller => {
+                    self.deadline = deadline;
+                    self.extension = None;
+                    self.env().emit_event(ExtendDeadline { deadline });
+                }
+                _ => {
+                    self.extension = Some((deadline, caller));
+                    self.env().emit_event(ProposeExtension {
+                        proposed_by: caller,
+                        deadline,
+                    });
+                }
+            }
+            Ok(())
         }
 
         #[ink(message)]
-        pub fn revoke_authorization(&mut self, caller: AccountId) {
-            let _ = self.authorized_callers.take(&caller);
+        pub fn get_deadline(&self) -> Timestamp {
+            self.deadline
         }
-    }
-
-    #[cfg(test)]
-    mod tests {
-        use super::*;
 
-        use ink_lang as ink;
+        #[ink(message)]
+        pub fn get_status(&self) -> EscrowStatus {
+            self.status
+        }
 
-        #[ink::test]
-        fn test_lock_funds() {
-            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);
-            assert_eq!(escrow.get_status(), EscrowStatus::Locked);
+        #[ink(message)]
+        pub fn get_amount(&self) -> Balance {
+            self.amount
+        }
 
-            let lock_funds_event = ink_env::test::emitted_events()
-                .find(|event| matches!(event.event, "LockFunds"))
-                .expect("no LockFunds event was emitted");
-            assert_eq!(lock_funds_event.event, "LockFunds");
-            assert_eq!(lock_funds_event.args[0].clone().into_account_id(), AccountId::from([0x1; 32]));
-            assert_eq!(lock_funds_event.args[1].clone().into_account_id(), AccountId::from([0x2; 32]));
-            assert_eq!(lock_funds_event.args[2].clone().into_balance(), 100);
+        #[ink(message)]
+        pub fn get_arbiter(&self) -> AccountId {
+            self.arbiter
         }
 
-        #[ink::test]
-        fn test_release_funds() {
-            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);
-            escrow.release_funds();
-            assert_eq!(escrow.get_status(), EscrowStatus::Released);
-
-            let release_funds_event = ink_env::test::emitted_events()
-                .find(|event| matches!(event.event, "ReleaseFunds"))
-                .expect("no ReleaseFunds event was emitted");
-            assert_eq!(release_funds_event.event, "ReleaseFunds");
-            assert_eq!(release_funds_event.args[0].clone().into_account_id(), AccountId::from([0x1; 32]));
-            assert_eq!(release_funds_event.args[1].clone().into_account_id(), AccountId::from([0x2; 32]));
-            assert_eq!(release_funds_event.args[2].clone().into_balance(), 100);
+        fn release(&mut self) -> Result<(), Error> {
+            self.ensure_funded()?;
+            self.env()
+                .transfer(self.seller, self.amount)
+                .map_err(|_| Error::TransferFailed)?;
+            self.status = EscrowStatus::Released;
+            self.env().emit_event(ReleaseFunds {
+                seller: self.seller,
+                buyer: self.buyer,
+                amount: self.amount,
+            });
+            Ok(())
         }
 
-        #[ink::test]
-        fn test_refund_funds() {
-            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);
-            escrow.refund_funds();
-            assert_eq!(escrow.get_status(), EscrowStatus::Refunded);
-
-            let refund_funds_event = ink_env::test::emitted_events()
-                .find(|event| matches!(event.event, "RefundFunds"))
-                .expect("no RefundFunds event was emitted");
-            assert_eq!(refund_funds_event.event, "RefundFunds");
-            assert_eq!(refund_funds_event.args[0].clone().into_account_id(), AccountId::from([0x1; 32]));
-            assert_eq!(refund_funds_event.args[1].clone().into_account_id(), AccountId::from([0x2; 32]));
-            assert_eq!(refund_funds_event.args[2].clone().into_balance(), 100);
+        fn refund(&mut self) -> Result<(), Error> {
+            self.ensure_funded()?;
+            self.env()
+                .transfer(self.buyer, self.amount)
+                .map_err(|_| Error::TransferFailed)?;
+            self.status = EscrowStatus::Refunded;
+            self.env().emit_event(RefundFunds {
+                seller: self.seller,
+                buyer: self.buyer,
+                amount: self.amount,
+            });
+            Ok(())
         }
 
-        #[ink::test]
-        fn test_refund_funds_unauthorized() {
-            let mut escrow = Escrow::new(AccountId::from([0x1; 32]), AccountId::from([0x2; 32]), 100);
-            assert_eq!(escrow.get_status(), EscrowStatus::Locked);
-
-            // try to refund funds from a non-seller account
-            let unauthorized_caller = AccountId::from([0x3; 32]);
-            assert_eq!(
-                escrow.refund_funds_with_call(
-                    ink_env::test::Call::new(unauthorized_caller, 0)
-                ),
-                Err(Error::Unauthorized)
-            );
-
-            // check that the status has not changed
-            assert_eq!(escrow.get_status(), EscrowStatus::Locked);
-
-            // check that no events have been emitted
-            let emitted_events = ink_env::test::emitted_events();
-            assert!(emitted_events.iter().all(|event| event.event != "ReleaseFunds"));
-            assert!(emitted_events.iter().all(|event| event.event != "RefundFunds"));
+        /// The funds can only be paid out once, while they are locked or disputed.
+        fn ensure_funded(&self) -> Result<(), Error> {
+            match self.status {
+                EscrowStatus::Locked | EscrowStatus::Disputed => Ok(()),
+                _ => Err(Error::InvalidStatus),
+            }
         }
     }
 }
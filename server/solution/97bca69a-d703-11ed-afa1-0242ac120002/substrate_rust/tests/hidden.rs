@@ -0,0 +1,36 @@
+use escrow::{Error, Escrow, EscrowStatus};
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{balance_of, callee, set_balance, set_caller, transfer_in};
+
+const SELLER: AccountId = AccountId([0x4; 32]);
+const BUYER: AccountId = AccountId([0x2; 32]);
+const ARBITER: AccountId = AccountId([0x3; 32]);
+const DEADLINE: u64 = 1_000;
+
+fn setup() -> Escrow {
+    let contract = callee();
+    set_balance(contract, 1_000_000);
+    Escrow::new(SELLER, BUYER, ARBITER, 100, DEADLINE).unwrap()
+}
+
+#[ink::test]
+fn test_refund_funds_unauthorized() {
+    let mut escrow = setup();
+    set_caller(BUYER);
+    transfer_in(100);
+    escrow.lock_funds().unwrap();
+
+    // try to refund funds from a non-seller account
+    for caller in [BUYER, ARBITER, AccountId::from([0x5; 32])] {
+        set_caller(caller);
+        assert_eq!(escrow.refund_funds(), Err(Error::Unauthorized));
+    }
+
+    // check that the status has not changed and the funds are still held
+    assert_eq!(escrow.get_status(), EscrowStatus::Locked);
+    let contract = callee();
+    assert_eq!(balance_of(contract), 1_000_100);
+
+    // check that only the deposit emitted an event
+    assert_eq!(leetcoin_test_kit::recorded_events().len(), 1);
+}
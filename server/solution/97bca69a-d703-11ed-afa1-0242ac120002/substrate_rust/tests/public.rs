@@ -0,0 +1,264 @@
+use escrow::{
+    Error, Escrow, EscrowStatus, ExtendDeadline, LockFunds, ProposeExtension, RaiseDispute,
+    RefundFunds, ReleaseFunds, ResolveDispute,
+};
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{
+    balance_of, callee, event, set_balance, set_block_timestamp, set_caller, set_value_transferred,
+    transfer_in,
+};
+
+const SELLER: AccountId = AccountId([0x4; 32]);
+/// Starts with a balance of 1000 in the off-chain environment.
+const BUYER: AccountId = AccountId([0x2; 32]);
+const ARBITER: AccountId = AccountId([0x3; 32]);
+const DEADLINE: u64 = 1_000;
+
+fn setup() -> Escrow {
+    let contract = callee();
+    set_balance(contract, 1_000_000);
+    Escrow::new(SELLER, BUYER, ARBITER, 100, DEADLINE).unwrap()
+}
+
+/// Deposits the agreed amount as the buyer.
+fn lock(escrow: &mut Escrow) {
+    set_caller(BUYER);
+    transfer_in(100);
+    escrow.lock_funds().unwrap();
+}
+
+#[ink::test]
+fn test_new() {
+    let escrow = setup();
+    assert_eq!(escrow.get_status(), EscrowStatus::AwaitingDeposit);
+    assert_eq!(escrow.get_amount(), 100);
+    assert_eq!(escrow.get_arbiter(), ARBITER);
+
+    assert_eq!(
+        Escrow::new(SELLER, SELLER, ARBITER, 100, DEADLINE).err(),
+        Some(Error::InvalidParties)
+    );
+    assert_eq!(
+        Escrow::new(SELLER, BUYER, SELLER, 100, DEADLINE).err(),
+        Some(Error::InvalidParties)
+    );
+    assert_eq!(
+        Escrow::new(SELLER, BUYER, BUYER, 100, DEADLINE).err(),
+        Some(Error::InvalidParties)
+    );
+    assert_eq!(
+        Escrow::new(SELLER, BUYER, ARBITER, 0, DEADLINE).err(),
+        Some(Error::ZeroAmount)
+    );
+}
+
+#[ink::test]
+fn test_lock_funds() {
+    let mut escrow = setup();
+    assert_eq!(escrow.get_status(), EscrowStatus::AwaitingDeposit);
+
+    lock(&mut escrow);
+    assert_eq!(escrow.get_status(), EscrowStatus::Locked);
+    assert_eq!(balance_of(BUYER), 900);
+    leetcoin_test_kit::assert_events([event(LockFunds {
+        seller: SELLER,
+        buyer: BUYER,
+        amount: 100,
+    })]);
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_topic(&events[0], &100u128);
+
+    transfer_in(100);
+    assert_eq!(escrow.lock_funds(), Err(Error::InvalidStatus));
+}
+
+#[ink::test]
+fn test_lock_funds_requires_amount() {
+    let mut escrow = setup();
+
+    set_caller(BUYER);
+    transfer_in(99);
+    assert_eq!(escrow.lock_funds(), Err(Error::IncorrectAmount));
+
+    set_caller(SELLER);
+    set_value_transferred(100);
+    assert_eq!(escrow.lock_funds(), Err(Error::Unauthorized));
+
+    assert_eq!(escrow.get_status(), EscrowStatus::AwaitingDeposit);
+    leetcoin_test_kit::assert_no_events();
+}
+
+#[ink::test]
+fn test_release_funds() {
+    let mut escrow = setup();
+    assert_eq!(escrow.release_funds(), Err(Error::Unauthorized));
+    set_caller(BUYER);
+    assert_eq!(escrow.release_funds(), Err(Error::InvalidStatus));
+
+    lock(&mut escrow);
+    assert_eq!(escrow.release_funds(), Ok(()));
+    assert_eq!(escrow.get_status(), EscrowStatus::Released);
+    assert_eq!(balance_of(SELLER), 100);
+    assert_eq!(escrow.release_funds(), Err(Error::InvalidStatus));
+
+    let events = leetcoin_test_kit::recorded_events();
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        leetcoin_test_kit::decode_event::<ReleaseFunds>(&events[1]).map(|event| event.amount),
+        Some(100)
+    );
+}
+
+#[ink::test]
+fn test_refund_funds() {
+    let mut escrow = setup();
+    lock(&mut escrow);
+
+    set_caller(SELLER);
+    assert_eq!(escrow.refund_funds(), Ok(()));
+    assert_eq!(escrow.get_status(), EscrowStatus::Refunded);
+    assert_eq!(balance_of(BUYER), 1000);
+    assert_eq!(escrow.refund_funds(), Err(Error::InvalidStatus));
+
+    set_caller(BUYER);
+    assert_eq!(escrow.release_funds(), Err(Error::InvalidStatus));
+    assert!(matches!(
+        leetcoin_test_kit::decode_events::<RefundFunds>()[..],
+        [RefundFunds { amount: 100, .. }]
+    ));
+}
+
+#[ink::test]
+fn test_resolve_dispute() {
+    let mut escrow = setup();
+    lock(&mut escrow);
+
+    set_caller(ARBITER);
+    assert_eq!(escrow.resolve_dispute(true), Err(Error::InvalidStatus));
+    assert_eq!(escrow.raise_dispute(), Err(Error::Unauthorized));
+
+    set_caller(SELLER);
+    assert_eq!(escrow.raise_dispute(), Ok(()));
+    assert_eq!(escrow.get_status(), EscrowStatus::Disputed);
+    assert_eq!(escrow.raise_dispute(), Err(Error::InvalidStatus));
+    assert_eq!(escrow.resolve_dispute(true), Err(Error::Unauthorized));
+
+    set_caller(ARBITER);
+    assert_eq!(escrow.resolve_dispute(false), Ok(()));
+    assert_eq!(escrow.get_status(), EscrowStatus::Refunded);
+    assert_eq!(balance_of(BUYER), 1000);
+    assert_eq!(escrow.resolve_dispute(true), Err(Error::InvalidStatus));
+
+    leetcoin_test_kit::assert_events([
+        event(LockFunds {
+            seller: SELLER,
+            buyer: BUYER,
+            amount: 100,
+        }),
+        event(RaiseDispute { raised_by: SELLER }),
+        event(RefundFunds {
+            seller: SELLER,
+            buyer: BUYER,
+            amount: 100,
+        }),
+        event(ResolveDispute {
+            arbiter: ARBITER,
+            released: false,
+        }),
+    ]);
+}
+
+#[ink::test]
+fn test_claim_refund_after_deadline() {
+    let mut escrow = setup();
+    set_caller(BUYER);
+    assert_eq!(
+        escrow.claim_refund_after_deadline(),
+        Err(Error::InvalidStatus)
+    );
+
+    lock(&mut escrow);
+    set_block_timestamp(DEADLINE);
+    assert_eq!(
+        escrow.claim_refund_after_deadline(),
+        Err(Error::DeadlineNotReached)
+    );
+
+    set_block_timestamp(DEADLINE + 1);
+    set_caller(SELLER);
+    assert_eq!(
+        escrow.claim_refund_after_deadline(),
+        Err(Error::Unauthorized)
+    );
+    set_caller(BUYER);
+    assert_eq!(escrow.claim_refund_after_deadline(), Ok(()));
+    assert_eq!(escrow.get_status(), EscrowStatus::Refunded);
+    assert_eq!(balance_of(BUYER), 1000);
+    assert_eq!(leetcoin_test_kit::decode_events::<RefundFunds>().len(), 1);
+}
+
+#[ink::test]
+fn test_claim_refund_after_deadline_while_disputed() {
+    let mut escrow = setup();
+    lock(&mut escrow);
+
+    // The seller opens a dispute just before the deadline.
+    set_block_timestamp(DEADLINE - 1);
+    set_caller(SELLER);
+    assert_eq!(escrow.raise_dispute(), Ok(()));
+    set_caller(BUYER);
+    assert_eq!(
+        escrow.claim_refund_after_deadline(),
+        Err(Error::DeadlineNotReached)
+    );
+
+    set_block_timestamp(DEADLINE + 1);
+    assert_eq!(escrow.claim_refund_after_deadline(), Ok(()));
+    assert_eq!(escrow.get_status(), EscrowStatus::Refunded);
+    assert_eq!(balance_of(BUYER), 1000);
+
+    set_caller(ARBITER);
+    assert_eq!(escrow.resolve_dispute(true), Err(Error::InvalidStatus));
+    assert_eq!(balance_of(SELLER), 0);
+}
+
+#[ink::test]
+fn test_extend_deadline() {
+    let mut escrow = setup();
+    lock(&mut escrow);
+
+    assert_eq!(
+        escrow.extend_deadline(DEADLINE),
+        Err(Error::InvalidDeadline)
+    );
+    set_caller(ARBITER);
+    assert_eq!(
+        escrow.extend_deadline(2 * DEADLINE),
+        Err(Error::Unauthorized)
+    );
+
+    // Agreeing on a different deadline replaces the proposal.
+    set_caller(BUYER);
+    assert_eq!(escrow.extend_deadline(3 * DEADLINE), Ok(()));
+    set_caller(SELLER);
+    assert_eq!(escrow.extend_deadline(2 * DEADLINE), Ok(()));
+    assert_eq!(escrow.get_deadline(), DEADLINE);
+    set_caller(BUYER);
+    assert_eq!(escrow.extend_deadline(2 * DEADLINE), Ok(()));
+    assert_eq!(escrow.get_deadline(), 2 * DEADLINE);
+
+    set_block_timestamp(DEADLINE + 1);
+    assert_eq!(
+        escrow.claim_refund_after_deadline(),
+        Err(Error::DeadlineNotReached)
+    );
+
+    let events = leetcoin_test_kit::recorded_events();
+    assert_eq!(events.len(), 4);
+    leetcoin_test_kit::assert_signature::<ProposeExtension>(&events[2]);
+    leetcoin_test_kit::assert_topic(&events[2], &SELLER);
+    assert!(matches!(
+        leetcoin_test_kit::decode_events::<ExtendDeadline>()[..],
+        [ExtendDeadline { deadline: 2_000 }]
+    ));
+}
@@ -0,0 +1,96 @@
+use escrow::{
+    Escrow, ExtendDeadline, LockFunds, ProposeExtension, RaiseDispute, RefundFunds, ReleaseFunds,
+    ResolveDispute,
+};
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{Args, Contract, Event, ReplayError, ToValue, Value};
+
+/// Binds `testcase.txt` to [`Escrow`].
+struct Replay(Escrow);
+
+impl Contract for Replay {
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => Escrow::new(
+                args.account(0)?,
+                args.account(1)?,
+                args.account(2)?,
+                args.balance(3)?,
+                args.u64(4)?,
+            )
+            .map(Replay)
+            .map_err(|err| ReplayError::new(format!("`new` failed with {:?}", err))),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "lock_funds" => Ok(self.0.lock_funds().to_value()),
+            "release_funds" => Ok(self.0.release_funds().to_value()),
+            "refund_funds" => Ok(self.0.refund_funds().to_value()),
+            "raise_dispute" => Ok(self.0.raise_dispute().to_value()),
+            "resolve_dispute" => Ok(self.0.resolve_dispute(args.bool(0)?).to_value()),
+            "claim_refund_after_deadline" => Ok(self.0.claim_refund_after_deadline().to_value()),
+            "extend_deadline" => Ok(self.0.extend_deadline(args.u64(0)?).to_value()),
+            "get_deadline" => Ok(self.0.get_deadline().to_value()),
+            "get_status" => Ok(format!("{:?}", self.0.get_status()).to_value()),
+            "get_amount" => Ok(self.0.get_amount().to_value()),
+            "get_arbiter" => Ok(self.0.get_arbiter().to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        if let Some(event) = decode_event::<LockFunds>(event) {
+            return Some(
+                Event::new("LockFunds")
+                    .field("seller", event.seller)
+                    .field("buyer", event.buyer)
+                    .field("amount", event.amount),
+            );
+        }
+        if let Some(event) = decode_event::<ReleaseFunds>(event) {
+            return Some(
+                Event::new("ReleaseFunds")
+                    .field("seller", event.seller)
+                    .field("buyer", event.buyer)
+                    .field("amount", event.amount),
+            );
+        }
+        if let Some(event) = decode_event::<RefundFunds>(event) {
+            return Some(
+                Event::new("RefundFunds")
+                    .field("seller", event.seller)
+                    .field("buyer", event.buyer)
+                    .field("amount", event.amount),
+            );
+        }
+        if let Some(event) = decode_event::<RaiseDispute>(event) {
+            return Some(Event::new("RaiseDispute").field("raised_by", event.raised_by));
+        }
+        if let Some(event) = decode_event::<ResolveDispute>(event) {
+            return Some(
+                Event::new("ResolveDispute")
+                    .field("arbiter", event.arbiter)
+                    .field("released", event.released),
+            );
+        }
+        if let Some(event) = decode_event::<ProposeExtension>(event) {
+            return Some(
+                Event::new("ProposeExtension")
+                    .field("proposed_by", event.proposed_by)
+                    .field("deadline", event.deadline),
+            );
+        }
+        if let Some(event) = decode_event::<ExtendDeadline>(event) {
+            return Some(Event::new("ExtendDeadline").field("deadline", event.deadline));
+        }
+        None
+    }
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
@@ -0,0 +1,233 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::psp22_token::{Approval, PSP22Error, Psp22Token, Transfer};
+
+#[ink::contract]
+mod psp22_token {
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// A PSP22 token, the ERC-20 equivalent for ink! contracts, with a fixed supply
+    /// minted to the deployer.
+    ///
+    /// Messages use the standard PSP22 selectors, the first four bytes of the BLAKE2b
+    /// hash of `PSP22::<message>` or `PSP22Metadata::<message>`, so wallets and other
+    /// contracts can call the token without its metadata.
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Psp22Token {
+        total_supply: Balance,
+        balances: Mapping<AccountId, Balance>,
+        /// Amount `spender` may still transfer on behalf of `owner`, keyed by `(owner, spender)`.
+        allowances: Mapping<(AccountId, AccountId), Balance>,
+        name: Option<String>,
+        symbol: Option<String>,
+        decimals: u8,
+    }
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub spender: AccountId,
+        /// The new allowance.
+        pub amount: Balance,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PSP22Error {
+        /// An error not covered by the other variants.
+        Custom(String),
+        InsufficientBalance,
+        InsufficientAllowance,
+    }
+
+    impl Psp22Token {
+        /// Creates the token and mints `total_supply` to the caller.
+        #[ink(constructor)]
+        pub fn new(
+            total_supply: Balance,
+            name: Option<String>,
+            symbol: Option<String>,
+            decimals: u8,
+        ) -> Self {
+            let caller = Self::env().caller();
+            let mut token = Self {
+                total_supply,
+                name,
+                symbol,
+                decimals,
+                ..Default::default()
+            };
+            token.balances.insert(caller, &total_supply);
+            Self::env().emit_event(Transfer {
+                from: None,
+                to: Some(caller),
+                value: total_supply,
+            });
+            token
+        }
+
+        #[ink(message, selector = 0x162df8c2)]
+        pub fn total_supply(&self) -> Balance {
+            self.total_supply
+        }
+
+        #[ink(message, selector = 0x6568382f)]
+        pub fn balance_of(&self, owner: AccountId) -> Balance {
+            self.balances.get(owner).unwrap_or(0)
+        }
+
+        #[ink(message, selector = 0x4d47d921)]
+        pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            self.allowances.get((owner, spender)).unwrap_or(0)
+        }
+
+        /// Transfers `value` from the caller to `to`. `data` is not interpreted by this
+        /// token and is only part of the signature required by PSP22.
+        #[ink(message, selector = 0xdb20f9f5)]
+        pub fn transfer(
+            &mut self,
+            to: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let _ = data;
+            let from = self.env().caller();
+            self.transfer_from_to(from, to, value)
+        }
+
+        /// Transfers `value` from `from` to `to` out of the caller's allowance.
+        #[ink(message, selector = 0x54b3c76e)]
+        pub fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let _ = data;
+            let spender = self.env().caller();
+            if from == to || value == 0 {
+                return Ok(());
+            }
+            let allowance = self.allowance(from, spender);
+            if allowance < value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+            if self.balance_of(from) < value {
+                return Err(PSP22Error::InsufficientBalance);
+            }
+            self.set_allowance(from, spender, allowance - value);
+            self.transfer_from_to(from, to, value)
+        }
+
+        /// Sets the allowance of `spender` to `value`, replacing the previous one.
+        #[ink(message, selector = 0xb20f1bbd)]
+        pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            if owner == spender {
+                return Ok(());
+            }
+            self.set_allowance(owner, spender, value);
+            Ok(())
+        }
+
+        /// Raises the allowance of `spender` by `delta_value`, saturating at the maximum balance.
+        #[ink(message, selector = 0x96d6b57a)]
+        pub fn increase_allowance(
+            &mut self,
+            spender: AccountId,
+            delta_value: Balance,
+        ) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            if owner == spender || delta_value == 0 {
+                return Ok(());
+            }
+            let allowance = self.allowance(owner, spender);
+            self.set_allowance(owner, spender, allowance.saturating_add(delta_value));
+            Ok(())
+        }
+
+        #[ink(message, selector = 0xfecb57d5)]
+        pub fn decrease_allowance(
+            &mut self,
+            spender: AccountId,
+            delta_value: Balance,
+        ) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            if owner == spender || delta_value == 0 {
+                return Ok(());
+            }
+            let allowance = self.allowance(owner, spender);
+            if allowance < delta_value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+            self.set_allowance(owner, spender, allowance - delta_value);
+            Ok(())
+        }
+
+        #[ink(message, selector = 0x3d261bd4)]
+        pub fn token_name(&self) -> Option<String> {
+            self.name.clone()
+        }
+
+        #[ink(message, selector = 0x34205be5)]
+        pub fn token_symbol(&self) -> Option<String> {
+            self.symbol.clone()
+        }
+
+        #[ink(message, selector = 0x7271b782)]
+        pub fn token_decimals(&self) -> u8 {
+            self.decimals
+        }
+
+        /// Moves `value` between two accounts. Transfers to oneself and of nothing
+        /// succeed without emitting an event, as PSP22 requires.
+        fn transfer_from_to(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Result<(), PSP22Error> {
+            if from == to || value == 0 {
+                return Ok(());
+            }
+            let from_balance = self.balance_of(from);
+            if from_balance < value {
+                return Err(PSP22Error::InsufficientBalance);
+            }
+            self.balances.insert(from, &(from_balance - value));
+            // Cannot overflow, the balances add up to the total supply.
+            let to_balance = self.balance_of(to);
+            self.balances.insert(to, &(to_balance + value));
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: Some(to),
+                value,
+            });
+            Ok(())
+        }
+
+        fn set_allowance(&mut self, owner: AccountId, spender: AccountId, amount: Balance) {
+            self.allowances.insert((owner, spender), &amount);
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                amount,
+            });
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::set_caller;
+use psp22_token::{Approval, PSP22Error, Psp22Token};
+
+const ALICE: AccountId = AccountId([0x1; 32]);
+const BOB: AccountId = AccountId([0x2; 32]);
+const CHARLIE: AccountId = AccountId([0x3; 32]);
+
+#[ink::test]
+fn test_allowance_is_spent_once() {
+    let mut token = Psp22Token::new(1_000, None, None, 0);
+    assert_eq!(token.token_name(), None);
+    token.approve(BOB, 100).unwrap();
+
+    set_caller(BOB);
+    assert_eq!(token.transfer_from(ALICE, BOB, 100, vec![]), Ok(()));
+    assert_eq!(
+        token.transfer_from(ALICE, BOB, 1, vec![]),
+        Err(PSP22Error::InsufficientAllowance)
+    );
+    // Spending tokens that were received does not need an allowance.
+    assert_eq!(token.transfer(CHARLIE, 100, vec![]), Ok(()));
+    assert_eq!(token.balance_of(CHARLIE), 100);
+    assert_eq!(token.total_supply(), 1_000);
+}
+
+#[ink::test]
+fn test_transfer_from_to_self_keeps_allowance() {
+    let mut token = Psp22Token::new(1_000, None, None, 0);
+    token.approve(BOB, 100).unwrap();
+
+    set_caller(BOB);
+    assert_eq!(token.transfer_from(ALICE, ALICE, 100, vec![]), Ok(()));
+    assert_eq!(token.transfer_from(ALICE, CHARLIE, 0, vec![]), Ok(()));
+    assert_eq!(token.allowance(ALICE, BOB), 100);
+    assert_eq!(leetcoin_test_kit::decode_events::<Approval>().len(), 1);
+}
+
+/// The selector the contract dispatches `message` under; only compiles if it has one.
+macro_rules! selector_of {
+    ($message:literal) => {{
+        const ID: u32 = ink::selector_id!($message);
+        <Psp22Token as ink::reflect::DispatchableMessageInfo<ID>>::SELECTOR
+    }};
+}
+
+#[test]
+fn test_standard_selectors() {
+    assert_eq!(
+        selector_of!("PSP22::total_supply"),
+        [0x16, 0x2d, 0xf8, 0xc2]
+    );
+    assert_eq!(selector_of!("PSP22::balance_of"), [0x65, 0x68, 0x38, 0x2f]);
+    assert_eq!(selector_of!("PSP22::allowance"), [0x4d, 0x47, 0xd9, 0x21]);
+    assert_eq!(selector_of!("PSP22::transfer"), [0xdb, 0x20, 0xf9, 0xf5]);
+    assert_eq!(
+        selector_of!("PSP22::transfer_from"),
+        [0x54, 0xb3, 0xc7, 0x6e]
+    );
+    assert_eq!(selector_of!("PSP22::approve"), [0xb2, 0x0f, 0x1b, 0xbd]);
+    assert_eq!(
+        selector_of!("PSP22::increase_allowance"),
+        [0x96, 0xd6, 0xb5, 0x7a]
+    );
+    assert_eq!(
+        selector_of!("PSP22::decrease_allowance"),
+        [0xfe, 0xcb, 0x57, 0xd5]
+    );
+    assert_eq!(
+        selector_of!("PSP22Metadata::token_name"),
+        [0x3d, 0x26, 0x1b, 0xd4]
+    );
+    assert_eq!(
+        selector_of!("PSP22Metadata::token_symbol"),
+        [0x34, 0x20, 0x5b, 0xe5]
+    );
+    assert_eq!(
+        selector_of!("PSP22Metadata::token_decimals"),
+        [0x72, 0x71, 0xb7, 0x82]
+    );
+}
@@ -0,0 +1,194 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{event, set_caller};
+use psp22_token::{Approval, PSP22Error, Psp22Token, Transfer};
+
+/// The default caller, which deploys the token.
+const ALICE: AccountId = AccountId([0x1; 32]);
+const BOB: AccountId = AccountId([0x2; 32]);
+const CHARLIE: AccountId = AccountId([0x3; 32]);
+
+fn setup() -> Psp22Token {
+    Psp22Token::new(1_000, Some("Leet".into()), Some("LEET".into()), 18)
+}
+
+#[ink::test]
+fn test_new() {
+    let token = setup();
+    assert_eq!(token.total_supply(), 1_000);
+    assert_eq!(token.balance_of(ALICE), 1_000);
+    assert_eq!(token.balance_of(BOB), 0);
+    assert_eq!(token.token_name().as_deref(), Some("Leet"));
+    assert_eq!(token.token_symbol().as_deref(), Some("LEET"));
+    assert_eq!(token.token_decimals(), 18);
+
+    leetcoin_test_kit::assert_events([event(Transfer {
+        from: None,
+        to: Some(ALICE),
+        value: 1_000,
+    })]);
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_topic(&events[0], &ALICE);
+}
+
+#[ink::test]
+fn test_transfer() {
+    let mut token = setup();
+
+    assert_eq!(token.transfer(BOB, 100, vec![]), Ok(()));
+    assert_eq!(token.balance_of(ALICE), 900);
+    assert_eq!(token.balance_of(BOB), 100);
+    assert_eq!(
+        token.transfer(BOB, 901, vec![]),
+        Err(PSP22Error::InsufficientBalance)
+    );
+
+    let transfers = leetcoin_test_kit::decode_events::<Transfer>();
+    assert_eq!(transfers.len(), 2);
+    assert_eq!(
+        (transfers[1].from, transfers[1].to, transfers[1].value),
+        (Some(ALICE), Some(BOB), 100)
+    );
+}
+
+#[ink::test]
+fn test_transfer_to_self_or_nothing_is_a_no_op() {
+    let mut token = setup();
+    assert_eq!(token.transfer(ALICE, 2_000, vec![]), Ok(()));
+    assert_eq!(token.transfer(BOB, 0, vec![]), Ok(()));
+    assert_eq!(token.balance_of(ALICE), 1_000);
+    assert_eq!(leetcoin_test_kit::recorded_events().len(), 1);
+}
+
+#[ink::test]
+fn test_approve() {
+    let mut token = setup();
+
+    assert_eq!(token.approve(BOB, 100), Ok(()));
+    assert_eq!(token.allowance(ALICE, BOB), 100);
+    assert_eq!(token.allowance(BOB, ALICE), 0);
+
+    // A new approval replaces the old one rather than adding to it.
+    assert_eq!(token.approve(BOB, 30), Ok(()));
+    assert_eq!(token.allowance(ALICE, BOB), 30);
+
+    // Approving oneself does nothing.
+    assert_eq!(token.approve(ALICE, 50), Ok(()));
+    assert_eq!(token.allowance(ALICE, ALICE), 0);
+
+    let approvals = leetcoin_test_kit::decode_events::<Approval>();
+    assert_eq!(approvals.len(), 2);
+    assert_eq!(
+        (
+            approvals[1].owner,
+            approvals[1].spender,
+            approvals[1].amount
+        ),
+        (ALICE, BOB, 30)
+    );
+}
+
+#[ink::test]
+fn test_transfer_from() {
+    let mut token = setup();
+    token.approve(BOB, 100).unwrap();
+
+    set_caller(BOB);
+    assert_eq!(token.transfer_from(ALICE, CHARLIE, 60, vec![]), Ok(()));
+    assert_eq!(token.balance_of(ALICE), 940);
+    assert_eq!(token.balance_of(CHARLIE), 60);
+    assert_eq!(token.allowance(ALICE, BOB), 40);
+
+    leetcoin_test_kit::assert_events([
+        event(Transfer {
+            from: None,
+            to: Some(ALICE),
+            value: 1_000,
+        }),
+        event(Approval {
+            owner: ALICE,
+            spender: BOB,
+            amount: 100,
+        }),
+        event(Approval {
+            owner: ALICE,
+            spender: BOB,
+            amount: 40,
+        }),
+        event(Transfer {
+            from: Some(ALICE),
+            to: Some(CHARLIE),
+            value: 60,
+        }),
+    ]);
+}
+
+#[ink::test]
+fn test_transfer_from_exceeding_allowance() {
+    let mut token = setup();
+    token.approve(BOB, 100).unwrap();
+
+    set_caller(BOB);
+    assert_eq!(
+        token.transfer_from(ALICE, CHARLIE, 101, vec![]),
+        Err(PSP22Error::InsufficientAllowance)
+    );
+    // Allowances are per spender.
+    set_caller(CHARLIE);
+    assert_eq!(
+        token.transfer_from(ALICE, CHARLIE, 1, vec![]),
+        Err(PSP22Error::InsufficientAllowance)
+    );
+    assert_eq!(token.allowance(ALICE, BOB), 100);
+    assert_eq!(token.balance_of(ALICE), 1_000);
+}
+
+#[ink::test]
+fn test_transfer_from_exceeding_balance() {
+    let mut token = setup();
+    token.approve(BOB, 5_000).unwrap();
+
+    set_caller(BOB);
+    assert_eq!(
+        token.transfer_from(ALICE, CHARLIE, 1_001, vec![]),
+        Err(PSP22Error::InsufficientBalance)
+    );
+    assert_eq!(token.allowance(ALICE, BOB), 5_000);
+    assert_eq!(token.transfer_from(ALICE, CHARLIE, 1_000, vec![]), Ok(()));
+    assert_eq!(token.allowance(ALICE, BOB), 4_000);
+    assert_eq!(token.balance_of(ALICE), 0);
+}
+
+#[ink::test]
+fn test_increase_allowance() {
+    let mut token = setup();
+
+    assert_eq!(token.increase_allowance(BOB, 100), Ok(()));
+    assert_eq!(token.increase_allowance(BOB, 50), Ok(()));
+    assert_eq!(token.allowance(ALICE, BOB), 150);
+
+    assert_eq!(token.increase_allowance(BOB, u128::MAX), Ok(()));
+    assert_eq!(token.allowance(ALICE, BOB), u128::MAX);
+
+    assert_eq!(leetcoin_test_kit::decode_events::<Approval>().len(), 3);
+    assert_eq!(token.increase_allowance(BOB, 0), Ok(()));
+    assert_eq!(leetcoin_test_kit::decode_events::<Approval>().len(), 3);
+}
+
+#[ink::test]
+fn test_decrease_allowance() {
+    let mut token = setup();
+    token.approve(BOB, 100).unwrap();
+
+    assert_eq!(token.decrease_allowance(BOB, 40), Ok(()));
+    assert_eq!(token.allowance(ALICE, BOB), 60);
+    assert_eq!(
+        token.decrease_allowance(BOB, 61),
+        Err(PSP22Error::InsufficientAllowance)
+    );
+    assert_eq!(token.decrease_allowance(BOB, 60), Ok(()));
+    assert_eq!(token.allowance(ALICE, BOB), 0);
+    assert_eq!(
+        token.decrease_allowance(CHARLIE, 1),
+        Err(PSP22Error::InsufficientAllowance)
+    );
+}
@@ -0,0 +1,85 @@
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{Args, Contract, Event, ReplayError, ToValue, Value};
+use psp22_token::{Approval, Psp22Token, Transfer};
+
+/// Binds `testcase.txt` to [`Psp22Token`].
+struct Replay(Psp22Token);
+
+impl Contract for Replay {
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => Ok(Replay(Psp22Token::new(
+                args.balance(0)?,
+                args.optional_string(1)?,
+                args.optional_string(2)?,
+                args.u8(3)?,
+            ))),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "total_supply" => Ok(self.0.total_supply().to_value()),
+            "balance_of" => Ok(self.0.balance_of(args.account(0)?).to_value()),
+            "allowance" => Ok(self
+                .0
+                .allowance(args.account(0)?, args.account(1)?)
+                .to_value()),
+            "transfer" => Ok(self
+                .0
+                .transfer(args.account(0)?, args.balance(1)?, args.bytes(2)?)
+                .to_value()),
+            "transfer_from" => Ok(self
+                .0
+                .transfer_from(
+                    args.account(0)?,
+                    args.account(1)?,
+                    args.balance(2)?,
+                    args.bytes(3)?,
+                )
+                .to_value()),
+            "approve" => Ok(self
+                .0
+                .approve(args.account(0)?, args.balance(1)?)
+                .to_value()),
+            "increase_allowance" => Ok(self
+                .0
+                .increase_allowance(args.account(0)?, args.balance(1)?)
+                .to_value()),
+            "decrease_allowance" => Ok(self
+                .0
+                .decrease_allowance(args.account(0)?, args.balance(1)?)
+                .to_value()),
+            "token_name" => Ok(self.0.token_name().to_value()),
+            "token_symbol" => Ok(self.0.token_symbol().to_value()),
+            "token_decimals" => Ok(self.0.token_decimals().to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        if let Some(event) = decode_event::<Transfer>(event) {
+            return Some(
+                Event::new("Transfer")
+                    .field("from", event.from)
+                    .field("to", event.to)
+                    .field("value", event.value),
+            );
+        }
+        if let Some(event) = decode_event::<Approval>(event) {
+            return Some(
+                Event::new("Approval")
+                    .field("owner", event.owner)
+                    .field("spender", event.spender)
+                    .field("amount", event.amount),
+            );
+        }
+        None
+    }
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
@@ -1,47 +1,104 @@
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-use ink_lang as ink;
+pub use self::simple_storage::{Error, SimpleStorage, ValueChanged, MAX_HISTORY_CAPACITY};
 
 #[ink::contract]
 mod simple_storage {
+    use ink::prelude::vec::Vec;
+
+    /// The most values the history may keep, all of them are stored in a single cell.
+    pub const MAX_HISTORY_CAPACITY: u32 = 100;
+
+    /// A single value only the deployer may change, along with the last few values it
+    /// was set to.
     #[ink(storage)]
     pub struct SimpleStorage {
-        value: ink_storage::collections::HashMap<AccountId, u32>,
+        value: Option<u32>,
+        owner: AccountId,
+        /// A ring buffer of the most recent values, `history_start` is its oldest entry
+        /// once it is full.
+        history: Vec<u32>,
+        history_start: u32,
+        history_capacity: u32,
+    }
+
+    #[ink(event)]
+    pub struct ValueChanged {
+        /// `None` when the value was set for the first time.
+        pub old: Option<u32>,
+        pub new: u32,
+        #[ink(topic)]
+        pub by: AccountId,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Unauthorized,
+        /// The history capacity is above `MAX_HISTORY_CAPACITY`.
+        CapacityTooLarge,
     }
 
     impl SimpleStorage {
+        /// Creates the storage, keeping the last `history_capacity` values.
         #[ink(constructor)]
-        pub fn new() -> Self {
-            Self {
-                value: Default::default(),
+        pub fn new(history_capacity: u32) -> Result<Self, Error> {
+            if history_capacity > MAX_HISTORY_CAPACITY {
+                return Err(Error::CapacityTooLarge);
             }
+            Ok(Self {
+                value: None,
+                owner: Self::env().caller(),
+                history: Vec::new(),
+                history_start: 0,
+                history_capacity,
+            })
         }
 
         #[ink(message)]
         pub fn get(&self) -> Option<u32> {
-            self.value.get(&self.env().caller()).copied()
+            self.value
         }
 
         #[ink(message)]
-        pub fn set(&mut self, value: u32) {
-            self.value.insert(self.env().caller(), value);
+        pub fn set(&mut self, value: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(Error::Unauthorized);
+            }
+            let old = self.value.replace(value);
+            self.record(value);
+            self.env().emit_event(ValueChanged {
+                old,
+                new: value,
+                by: caller,
+            });
+            Ok(())
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-
-        use ink_lang as ink;
+        /// Returns the last values that were set, oldest first.
+        #[ink(message)]
+        pub fn get_history(&self) -> Vec<u32> {
+            let (newer, older) = self.history.split_at(self.history_start as usize);
+            older.iter().chain(newer).copied().collect()
+        }
 
-        #[ink::test]
-        fn test_get_and_set() {
-            let mut simple_storage = SimpleStorage::new();
-            assert_eq!(simple_storage.get(), None);
+        #[ink(message)]
+        pub fn get_owner(&self) -> AccountId {
+            self.owner
+        }
 
-            let new_value = 42;
-            simple_storage.set(new_value);
-            assert_eq!(simple_storage.get(), Some(new_value));
+        /// Appends `value` to the history, overwriting the oldest entry once it is full.
+        fn record(&mut self, value: u32) {
+            if self.history_capacity == 0 {
+                return;
+            }
+            if self.history.len() < self.history_capacity as usize {
+                self.history.push(value);
+            } else {
+                self.history[self.history_start as usize] = value;
+                self.history_start = (self.history_start + 1) % self.history_capacity;
+            }
         }
     }
 }
@@ -0,0 +1,43 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::set_caller;
+use simple_storage::{Error, SimpleStorage, MAX_HISTORY_CAPACITY};
+
+#[ink::test]
+fn test_value_is_shared() {
+    let mut simple_storage = SimpleStorage::new(2).unwrap();
+    simple_storage.set(1).unwrap();
+
+    set_caller(AccountId::from([0x2; 32]));
+    assert_eq!(simple_storage.get(), Some(1));
+    assert_eq!(simple_storage.set(2), Err(Error::Unauthorized));
+}
+
+#[ink::test]
+fn test_history_wraps_repeatedly() {
+    let mut simple_storage = SimpleStorage::new(2).unwrap();
+    for value in 1..=9 {
+        simple_storage.set(value).unwrap();
+        let expected: Vec<u32> = ((value - 1).max(1)..=value).collect();
+        assert_eq!(simple_storage.get_history(), expected);
+    }
+
+    let mut simple_storage = SimpleStorage::new(0).unwrap();
+    simple_storage.set(1).unwrap();
+    assert_eq!(simple_storage.get(), Some(1));
+    assert_eq!(simple_storage.get_history(), Vec::<u32>::new());
+}
+
+#[ink::test]
+fn test_history_capacity_is_bounded() {
+    assert_eq!(
+        SimpleStorage::new(MAX_HISTORY_CAPACITY + 1).err(),
+        Some(Error::CapacityTooLarge)
+    );
+
+    let mut simple_storage = SimpleStorage::new(MAX_HISTORY_CAPACITY).unwrap();
+    for value in 0..=MAX_HISTORY_CAPACITY {
+        simple_storage.set(value).unwrap();
+    }
+    let expected: Vec<u32> = (1..=MAX_HISTORY_CAPACITY).collect();
+    assert_eq!(simple_storage.get_history(), expected);
+}
@@ -0,0 +1,67 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{event, set_caller};
+use simple_storage::{Error, SimpleStorage, ValueChanged};
+
+/// The default caller, which deploys the contract.
+const OWNER: AccountId = AccountId([0x1; 32]);
+
+#[ink::test]
+fn test_get_and_set() {
+    let mut simple_storage = SimpleStorage::new(3).unwrap();
+    assert_eq!(simple_storage.get(), None);
+    assert_eq!(simple_storage.get_owner(), OWNER);
+
+    let new_value = 42;
+    assert_eq!(simple_storage.set(new_value), Ok(()));
+    assert_eq!(simple_storage.get(), Some(new_value));
+}
+
+#[ink::test]
+fn test_set_unauthorized() {
+    let mut simple_storage = SimpleStorage::new(3).unwrap();
+    simple_storage.set(1).unwrap();
+
+    set_caller(AccountId::from([0x2; 32]));
+    assert_eq!(simple_storage.set(2), Err(Error::Unauthorized));
+    assert_eq!(simple_storage.get(), Some(1));
+    assert_eq!(simple_storage.get_history(), [1]);
+    assert_eq!(leetcoin_test_kit::recorded_events().len(), 1);
+}
+
+#[ink::test]
+fn test_value_changed() {
+    let mut simple_storage = SimpleStorage::new(3).unwrap();
+    simple_storage.set(1).unwrap();
+    simple_storage.set(2).unwrap();
+
+    leetcoin_test_kit::assert_events([
+        event(ValueChanged {
+            old: None,
+            new: 1,
+            by: OWNER,
+        }),
+        event(ValueChanged {
+            old: Some(1),
+            new: 2,
+            by: OWNER,
+        }),
+    ]);
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_topic(&events[1], &OWNER);
+}
+
+#[ink::test]
+fn test_history() {
+    let mut simple_storage = SimpleStorage::new(3).unwrap();
+    assert_eq!(simple_storage.get_history(), Vec::<u32>::new());
+
+    simple_storage.set(1).unwrap();
+    simple_storage.set(2).unwrap();
+    assert_eq!(simple_storage.get_history(), [1, 2]);
+
+    // Once full, the oldest values make room for new ones.
+    for value in 3..=7 {
+        simple_storage.set(value).unwrap();
+    }
+    assert_eq!(simple_storage.get_history(), [5, 6, 7]);
+}
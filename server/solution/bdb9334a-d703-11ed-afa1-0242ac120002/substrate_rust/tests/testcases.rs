@@ -0,0 +1,42 @@
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{Args, Contract, Event, ReplayError, ToValue, Value};
+use simple_storage::{SimpleStorage, ValueChanged};
+
+/// Binds `testcase.txt` to [`SimpleStorage`].
+struct Replay(SimpleStorage);
+
+impl Contract for Replay {
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => SimpleStorage::new(args.u32(0)?)
+                .map(Replay)
+                .map_err(|err| ReplayError::new(format!("`new` failed with {:?}", err))),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "get" => Ok(self.0.get().to_value()),
+            "set" => Ok(self.0.set(args.u32(0)?).to_value()),
+            "get_history" => Ok(self.0.get_history().to_value()),
+            "get_owner" => Ok(self.0.get_owner().to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        let event = decode_event::<ValueChanged>(event)?;
+        Some(
+            Event::new("ValueChanged")
+                .field("old", event.old)
+                .field("new", event.new)
+                .field("by", event.by),
+        )
+    }
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
@@ -0,0 +1,726 @@
+//! Grades a solution crate by running each of its tests on its own.
+//!
+//! Usage: `leetcoin-grader <path/to/Cargo.toml>`
+//!
+//! Only the integration tests in the crate's `tests/` directory are graded, so the
+//! unit tests a submission ships inside `lib.rs` cannot replace or skip them.
+//!
+//! Every test runs in its own process and is killed after `TEST_TIMEOUT`. A test only
+//! passes if it exits successfully and libtest logged it as passed to a file at a new
+//! random path, so a submission cannot pass a test by exiting the process early or by
+//! printing a summary of its own. libtest captures what the test prints, so it cannot
+//! interleave with libtest's output either. The test still runs in the grader's user
+//! and could look the log file up, which only a sandbox around the grader prevents.
+//!
+//! Prints one JSON record per test to stdout. Exits with 0 if every test passed,
+//! 1 if a test failed, 2 if the crate did not compile and 3 if the grader itself
+//! failed.
+//!
+//! Compiler diagnostics are shown to the user, so only those pointing at `lib.rs`
+//! are printed; the tests, hidden ones included, are never quoted.
+//!
+//! The output of the public tests is shown to the user as well, so `tests/hidden.rs`
+//! is moved out of the crate while they are compiled and run, and only put back to
+//! compile and run the hidden tests afterwards. A submission therefore cannot
+//! `include_str!` the hidden tests into a public one. The grader works on the crate in
+//! place, so it should be run on a copy of it.
+
+use std::collections::hash_map::RandomState;
+use std::fs;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{ErrorKind, Read};
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, ExitCode, Output, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// Tests inside a target or module with this name are not shown to the user.
+const HIDDEN: &str = "hidden";
+/// The hidden test target, relative to the crate.
+const HIDDEN_TESTS: &str = "tests/hidden.rs";
+/// The file holding the submitted solution.
+const SOLUTION: &str = "lib.rs";
+/// Shown instead of diagnostics that only point at the tests.
+const TESTS_DO_NOT_COMPILE: &str =
+    "error: the tests do not compile against lib.rs, check the names and types of its messages\n";
+
+/// How long a single test may run before it is killed and counted as failed.
+const TEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// What libtest prints to stderr for every `--logfile`, which is not worth showing.
+const LOGFILE_DEPRECATED: &str = "warning: `--logfile` is deprecated";
+
+const EXIT_FAILED_TESTS: u8 = 1;
+const EXIT_FAILED_TO_COMPILE: u8 = 2;
+const EXIT_GRADER_ERROR: u8 = 3;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CaseResult {
+    id: String,
+    passed: bool,
+    panic_message: Option<String>,
+    /// Wall-clock runtime in milliseconds.
+    runtime: f64,
+    public: bool,
+    raw_output: String,
+}
+
+/// A compiled test executable.
+struct TestTarget {
+    name: String,
+    executable: String,
+}
+
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    target: Option<CargoTarget>,
+    profile: Option<CargoProfile>,
+    executable: Option<String>,
+    message: Option<CompilerMessage>,
+}
+
+#[derive(Deserialize)]
+struct CargoTarget {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct CargoProfile {
+    test: bool,
+}
+
+#[derive(Deserialize)]
+struct CompilerMessage {
+    rendered: Option<String>,
+    #[serde(default)]
+    spans: Vec<DiagnosticSpan>,
+}
+
+#[derive(Deserialize)]
+struct DiagnosticSpan {
+    file_name: String,
+    is_primary: bool,
+}
+
+enum GradeError {
+    Usage,
+    Compile(String),
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for GradeError {
+    fn from(err: std::io::Error) -> Self {
+        GradeError::Io(err)
+    }
+}
+
+fn main() -> ExitCode {
+    let manifest = match std::env::args().nth(1) {
+        Some(manifest) => manifest,
+        None => return report(GradeError::Usage),
+    };
+
+    let cases = match grade(&manifest) {
+        Ok(cases) => cases,
+        Err(err) => return report(err),
+    };
+
+    let mut all_passed = true;
+    for case in cases {
+        all_passed &= case.passed;
+        println!(
+            "{}",
+            serde_json::to_string(&case).expect("case is serializable")
+        );
+    }
+
+    if all_passed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_FAILED_TESTS)
+    }
+}
+
+fn report(err: GradeError) -> ExitCode {
+    match err {
+        GradeError::Usage => {
+            eprintln!("usage: leetcoin-grader <path/to/Cargo.toml>");
+            ExitCode::from(EXIT_GRADER_ERROR)
+        }
+        GradeError::Compile(output) => {
+            eprint!("{}", output);
+            ExitCode::from(EXIT_FAILED_TO_COMPILE)
+        }
+        GradeError::Io(err) => {
+            eprintln!("{}", err);
+            ExitCode::from(EXIT_GRADER_ERROR)
+        }
+    }
+}
+
+/// Runs the public tests of the crate, then its hidden ones.
+fn grade(manifest: &str) -> Result<Vec<CaseResult>, GradeError> {
+    let hidden = HiddenTests::stash(&crate_dir(manifest))?;
+
+    let mut cases = Vec::new();
+    for target in build(manifest, "*")? {
+        cases.extend(run_target(manifest, &target)?);
+    }
+
+    if hidden.restore()? {
+        for target in build(manifest, HIDDEN)? {
+            cases.extend(run_target(manifest, &target)?);
+        }
+    }
+    Ok(cases)
+}
+
+/// Holds `tests/hidden.rs` while it is moved out of the crate, and puts it back when
+/// dropped at the latest.
+struct HiddenTests {
+    path: PathBuf,
+    source: Option<Vec<u8>>,
+}
+
+impl HiddenTests {
+    fn stash(crate_dir: &Path) -> std::io::Result<Self> {
+        let path = crate_dir.join(HIDDEN_TESTS);
+        let source = match fs::read(&path) {
+            Ok(source) => Some(source),
+            Err(err) if err.kind() == ErrorKind::NotFound => None,
+            Err(err) => return Err(err),
+        };
+        if source.is_some() {
+            fs::remove_file(&path)?;
+        }
+        Ok(HiddenTests { path, source })
+    }
+
+    /// Puts the hidden tests back, returning whether the crate has any.
+    fn restore(mut self) -> std::io::Result<bool> {
+        match self.source.take() {
+            // Overwrites whatever a public test may have written in its place.
+            Some(source) => fs::write(&self.path, source).map(|()| true),
+            None => Ok(false),
+        }
+    }
+}
+
+impl Drop for HiddenTests {
+    fn drop(&mut self) {
+        if let Some(source) = self.source.take() {
+            // Nothing is left to report the error to while unwinding.
+            let _ = fs::write(&self.path, source);
+        }
+    }
+}
+
+/// The directory holding `manifest`, which is `.` for a bare `Cargo.toml`.
+fn crate_dir(manifest: &str) -> PathBuf {
+    Path::new(manifest)
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."))
+        .to_path_buf()
+}
+
+/// Compiles the integration test targets of the crate matching `test` without
+/// running them.
+fn build(manifest: &str, test: &str) -> Result<Vec<TestTarget>, GradeError> {
+    let output = Command::new("cargo")
+        .args([
+            "test",
+            "--no-run",
+            "--locked",
+            "--test",
+            test,
+            "--message-format=json",
+            "--manifest-path",
+            manifest,
+        ])
+        .output()?;
+
+    let mut targets = Vec::new();
+    let mut diagnostics = String::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let message: CargoMessage = match serde_json::from_str(line) {
+            Ok(message) => message,
+            Err(_) => continue,
+        };
+        match message.reason.as_str() {
+            "compiler-artifact" => {
+                let is_test = message.profile.map(|profile| profile.test).unwrap_or(false);
+                if let (true, Some(target), Some(executable)) =
+                    (is_test, message.target, message.executable)
+                {
+                    targets.push(TestTarget {
+                        name: target.name,
+                        executable,
+                    });
+                }
+            }
+            "compiler-message" => {
+                if let Some(rendered) = message.message.and_then(solution_diagnostic) {
+                    diagnostics.push_str(&rendered);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if !output.status.success() {
+        // cargo's own stderr names the failing targets, so it is not passed on
+        if diagnostics.is_empty() {
+            diagnostics.push_str(TESTS_DO_NOT_COMPILE);
+        }
+        return Err(GradeError::Compile(diagnostics));
+    }
+    Ok(targets)
+}
+
+/// Returns the rendered diagnostic if it points at the solution and nowhere else.
+fn solution_diagnostic(message: CompilerMessage) -> Option<String> {
+    let points_at_solution = message
+        .spans
+        .iter()
+        .any(|span| span.is_primary && is_solution(&span.file_name));
+    let points_at_tests = message
+        .spans
+        .iter()
+        .any(|span| !is_solution(&span.file_name));
+    if points_at_solution && !points_at_tests {
+        message.rendered
+    } else {
+        None
+    }
+}
+
+fn is_solution(file_name: &str) -> bool {
+    let path = Path::new(file_name);
+    path.file_name().is_some_and(|name| name == SOLUTION)
+        && !path
+            .components()
+            .any(|component| component == Component::Normal("tests".as_ref()))
+}
+
+/// Runs every test of `target` in its own process so each one can be timed.
+fn run_target(manifest: &str, target: &TestTarget) -> Result<Vec<CaseResult>, GradeError> {
+    let crate_dir = crate_dir(manifest);
+    let listing = Command::new(&target.executable)
+        .args(["--list", "--format", "terse"])
+        .current_dir(&crate_dir)
+        .output()?;
+
+    let mut cases = Vec::new();
+    for name in parse_test_list(&String::from_utf8_lossy(&listing.stdout)) {
+        let log = log_path();
+        let start = Instant::now();
+        let output = output_with_timeout(
+            Command::new(&target.executable)
+                .args(["--exact", &name, "--test-threads=1", "--logfile"])
+                .arg(&log)
+                .env("RUST_BACKTRACE", "0")
+                .current_dir(&crate_dir),
+            TEST_TIMEOUT,
+        );
+        let runtime = start.elapsed().as_secs_f64() * 1000.0;
+        let logged = fs::read_to_string(&log).unwrap_or_default();
+        // Missing when the test exited before libtest created it.
+        let _ = fs::remove_file(&log);
+        let output = output?;
+
+        let id = format!("{}::{}", target.name, name);
+        let public = is_public(&target.name, &name);
+        let Some(output) = output else {
+            cases.push(CaseResult {
+                id,
+                passed: false,
+                panic_message: Some(format!(
+                    "timed out after {} seconds",
+                    TEST_TIMEOUT.as_secs()
+                )),
+                runtime,
+                public,
+                raw_output: String::new(),
+            });
+            continue;
+        };
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr: String = String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .filter(|line| *line != LOGFILE_DEPRECATED)
+            .map(|line| format!("{}\n", line))
+            .collect();
+        let raw_output = format!("{}{}", stdout, stderr);
+        let passed = output.status.success() && logged_passed(&logged, &name);
+        let panic_message = if passed {
+            None
+        } else if output.status.success() {
+            Some("the test exited before libtest reported a result".to_string())
+        } else {
+            // libtest prints the captured panic along with the test's other output.
+            parse_panic_message(&raw_output)
+        };
+        cases.push(CaseResult {
+            id,
+            passed,
+            panic_message,
+            runtime,
+            public,
+            raw_output,
+        });
+    }
+    Ok(cases)
+}
+
+/// Runs `command` to completion, killing it once `timeout` elapsed. Returns `None` if
+/// it was killed.
+fn output_with_timeout(
+    command: &mut Command,
+    timeout: Duration,
+) -> std::io::Result<Option<Output>> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    // Drained on their own threads, so a test filling a pipe cannot block.
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            // Processes the test spawned may still hold the pipes open, so the
+            // readers are left behind rather than joined.
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    Ok(Some(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    }))
+}
+
+fn drain(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            // A read error only cuts the output short.
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        bytes
+    })
+}
+
+/// A path no other run uses for libtest's `--logfile`, which is not known before
+/// the test runs.
+fn log_path() -> PathBuf {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(Instant::now().elapsed().as_nanos());
+    std::env::temp_dir().join(format!(
+        "leetcoin-grader-{}-{:016x}.log",
+        std::process::id(),
+        hasher.finish()
+    ))
+}
+
+/// Whether libtest logged `name` as the one test it ran, and as passed.
+fn logged_passed(log: &str, name: &str) -> bool {
+    log.lines().collect::<Vec<_>>() == [format!("ok {}", name)]
+}
+
+/// Extracts the test names from the output of `--list --format terse`.
+fn parse_test_list(listing: &str) -> Vec<String> {
+    listing
+        .lines()
+        .filter_map(|line| line.strip_suffix(": test"))
+        .map(str::to_string)
+        .collect()
+}
+
+fn is_public(target: &str, test: &str) -> bool {
+    target != HIDDEN && !test.split("::").any(|segment| segment == HIDDEN)
+}
+
+/// Extracts the message of the first panic from a test's output.
+fn parse_panic_message(output: &str) -> Option<String> {
+    let mut lines = output.lines();
+    let header = lines.find(|line| line.starts_with("thread '") && line.contains("panicked at"))?;
+
+    // Before Rust 1.73 the message was quoted on the same line:
+    // thread 'name' panicked at 'message', src/lib.rs:1:1
+    if let Some((_, rest)) = header.split_once("panicked at '") {
+        return rest
+            .rsplit_once("', ")
+            .map(|(message, _)| message.to_string());
+    }
+
+    // thread 'name' panicked at src/lib.rs:1:1:
+    // message
+    // libtest follows the captured output with its list of failures.
+    let message: Vec<&str> = lines
+        .take_while(|line| {
+            !line.starts_with("note:")
+                && !line.starts_with("stack backtrace:")
+                && *line != "failures:"
+        })
+        .collect();
+    Some(message.join("\n").trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_test_list() {
+        let listing =
+            "simple_token::tests::test_mint: test\nsimple_token::tests::test_transfer: test\n";
+        assert_eq!(
+            parse_test_list(listing),
+            vec![
+                "simple_token::tests::test_mint",
+                "simple_token::tests::test_transfer"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_public() {
+        assert!(is_public("simple_token", "simple_token::tests::test_mint"));
+        assert!(!is_public("hidden", "test_insufficient_balance_transfer"));
+        assert!(!is_public(
+            "simple_token",
+            "tests::hidden::test_insufficient_balance_transfer"
+        ));
+    }
+
+    fn compiler_message(spans: &[(&str, bool)]) -> CompilerMessage {
+        CompilerMessage {
+            rendered: Some("error[E0308]: mismatched types\n".to_string()),
+            spans: spans
+                .iter()
+                .map(|(file_name, is_primary)| DiagnosticSpan {
+                    file_name: file_name.to_string(),
+                    is_primary: *is_primary,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_solution_diagnostic() {
+        let in_solution = compiler_message(&[("simple_token/substrate_rust/lib.rs", true)]);
+        assert!(solution_diagnostic(in_solution).is_some());
+
+        for spans in [
+            &[("simple_token/substrate_rust/tests/hidden.rs", true)][..],
+            &[
+                ("simple_token/substrate_rust/lib.rs", true),
+                ("simple_token/substrate_rust/tests/hidden.rs", false),
+            ],
+            &[("simple_token/substrate_rust/tests/lib.rs", true)],
+            &[],
+        ] {
+            assert!(solution_diagnostic(compiler_message(spans)).is_none());
+        }
+    }
+
+    #[test]
+    fn test_logged_passed() {
+        assert!(logged_passed("ok tests::test_mint\n", "tests::test_mint"));
+        // A submission exiting early leaves the log empty.
+        assert!(!logged_passed("", "tests::test_mint"));
+        assert!(!logged_passed(
+            "failed tests::test_mint\n",
+            "tests::test_mint"
+        ));
+        assert!(!logged_passed("ok tests::test_burn\n", "tests::test_mint"));
+        assert!(!logged_passed(
+            "ok tests::test_mint\nok tests::test_mint\n",
+            "tests::test_mint"
+        ));
+    }
+
+    #[test]
+    fn test_log_path() {
+        assert_ne!(log_path(), log_path());
+    }
+
+    #[test]
+    fn test_output_with_timeout() {
+        let output = output_with_timeout(
+            Command::new("sh").args(["-c", "echo done"]),
+            Duration::from_secs(10),
+        )
+        .unwrap()
+        .expect("finished in time");
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"done\n");
+
+        let start = Instant::now();
+        let output =
+            output_with_timeout(Command::new("sleep").arg("10"), Duration::from_millis(100))
+                .unwrap();
+        assert!(output.is_none());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_crate_dir() {
+        assert_eq!(crate_dir("Cargo.toml"), Path::new("."));
+        assert_eq!(
+            crate_dir("escrow/substrate_rust/Cargo.toml"),
+            Path::new("escrow/substrate_rust")
+        );
+    }
+
+    #[test]
+    fn test_hidden_tests_are_stashed() {
+        let crate_dir =
+            std::env::temp_dir().join(format!("leetcoin-grader-{}", std::process::id()));
+        let path = crate_dir.join(HIDDEN_TESTS);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "#[test]\nfn test_hidden() {}\n").unwrap();
+
+        let hidden = HiddenTests::stash(&crate_dir).unwrap();
+        assert!(!path.exists());
+        fs::write(&path, "written by a public test").unwrap();
+        assert!(hidden.restore().unwrap());
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "#[test]\nfn test_hidden() {}\n"
+        );
+
+        // Dropping the stash puts the tests back as well.
+        drop(HiddenTests::stash(&crate_dir).unwrap());
+        assert!(path.exists());
+
+        fs::remove_dir_all(&crate_dir).unwrap();
+        assert!(!HiddenTests::stash(&crate_dir).unwrap().restore().unwrap());
+    }
+
+    #[test]
+    fn test_parse_panic_message() {
+        let stderr = "thread 'tests::test_vote_twice' panicked at lib.rs:10:5:\nassertion `left == right` failed\n  left: 2\n right: 1\nnote: run with `RUST_BACKTRACE=1`";
+        assert_eq!(
+            parse_panic_message(stderr).as_deref(),
+            Some("assertion `left == right` failed\n  left: 2\n right: 1")
+        );
+    }
+
+    #[test]
+    fn test_parse_legacy_panic_message() {
+        let stderr = "thread 'tests::test_transfer' panicked at 'no transfer event', lib.rs:10:5\n";
+        assert_eq!(
+            parse_panic_message(stderr).as_deref(),
+            Some("no transfer event")
+        );
+    }
+
+    #[test]
+    fn test_parse_panic_message_without_panic() {
+        assert_eq!(parse_panic_message("running 1 test\n"), None);
+    }
+
+    #[test]
+    fn test_parse_captured_panic_message() {
+        let output = "running 1 test\ntest test_vote ... FAILED\n\nfailures:\n\n---- test_vote stdout ----\n\nthread 'test_vote' (7) panicked at tests/public.rs:12:5:\nno vote event\n\n\nfailures:\n    test_vote\n";
+        assert_eq!(
+            parse_panic_message(output).as_deref(),
+            Some("no vote event")
+        );
+    }
+
+    /// Writes a crate without dependencies whose public tests are `tests` and grades it.
+    fn grade_crate(name: &str, tests: &str) -> Vec<CaseResult> {
+        let crate_dir =
+            std::env::temp_dir().join(format!("leetcoin-grader-{}-{}", name, std::process::id()));
+        fs::create_dir_all(crate_dir.join("tests")).unwrap();
+        fs::write(
+            crate_dir.join("Cargo.toml"),
+            format!(
+                "[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[lib]\npath = \"lib.rs\"\n"
+            ),
+        )
+        .unwrap();
+        fs::write(
+            crate_dir.join("Cargo.lock"),
+            format!("version = 3\n\n[[package]]\nname = \"{name}\"\nversion = \"0.1.0\"\n"),
+        )
+        .unwrap();
+        fs::write(
+            crate_dir.join(SOLUTION),
+            "pub fn answer() -> u32 {\n    42\n}\n",
+        )
+        .unwrap();
+        fs::write(crate_dir.join("tests/public.rs"), tests).unwrap();
+
+        let manifest = crate_dir.join("Cargo.toml");
+        let cases = grade(manifest.to_str().unwrap());
+        fs::remove_dir_all(&crate_dir).unwrap();
+        cases.unwrap_or_else(|_| panic!("could not grade {}", name))
+    }
+
+    #[test]
+    fn test_fake_summary_fails() {
+        let cases = grade_crate(
+            "fake_summary",
+            r#"
+use std::io::Write;
+
+#[test]
+fn test_fake() {
+    let summary = "\ntest result: ok. 1 passed; 0 failed; 0 ignored\n";
+    print!("{}", summary);
+    let mut stdout = std::io::stdout();
+    stdout.write_all(summary.as_bytes()).unwrap();
+    stdout.flush().unwrap();
+    std::process::exit(0);
+}
+
+#[test]
+fn test_answer() {
+    assert_eq!(fake_summary::answer(), 42, "wrong answer");
+}
+
+#[test]
+fn test_wrong_answer() {
+    assert_eq!(fake_summary::answer(), 41, "wrong answer");
+}
+"#,
+        );
+        let results: Vec<(&str, bool)> = cases
+            .iter()
+            .map(|case| (case.id.as_str(), case.passed))
+            .collect();
+        assert_eq!(
+            results,
+            [
+                ("public::test_answer", true),
+                ("public::test_fake", false),
+                ("public::test_wrong_answer", false),
+            ]
+        );
+        assert_eq!(
+            cases[1].panic_message.as_deref(),
+            Some("the test exited before libtest reported a result")
+        );
+        assert!(cases[2].panic_message.as_deref().is_some_and(
+            |message| message.starts_with("assertion `left == right` failed: wrong answer")
+        ));
+        assert!(!cases[2].raw_output.contains(LOGFILE_DEPRECATED));
+    }
+}
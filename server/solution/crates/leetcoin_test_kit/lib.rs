@@ -0,0 +1,250 @@
+//! Test support shared by the reference solution crates.
+//!
+//! ink! records every emitted event in the off-chain environment as the SCALE encoded
+//! event struct plus a list of 32 byte topics, the first of which is the event's
+//! signature topic. These helpers use the signature topic to decode the blobs back
+//! into the contract's event structs and check topics the same way ink! computes them.
+//!
+//! The off-chain environment helpers wrap `ink::env::test` for the default
+//! environment, so tests do not have to name it on every call.
+
+use ink::env::hash::{Blake2x256, CryptoHash, HashOutput};
+use ink::env::{test, DefaultEnvironment as Environment, Event};
+use ink::primitives::AccountId;
+use scale::{Decode, Encode};
+
+pub use ink::env::test::EmittedEvent;
+
+/// Length of a topic in the default environment.
+const TOPIC_LEN: usize = 32;
+
+/// An event a test expects to be emitted, see [`event`].
+pub struct ExpectedEvent {
+    name: &'static str,
+    signature: Option<[u8; TOPIC_LEN]>,
+    data: Vec<u8>,
+}
+
+/// Wraps an event struct so events of different types can be expected in one list.
+pub fn event<E: Event>(event: E) -> ExpectedEvent {
+    ExpectedEvent {
+        name: short_type_name::<E>(),
+        signature: E::SIGNATURE_TOPIC,
+        data: event.encode(),
+    }
+}
+
+/// Runs `test` in a fresh off-chain environment, as `#[ink::test]` does, for test
+/// bodies that are not functions of their own such as proptest cases.
+pub fn off_chain(test: impl FnOnce()) {
+    test::run_test::<Environment, _>(|_| {
+        test();
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Makes `caller` the caller of the following calls.
+pub fn set_caller(caller: AccountId) {
+    test::set_caller::<Environment>(caller);
+}
+
+/// Returns the account of the contract under test.
+pub fn callee() -> AccountId {
+    test::callee::<Environment>()
+}
+
+/// Deploys the following contracts at `callee`, which is alice's account unless set.
+pub fn set_callee(callee: AccountId) {
+    test::set_callee::<Environment>(callee);
+}
+
+/// Returns the accounts the off-chain environment starts with.
+pub fn default_accounts() -> test::DefaultAccounts<Environment> {
+    test::default_accounts::<Environment>()
+}
+
+/// Sets the number of the current block.
+pub fn set_block_number(number: u32) {
+    test::set_block_number::<Environment>(number);
+}
+
+/// Sets the timestamp of the current block, in milliseconds.
+pub fn set_block_timestamp(timestamp: u64) {
+    test::set_block_timestamp::<Environment>(timestamp);
+}
+
+/// Returns the native balance of `account`, 0 for an account that never held any.
+pub fn balance_of(account: AccountId) -> u128 {
+    test::get_account_balance::<Environment>(account).unwrap_or(0)
+}
+
+/// Sets the native balance of `account`.
+pub fn set_balance(account: AccountId, balance: u128) {
+    test::set_account_balance::<Environment>(account, balance);
+}
+
+/// Moves `value` from the caller to the contract, as a payable call does.
+pub fn transfer_in(value: u128) {
+    test::transfer_in::<Environment>(value);
+}
+
+/// Sets the value the next call receives without moving any balance.
+pub fn set_value_transferred(value: u128) {
+    test::set_value_transferred::<Environment>(value);
+}
+
+/// Returns every event recorded so far.
+pub fn recorded_events() -> Vec<EmittedEvent> {
+    test::recorded_events().collect()
+}
+
+/// Decodes a recorded event into `E`, or returns `None` if it is a different event.
+pub fn decode_event<E: Event + Decode>(event: &EmittedEvent) -> Option<E> {
+    if !has_signature::<E>(event) {
+        return None;
+    }
+    let decoded = <E as Decode>::decode(&mut &event.data[..]).unwrap_or_else(|err| {
+        panic!(
+            "could not decode {} {}: {}",
+            short_type_name::<E>(),
+            to_hex(&event.data),
+            err
+        )
+    });
+    Some(decoded)
+}
+
+/// Decodes every recorded event of type `E`, skipping events of other types.
+pub fn decode_events<E: Event + Decode>() -> Vec<E> {
+    recorded_events().iter().filter_map(decode_event).collect()
+}
+
+/// Asserts that exactly `expected` were emitted, in this order.
+///
+/// SCALE encoding is canonical, so events are compared by their signature topic and
+/// encoded form and the event types do not need to implement `PartialEq` or `Debug`.
+pub fn assert_events(expected: impl IntoIterator<Item = ExpectedEvent>) {
+    let expected: Vec<ExpectedEvent> = expected.into_iter().collect();
+    let emitted = recorded_events();
+    assert_eq!(
+        emitted.len(),
+        expected.len(),
+        "expected {} events but {} were emitted",
+        expected.len(),
+        emitted.len()
+    );
+    for (n, (emitted, expected)) in emitted.iter().zip(&expected).enumerate() {
+        assert!(
+            emitted.topics.first().map(Vec::as_slice)
+                == expected.signature.as_ref().map(|s| &s[..]),
+            "event #{} is not a {}",
+            n,
+            expected.name
+        );
+        assert!(
+            emitted.data == expected.data,
+            "event #{} differs: expected {} {} but got {}",
+            n,
+            expected.name,
+            to_hex(&expected.data),
+            to_hex(&emitted.data)
+        );
+    }
+}
+
+/// Asserts that no event was emitted.
+pub fn assert_no_events() {
+    assert_events([]);
+}
+
+/// Computes the topic ink! records for an `#[ink(topic)]` field holding `value`.
+///
+/// For `Option` fields ink! records the topic of the inner value, so pass `&to`
+/// rather than `&Some(to)`.
+pub fn topic<V: Encode>(value: &V) -> Vec<u8> {
+    let encoded = value.encode();
+    let mut topic = vec![0; TOPIC_LEN];
+    if encoded.len() <= TOPIC_LEN {
+        topic[..encoded.len()].copy_from_slice(&encoded);
+    } else {
+        let mut hash = <Blake2x256 as HashOutput>::Type::default();
+        <Blake2x256 as CryptoHash>::hash(&encoded, &mut hash);
+        topic.copy_from_slice(&hash[..TOPIC_LEN]);
+    }
+    topic
+}
+
+/// Asserts that `event` carries the topic for `value`.
+pub fn assert_topic<V: Encode>(event: &EmittedEvent, value: &V) {
+    let expected = topic(value);
+    assert!(
+        event.topics.iter().skip(1).any(|topic| *topic == expected),
+        "topic {} was not set, event topics: {:?}",
+        to_hex(&expected),
+        event
+            .topics
+            .iter()
+            .map(|topic| to_hex(topic))
+            .collect::<Vec<_>>()
+    );
+}
+
+/// Asserts that `event` is an `E`.
+pub fn assert_signature<E: Event>(event: &EmittedEvent) {
+    assert!(
+        has_signature::<E>(event),
+        "event is not a {}",
+        short_type_name::<E>()
+    );
+}
+
+fn has_signature<E: Event>(event: &EmittedEvent) -> bool {
+    match E::SIGNATURE_TOPIC {
+        Some(signature) => event.topics.first().map(Vec::as_slice) == Some(&signature[..]),
+        None => false,
+    }
+}
+
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::from("0x");
+    for byte in bytes {
+        hex.push_str(&format!("{:02x}", byte));
+    }
+    hex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_topic_is_padded() {
+        let topic = topic(&1u8);
+        assert_eq!(topic[0], 1);
+        assert!(topic[1..].iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn test_long_topic_is_hashed() {
+        let value = Some([0x1u8; 32]);
+        let mut hash = <Blake2x256 as HashOutput>::Type::default();
+        <Blake2x256 as CryptoHash>::hash(&value.encode(), &mut hash);
+        assert_eq!(topic(&value), hash.to_vec());
+    }
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name::<ExpectedEvent>(), "ExpectedEvent");
+    }
+
+    #[test]
+    fn test_to_hex() {
+        assert_eq!(to_hex(&[0x0, 0xab]), "0x00ab");
+    }
+}
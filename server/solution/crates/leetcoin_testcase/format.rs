@@ -0,0 +1,347 @@
+//! Parser for the `testcase.txt` format.
+//!
+//! Every non-empty line that does not start with `#` is one JSON record:
+//!
+//! ```text
+//! # deploys the contract, optionally funding it with `endowment`
+//! {"scenario": "mint_then_transfer", "constructor": "new", "args": [], "caller": "alice"}
+//! # calls a message, `returns` and `events` are only checked when present
+//! {"call": "mint", "args": ["bob", 100], "caller": "alice", "events": [{"Transfer": {"from": null, "to": "bob", "value": 100}}]}
+//! {"call": "transfer", "args": ["alice", 30], "caller": "bob", "returns": true}
+//! # queries a message without recording it as a step of the scenario, called by
+//! # alice (or `caller`) without value, whatever the previous call used
+//! {"storage": "balance_of", "args": ["alice"], "equals": 30}
+//! {"storage": "allowance", "args": ["alice", "bob"], "caller": "bob", "equals": 0}
+//! # checks the native balance of an account
+//! {"balance": "contract", "equals": 0}
+//! # sets the current block number and/or timestamp (in milliseconds)
+//! {"block": {"number": 10, "timestamp": 86400000}}
+//! ```
+//!
+//! Accounts are referred to by the names of the off-chain default accounts plus
+//! `contract` for the contract under test.
+
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+use crate::value::ToValue;
+use crate::ReplayError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scenario {
+    pub name: String,
+    pub line: usize,
+    pub constructor: Call,
+    /// Native balance the contract account starts with, either 0 or at least the
+    /// existential deposit of the off-chain environment (1_000_000).
+    pub endowment: Option<u128>,
+    pub steps: Vec<Step>,
+}
+
+/// A constructor or message call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    pub name: String,
+    pub args: Vec<Value>,
+    pub caller: Option<String>,
+    /// Value transferred with a payable call, handed back to the caller when the call
+    /// returns `Err` as a reverted call would on chain.
+    pub value: Option<u128>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    Call {
+        line: usize,
+        call: Call,
+        returns: Option<Value>,
+        events: Option<Vec<Event>>,
+    },
+    Storage {
+        line: usize,
+        query: Call,
+        equals: Value,
+    },
+    Balance {
+        line: usize,
+        account: String,
+        equals: u128,
+    },
+    Block {
+        line: usize,
+        number: Option<u32>,
+        timestamp: Option<u64>,
+    },
+}
+
+impl Step {
+    pub fn line(&self) -> usize {
+        match self {
+            Step::Call { line, .. }
+            | Step::Storage { line, .. }
+            | Step::Balance { line, .. }
+            | Step::Block { line, .. } => *line,
+        }
+    }
+}
+
+/// An event with its fields, written as `{"Name": {"field": value}}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub name: String,
+    pub fields: Map<String, Value>,
+}
+
+impl Event {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            fields: Map::new(),
+        }
+    }
+
+    pub fn field(mut self, name: &str, value: impl ToValue) -> Self {
+        self.fields.insert(name.to_string(), value.to_value());
+        self
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let map = Map::<String, Value>::deserialize(deserializer)?;
+        let mut entries = map.into_iter();
+        match (entries.next(), entries.next()) {
+            (Some((name, Value::Object(fields))), None) => Ok(Event { name, fields }),
+            (Some((name, Value::Null)), None) => Ok(Event {
+                name,
+                fields: Map::new(),
+            }),
+            _ => Err(serde::de::Error::custom(
+                "an event must be written as {\"Name\": {fields}}",
+            )),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScenarioRecord {
+    scenario: String,
+    constructor: String,
+    #[serde(default)]
+    args: Vec<Value>,
+    caller: Option<String>,
+    value: Option<u128>,
+    endowment: Option<u128>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CallRecord {
+    call: String,
+    #[serde(default)]
+    args: Vec<Value>,
+    caller: Option<String>,
+    value: Option<u128>,
+    #[serde(default, deserialize_with = "present")]
+    returns: Option<Value>,
+    events: Option<Vec<Event>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StorageRecord {
+    storage: String,
+    #[serde(default)]
+    args: Vec<Value>,
+    caller: Option<String>,
+    equals: Value,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BalanceRecord {
+    balance: String,
+    equals: u128,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BlockRecord {
+    block: BlockFields,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BlockFields {
+    number: Option<u32>,
+    timestamp: Option<u64>,
+}
+
+/// Distinguishes `"returns": null` from a missing `returns`.
+fn present<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Value>, D::Error> {
+    Value::deserialize(deserializer).map(Some)
+}
+
+/// Parses every scenario in `source`.
+pub fn parse(source: &str) -> Result<Vec<Scenario>, ReplayError> {
+    let mut scenarios: Vec<Scenario> = Vec::new();
+    for (index, text) in source.lines().enumerate() {
+        let line = index + 1;
+        let text = text.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+
+        let record: Map<String, Value> = serde_json::from_str(text)
+            .map_err(|err| ReplayError::new(format!("line {}: {}", line, err)))?;
+        let parse_error =
+            |err: serde_json::Error| ReplayError::new(format!("line {}: {}", line, err));
+        let record = Value::Object(record);
+
+        if record.get("scenario").is_some() {
+            let record: ScenarioRecord = serde_json::from_value(record).map_err(parse_error)?;
+            scenarios.push(Scenario {
+                name: record.scenario,
+                line,
+                constructor: Call {
+                    name: record.constructor,
+                    args: record.args,
+                    caller: record.caller,
+                    value: record.value,
+                },
+                endowment: record.endowment,
+                steps: Vec::new(),
+            });
+            continue;
+        }
+
+        let step = if record.get("call").is_some() {
+            let record: CallRecord = serde_json::from_value(record).map_err(parse_error)?;
+            Step::Call {
+                line,
+                call: Call {
+                    name: record.call,
+                    args: record.args,
+                    caller: record.caller,
+                    value: record.value,
+                },
+                returns: record.returns,
+                events: record.events,
+            }
+        } else if record.get("storage").is_some() {
+            let record: StorageRecord = serde_json::from_value(record).map_err(parse_error)?;
+            Step::Storage {
+                line,
+                query: Call {
+                    name: record.storage,
+                    args: record.args,
+                    caller: record.caller,
+                    value: None,
+                },
+                equals: record.equals,
+            }
+        } else if record.get("balance").is_some() {
+            let record: BalanceRecord = serde_json::from_value(record).map_err(parse_error)?;
+            Step::Balance {
+                line,
+                account: record.balance,
+                equals: record.equals,
+            }
+        } else if record.get("block").is_some() {
+            let record: BlockRecord = serde_json::from_value(record).map_err(parse_error)?;
+            Step::Block {
+                line,
+                number: record.block.number,
+                timestamp: record.block.timestamp,
+            }
+        } else {
+            return Err(ReplayError::new(format!(
+                "line {}: expected a `scenario`, `call`, `storage`, `balance` or `block` record",
+                line
+            )));
+        };
+
+        match scenarios.last_mut() {
+            Some(scenario) => scenario.steps.push(step),
+            None => {
+                return Err(ReplayError::new(format!(
+                    "line {}: steps must follow a `scenario` record",
+                    line
+                )))
+            }
+        }
+    }
+    Ok(scenarios)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_scenario() {
+        let source = r#"
+# a comment
+{"scenario": "mint", "constructor": "new", "endowment": 10}
+{"call": "mint", "args": ["bob", 100], "caller": "alice", "events": [{"Transfer": {"to": "bob"}}]}
+{"call": "transfer", "args": ["alice", 1], "returns": null}
+{"storage": "balance_of", "args": ["bob"], "equals": 100}
+{"balance": "contract", "equals": 10}
+{"block": {"timestamp": 5}}
+"#;
+        let scenarios = parse(source).unwrap();
+        assert_eq!(scenarios.len(), 1);
+        let scenario = &scenarios[0];
+        assert_eq!(scenario.name, "mint");
+        assert_eq!(scenario.line, 3);
+        assert_eq!(scenario.endowment, Some(10));
+        assert_eq!(scenario.steps.len(), 5);
+
+        match &scenario.steps[0] {
+            Step::Call {
+                call,
+                returns,
+                events,
+                ..
+            } => {
+                assert_eq!(call.name, "mint");
+                assert_eq!(call.caller.as_deref(), Some("alice"));
+                assert_eq!(returns, &None);
+                let events = events.as_ref().unwrap();
+                assert_eq!(events[0].name, "Transfer");
+                assert_eq!(events[0].fields["to"], json!("bob"));
+            }
+            step => panic!("unexpected step {:?}", step),
+        }
+        match &scenario.steps[1] {
+            Step::Call { returns, .. } => assert_eq!(returns, &Some(Value::Null)),
+            step => panic!("unexpected step {:?}", step),
+        }
+        assert_eq!(scenario.steps[3].line(), 7);
+        assert_eq!(
+            scenario.steps[4],
+            Step::Block {
+                line: 8,
+                number: None,
+                timestamp: Some(5)
+            }
+        );
+    }
+
+    #[test]
+    fn test_step_without_scenario() {
+        let err = parse(r#"{"call": "get"}"#).unwrap_err();
+        assert!(err.to_string().contains("must follow a `scenario`"));
+    }
+
+    #[test]
+    fn test_unknown_field() {
+        let err = parse(
+            "{\"scenario\": \"s\", \"constructor\": \"new\"}\n{\"call\": \"get\", \"return\": 1}",
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("line 2:"));
+    }
+}
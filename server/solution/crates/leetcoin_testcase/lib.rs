@@ -0,0 +1,347 @@
+//! Interpreter for the `testcase.txt` file shipped with each question.
+//!
+//! A `testcase.txt` lists scenarios of constructor and message calls together with
+//! the values, events and balances they are expected to produce, and may move the
+//! block number and timestamp between calls (see [`format`]).
+//! A solution crate implements [`Contract`] to map the names used in the file onto
+//! its constructors, messages and events, and replays the file from a test:
+//!
+//! ```ignore
+//! #[test]
+//! fn testcases() {
+//!     leetcoin_testcase::assert_replay::<SimpleToken>(include_str!("../testcase.txt"));
+//! }
+//! ```
+
+pub mod format;
+pub mod value;
+
+use std::cell::Cell;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use ink::env::test::{self, EmittedEvent};
+use ink::env::DefaultEnvironment;
+
+pub use self::format::{Event, Scenario, Step};
+pub use self::value::{Args, ToValue};
+pub use serde_json::{json, Value};
+
+/// Error raised while parsing or replaying a `testcase.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayError(String);
+
+impl ReplayError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+
+    /// The contract has no constructor or message with this name.
+    pub fn unknown(kind: &str, name: &str) -> Self {
+        Self(format!("unknown {} `{}`", kind, name))
+    }
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// Binds the names used in `testcase.txt` to a contract.
+pub trait Contract: Sized {
+    /// Runs the constructor called `constructor`.
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError>;
+
+    /// Calls the message called `message` and returns its result.
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError>;
+
+    /// Decodes an emitted event into its name and fields, usually by trying each of
+    /// the contract's events with [`leetcoin_test_kit::decode_event`].
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        let _ = event;
+        None
+    }
+}
+
+/// A step of a scenario whose outcome did not match `testcase.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub scenario: String,
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "scenario `{}`, line {}: {}",
+            self.scenario, self.line, self.message
+        )
+    }
+}
+
+/// Replays every scenario in `source` and returns the steps that failed.
+///
+/// A scenario stops at its first failing step; the remaining scenarios still run.
+pub fn replay<C: Contract>(source: &str) -> Result<Vec<Failure>, ReplayError> {
+    let scenarios = format::parse(source)?;
+    Ok(scenarios
+        .iter()
+        .filter_map(|scenario| replay_scenario::<C>(scenario).err())
+        .collect())
+}
+
+/// Replays every scenario in `source` and panics if any of them fails.
+pub fn assert_replay<C: Contract>(source: &str) {
+    let failures = replay::<C>(source).unwrap_or_else(|err| panic!("invalid testcase: {}", err));
+    if !failures.is_empty() {
+        let report: Vec<String> = failures.iter().map(ToString::to_string).collect();
+        panic!(
+            "{} scenarios failed:\n{}",
+            failures.len(),
+            report.join("\n")
+        );
+    }
+}
+
+fn replay_scenario<C: Contract>(scenario: &Scenario) -> Result<(), Failure> {
+    let line = Cell::new(scenario.line);
+    let fail = |line: usize, message: String| Failure {
+        scenario: scenario.name.clone(),
+        line,
+        message,
+    };
+
+    let mut outcome = Ok(());
+    let replayed = panic::catch_unwind(AssertUnwindSafe(|| {
+        test::run_test::<DefaultEnvironment, _>(|_| {
+            outcome = run_steps::<C>(scenario, &line).map_err(|err| fail(line.get(), err.0));
+            Ok(())
+        })
+    }));
+
+    match replayed {
+        Ok(Ok(())) => outcome,
+        Ok(Err(err)) => Err(fail(line.get(), format!("{:?}", err))),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "contract panicked".to_string());
+            Err(fail(line.get(), format!("panicked: {}", message)))
+        }
+    }
+}
+
+/// Runs the scenario inside the off-chain environment, keeping `line` on the
+/// step being replayed so a panic can be attributed to it.
+fn run_steps<C: Contract>(scenario: &Scenario, line: &Cell<usize>) -> Result<(), ReplayError> {
+    let contract_account = value::account_id(value::CONTRACT)?;
+    test::set_callee::<DefaultEnvironment>(contract_account);
+    test::set_account_balance::<DefaultEnvironment>(
+        contract_account,
+        scenario.endowment.unwrap_or(0),
+    );
+
+    let constructor = &scenario.constructor;
+    prepare_call(constructor)?;
+    let mut contract = C::construct(
+        &constructor.name,
+        &Args::new(&constructor.name, &constructor.args),
+    )?;
+
+    for step in &scenario.steps {
+        line.set(step.line());
+        match step {
+            Step::Call {
+                call,
+                returns,
+                events,
+                ..
+            } => {
+                let emitted_before = test::recorded_events().count();
+                prepare_call(call)?;
+                let returned = contract.call(&call.name, &Args::new(&call.name, &call.args))?;
+                if is_err(&returned) {
+                    revert_value(call)?;
+                }
+                if let Some(expected) = returns {
+                    check_value(&call.name, expected, &returned)?;
+                }
+                if let Some(expected) = events {
+                    let emitted: Vec<EmittedEvent> =
+                        test::recorded_events().skip(emitted_before).collect();
+                    check_events::<C>(expected, &emitted)?;
+                }
+            }
+            Step::Storage { query, equals, .. } => {
+                prepare_call(query)?;
+                let returned = contract.call(&query.name, &Args::new(&query.name, &query.args))?;
+                check_value(&query.name, equals, &returned)?;
+            }
+            Step::Balance {
+                account, equals, ..
+            } => {
+                let balance =
+                    test::get_account_balance::<DefaultEnvironment>(value::account_id(account)?)
+                        .unwrap_or(0);
+                if balance != *equals {
+                    return Err(ReplayError::new(format!(
+                        "balance of `{}` is {} but {} was expected",
+                        account, balance, equals
+                    )));
+                }
+            }
+            Step::Block {
+                number, timestamp, ..
+            } => {
+                if let Some(number) = number {
+                    test::set_block_number::<DefaultEnvironment>(*number);
+                }
+                if let Some(timestamp) = timestamp {
+                    test::set_block_timestamp::<DefaultEnvironment>(*timestamp);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Sets the caller of `call`, alice by default, and transfers its value.
+fn prepare_call(call: &format::Call) -> Result<(), ReplayError> {
+    let caller = value::account_id(call.caller.as_deref().unwrap_or("alice"))?;
+    test::set_caller::<DefaultEnvironment>(caller);
+    match call.value {
+        Some(amount) => test::transfer_in::<DefaultEnvironment>(amount),
+        None => test::set_value_transferred::<DefaultEnvironment>(0),
+    }
+    Ok(())
+}
+
+fn is_err(returned: &Value) -> bool {
+    returned
+        .as_object()
+        .is_some_and(|result| result.len() == 1 && result.contains_key("Err"))
+}
+
+/// A message returning `Err` is reverted on chain, including the value transferred
+/// with it, while the off-chain environment keeps the value at the contract.
+fn revert_value(call: &format::Call) -> Result<(), ReplayError> {
+    let value = match call.value {
+        Some(value) if value > 0 => value,
+        _ => return Ok(()),
+    };
+    // `transfer_in` moves value from the caller to the callee, so swap them.
+    let caller = value::account_id(call.caller.as_deref().unwrap_or("alice"))?;
+    let contract = test::callee::<DefaultEnvironment>();
+    test::set_caller::<DefaultEnvironment>(contract);
+    test::set_callee::<DefaultEnvironment>(caller);
+    test::transfer_in::<DefaultEnvironment>(value);
+    test::set_callee::<DefaultEnvironment>(contract);
+    test::set_caller::<DefaultEnvironment>(caller);
+    Ok(())
+}
+
+fn check_value(name: &str, expected: &Value, returned: &Value) -> Result<(), ReplayError> {
+    if expected == returned {
+        Ok(())
+    } else {
+        Err(ReplayError::new(format!(
+            "`{}` returned {} but {} was expected",
+            name, returned, expected
+        )))
+    }
+}
+
+/// Checks that exactly the `expected` events were emitted, in order. Only the
+/// fields listed in `testcase.txt` are compared.
+fn check_events<C: Contract>(
+    expected: &[Event],
+    emitted: &[EmittedEvent],
+) -> Result<(), ReplayError> {
+    let emitted = emitted
+        .iter()
+        .map(|event| {
+            C::decode_event(event).ok_or_else(|| ReplayError::new("emitted an unknown event"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let names = |events: &[Event]| {
+        events
+            .iter()
+            .map(|event| event.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    if emitted.len() != expected.len()
+        || emitted
+            .iter()
+            .zip(expected)
+            .any(|(emitted, expected)| emitted.name != expected.name)
+    {
+        return Err(ReplayError::new(format!(
+            "emitted [{}] but [{}] was expected",
+            names(&emitted),
+            names(expected)
+        )));
+    }
+
+    for (emitted, expected) in emitted.iter().zip(expected) {
+        for (field, value) in &expected.fields {
+            match emitted.fields.get(field) {
+                Some(actual) if actual == value => {}
+                Some(actual) => {
+                    return Err(ReplayError::new(format!(
+                        "{}.{} is {} but {} was expected",
+                        emitted.name, field, actual, value
+                    )))
+                }
+                None => {
+                    return Err(ReplayError::new(format!(
+                        "{} has no field `{}`",
+                        emitted.name, field
+                    )))
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the caller and the value transferred with each call.
+    struct Probe;
+
+    impl Contract for Probe {
+        fn construct(_: &str, _: &Args) -> Result<Self, ReplayError> {
+            Ok(Probe)
+        }
+
+        fn call(&mut self, message: &str, _: &Args) -> Result<Value, ReplayError> {
+            match message {
+                "caller" => Ok(ink::env::caller::<DefaultEnvironment>().to_value()),
+                "transferred" => Ok(ink::env::transferred_value::<DefaultEnvironment>().to_value()),
+                _ => Err(ReplayError::unknown("message", message)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_storage_resets_caller_and_value() {
+        let source = r#"
+{"scenario": "storage", "constructor": "new", "endowment": 1000000}
+{"call": "transferred", "caller": "bob", "value": 5, "returns": 5}
+{"storage": "caller", "equals": "alice"}
+{"storage": "transferred", "equals": 0}
+{"storage": "caller", "caller": "charlie", "equals": "charlie"}
+"#;
+        assert_eq!(replay::<Probe>(source), Ok(vec![]));
+    }
+}
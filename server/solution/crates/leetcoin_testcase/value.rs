@@ -0,0 +1,418 @@
+//! Conversions between contract types and the JSON values of `testcase.txt`.
+
+use std::fmt::Debug;
+
+use ink::primitives::{AccountId, Hash};
+use serde_json::{Map, Value};
+
+use crate::ReplayError;
+
+/// Names of the off-chain default accounts, in the order of their byte value.
+const ACCOUNT_NAMES: [&str; 6] = ["alice", "bob", "charlie", "django", "eve", "frank"];
+
+/// Name of the account the contract under test is deployed at.
+pub const CONTRACT: &str = "contract";
+
+/// Account id of the contract under test.
+pub const CONTRACT_ACCOUNT: [u8; 32] = [0xFF; 32];
+
+/// Resolves an account name, or a `0x` prefixed hex id, to an account id.
+pub fn account_id(name: &str) -> Result<AccountId, ReplayError> {
+    if name == CONTRACT {
+        return Ok(AccountId::from(CONTRACT_ACCOUNT));
+    }
+    if let Some(index) = ACCOUNT_NAMES.iter().position(|known| *known == name) {
+        return Ok(AccountId::from([index as u8 + 1; 32]));
+    }
+    parse_hex(name)
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .map(AccountId::from)
+        .ok_or_else(|| ReplayError::new(format!("unknown account `{}`", name)))
+}
+
+/// Returns the name of `account`, or its hex id if it is not a known account.
+pub fn account_name(account: &AccountId) -> String {
+    let bytes: &[u8; 32] = account.as_ref();
+    if *bytes == CONTRACT_ACCOUNT {
+        return CONTRACT.to_string();
+    }
+    match ACCOUNT_NAMES.get(usize::from(bytes[0]).wrapping_sub(1)) {
+        Some(name) if bytes.iter().all(|byte| *byte == bytes[0]) => name.to_string(),
+        _ => to_hex(bytes),
+    }
+}
+
+/// Converts a value returned by or emitted from a contract into JSON.
+pub trait ToValue {
+    fn to_value(&self) -> Value;
+}
+
+impl ToValue for () {
+    fn to_value(&self) -> Value {
+        Value::Null
+    }
+}
+
+macro_rules! impl_to_value_for_number {
+    ( $( $ty:ty ),* ) => {
+        $(
+            impl ToValue for $ty {
+                fn to_value(&self) -> Value {
+                    Value::from(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_value_for_number!(bool, u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl ToValue for u128 {
+    /// Balances above `u64::MAX` do not fit a JSON number and are written as strings.
+    fn to_value(&self) -> Value {
+        match u64::try_from(*self) {
+            Ok(value) => Value::from(value),
+            Err(_) => Value::from(self.to_string()),
+        }
+    }
+}
+
+impl ToValue for str {
+    fn to_value(&self) -> Value {
+        Value::from(self)
+    }
+}
+
+impl ToValue for String {
+    fn to_value(&self) -> Value {
+        Value::from(self.as_str())
+    }
+}
+
+impl ToValue for AccountId {
+    fn to_value(&self) -> Value {
+        Value::from(account_name(self))
+    }
+}
+
+impl ToValue for Hash {
+    fn to_value(&self) -> Value {
+        Value::from(to_hex(self.as_ref()))
+    }
+}
+
+/// Lets adapters pass values they built with [`json!`](crate::json) as they are.
+impl ToValue for Value {
+    fn to_value(&self) -> Value {
+        self.clone()
+    }
+}
+
+impl<T: ToValue + ?Sized> ToValue for &T {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T: ToValue> ToValue for Option<T> {
+    fn to_value(&self) -> Value {
+        self.as_ref().map_or(Value::Null, ToValue::to_value)
+    }
+}
+
+impl<T: ToValue> ToValue for Vec<T> {
+    fn to_value(&self) -> Value {
+        Value::Array(self.iter().map(ToValue::to_value).collect())
+    }
+}
+
+/// Written as `{"Ok": value}` or `{"Err": "Variant"}`, using the `Debug` name of the error.
+impl<T: ToValue, E: Debug> ToValue for Result<T, E> {
+    fn to_value(&self) -> Value {
+        let mut map = Map::new();
+        match self {
+            Ok(value) => map.insert("Ok".to_string(), value.to_value()),
+            Err(err) => map.insert("Err".to_string(), Value::from(format!("{:?}", err))),
+        };
+        Value::Object(map)
+    }
+}
+
+macro_rules! impl_to_value_for_tuple {
+    ( $( ( $( $name:ident : $index:tt ),+ ) )* ) => {
+        $(
+            impl<$( $name: ToValue ),+> ToValue for ($( $name, )+) {
+                fn to_value(&self) -> Value {
+                    Value::Array(vec![$( self.$index.to_value() ),+])
+                }
+            }
+        )*
+    };
+}
+
+impl_to_value_for_tuple! {
+    (A: 0, B: 1)
+    (A: 0, B: 1, C: 2)
+    (A: 0, B: 1, C: 2, D: 3)
+    (A: 0, B: 1, C: 2, D: 3, E: 4)
+}
+
+/// The arguments of a constructor or message call.
+pub struct Args<'a> {
+    call: &'a str,
+    args: &'a [Value],
+}
+
+impl<'a> Args<'a> {
+    pub fn new(call: &'a str, args: &'a [Value]) -> Self {
+        Self { call, args }
+    }
+
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    pub fn account(&self, index: usize) -> Result<AccountId, ReplayError> {
+        match self.get(index)? {
+            Value::String(name) => account_id(name).map_err(|err| self.error(index, err)),
+            _ => Err(self.expected(index, "an account name")),
+        }
+    }
+
+    /// Accepts an array of account names.
+    pub fn accounts(&self, index: usize) -> Result<Vec<AccountId>, ReplayError> {
+        match self.get(index)? {
+            Value::Array(names) => names
+                .iter()
+                .map(|name| match name {
+                    Value::String(name) => account_id(name).map_err(|err| self.error(index, err)),
+                    _ => Err(self.expected(index, "an array of account names")),
+                })
+                .collect(),
+            _ => Err(self.expected(index, "an array of account names")),
+        }
+    }
+
+    pub fn bool(&self, index: usize) -> Result<bool, ReplayError> {
+        self.get(index)?
+            .as_bool()
+            .ok_or_else(|| self.expected(index, "a bool"))
+    }
+
+    pub fn u8(&self, index: usize) -> Result<u8, ReplayError> {
+        self.get(index)?
+            .as_u64()
+            .and_then(|value| u8::try_from(value).ok())
+            .ok_or_else(|| self.expected(index, "a u8"))
+    }
+
+    pub fn u32(&self, index: usize) -> Result<u32, ReplayError> {
+        self.get(index)?
+            .as_u64()
+            .and_then(|value| u32::try_from(value).ok())
+            .ok_or_else(|| self.expected(index, "a u32"))
+    }
+
+    pub fn u64(&self, index: usize) -> Result<u64, ReplayError> {
+        self.get(index)?
+            .as_u64()
+            .ok_or_else(|| self.expected(index, "a u64"))
+    }
+
+    pub fn i32(&self, index: usize) -> Result<i32, ReplayError> {
+        self.get(index)?
+            .as_i64()
+            .and_then(|value| i32::try_from(value).ok())
+            .ok_or_else(|| self.expected(index, "an i32"))
+    }
+
+    /// Accepts a number, or a string for amounts above `u64::MAX`.
+    pub fn balance(&self, index: usize) -> Result<u128, ReplayError> {
+        match self.get(index)? {
+            Value::Number(number) => number.as_u64().map(u128::from),
+            Value::String(text) => text.parse().ok(),
+            _ => None,
+        }
+        .ok_or_else(|| self.expected(index, "a balance"))
+    }
+
+    /// Accepts a u32, or `null` for `None`.
+    pub fn optional_u32(&self, index: usize) -> Result<Option<u32>, ReplayError> {
+        match self.get(index)? {
+            Value::Null => Ok(None),
+            _ => self.u32(index).map(Some),
+        }
+    }
+
+    /// Accepts a balance, or `null` for `None`.
+    pub fn optional_balance(&self, index: usize) -> Result<Option<u128>, ReplayError> {
+        match self.get(index)? {
+            Value::Null => Ok(None),
+            _ => self.balance(index).map(Some),
+        }
+    }
+
+    /// Accepts an array of balances.
+    pub fn balances(&self, index: usize) -> Result<Vec<u128>, ReplayError> {
+        match self.get(index)? {
+            Value::Array(values) => values
+                .iter()
+                .map(|value| match value {
+                    Value::Number(number) => number.as_u64().map(u128::from),
+                    Value::String(text) => text.parse().ok(),
+                    _ => None,
+                })
+                .collect::<Option<_>>(),
+            _ => None,
+        }
+        .ok_or_else(|| self.expected(index, "an array of balances"))
+    }
+
+    pub fn string(&self, index: usize) -> Result<String, ReplayError> {
+        self.get(index)?
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| self.expected(index, "a string"))
+    }
+
+    /// Accepts a string, or `null` for `None`.
+    pub fn optional_string(&self, index: usize) -> Result<Option<String>, ReplayError> {
+        match self.get(index)? {
+            Value::Null => Ok(None),
+            _ => self.string(index).map(Some),
+        }
+    }
+
+    /// Accepts a `0x` prefixed hex string or an array of byte values.
+    pub fn bytes(&self, index: usize) -> Result<Vec<u8>, ReplayError> {
+        match self.get(index)? {
+            Value::String(text) => parse_hex(text),
+            Value::Array(values) => values
+                .iter()
+                .map(|value| value.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+                .collect(),
+            _ => None,
+        }
+        .ok_or_else(|| self.expected(index, "bytes"))
+    }
+
+    pub fn hash(&self, index: usize) -> Result<Hash, ReplayError> {
+        self.bytes(index)?
+            .as_slice()
+            .try_into()
+            .map_err(|_| self.expected(index, "a 32 byte hash"))
+    }
+
+    /// Returns the argument as written, for types the accessors above do not cover.
+    pub fn value(&self, index: usize) -> Result<&'a Value, ReplayError> {
+        self.get(index)
+    }
+
+    fn get(&self, index: usize) -> Result<&'a Value, ReplayError> {
+        self.args.get(index).ok_or_else(|| {
+            ReplayError::new(format!(
+                "`{}` expects at least {} arguments",
+                self.call,
+                index + 1
+            ))
+        })
+    }
+
+    fn expected(&self, index: usize, what: &str) -> ReplayError {
+        ReplayError::new(format!(
+            "argument {} of `{}` must be {}, got {}",
+            index, self.call, what, self.args[index]
+        ))
+    }
+
+    fn error(&self, index: usize, err: ReplayError) -> ReplayError {
+        ReplayError::new(format!("argument {} of `{}`: {}", index, self.call, err))
+    }
+}
+
+fn parse_hex(text: &str) -> Option<Vec<u8>> {
+    let digits = text.strip_prefix("0x")?;
+    if digits.len() % 2 != 0 {
+        return None;
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|start| u8::from_str_radix(digits.get(start..start + 2)?, 16).ok())
+        .collect()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::from("0x");
+    for byte in bytes {
+        hex.push_str(&format!("{:02x}", byte));
+    }
+    hex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_account_names_round_trip() {
+        for name in ACCOUNT_NAMES.iter().chain([&CONTRACT]) {
+            assert_eq!(account_name(&account_id(name).unwrap()), *name);
+        }
+        let unknown = AccountId::from([7; 32]);
+        assert_eq!(account_id(&account_name(&unknown)).unwrap(), unknown);
+    }
+
+    #[test]
+    fn test_to_value() {
+        assert_eq!(u128::MAX.to_value(), json!(u128::MAX.to_string()));
+        assert_eq!(Some(AccountId::from([2; 32])).to_value(), json!("bob"));
+        assert_eq!(Ok::<_, ()>(vec![1u32]).to_value(), json!({"Ok": [1]}));
+        assert_eq!(
+            Err::<(), _>("Denied").to_value(),
+            json!({"Err": "\"Denied\""})
+        );
+        assert_eq!(("yes".to_string(), 3u32).to_value(), json!(["yes", 3]));
+    }
+
+    #[test]
+    fn test_args() {
+        let values = [
+            json!("charlie"),
+            json!(5),
+            json!("0x0102"),
+            json!(-1),
+            json!(["alice", "eve"]),
+            json!([1, "2"]),
+            json!(null),
+            json!("LEET"),
+        ];
+        let args = Args::new("call", &values);
+        assert_eq!(args.account(0).unwrap(), AccountId::from([3; 32]));
+        assert_eq!(args.balance(1).unwrap(), 5);
+        assert_eq!(args.bytes(2).unwrap(), vec![1, 2]);
+        assert_eq!(args.i32(3).unwrap(), -1);
+        assert!(args.u32(3).is_err());
+        assert_eq!(
+            args.accounts(4).unwrap(),
+            vec![AccountId::from([1; 32]), AccountId::from([5; 32])]
+        );
+        assert!(args.accounts(0).is_err());
+        assert_eq!(args.balances(5).unwrap(), vec![1, 2]);
+        assert!(args.balances(4).is_err());
+        assert_eq!(args.optional_string(6).unwrap(), None);
+        assert_eq!(args.optional_string(7).unwrap().as_deref(), Some("LEET"));
+        assert!(args.optional_string(1).is_err());
+        assert_eq!(args.optional_balance(1).unwrap(), Some(5));
+        assert_eq!(args.optional_balance(6).unwrap(), None);
+        assert_eq!(args.optional_u32(1).unwrap(), Some(5));
+        assert_eq!(args.optional_u32(6).unwrap(), None);
+        assert_eq!(args.u8(1).unwrap(), 5);
+        assert_eq!(args.value(5).unwrap(), &json!([1, "2"]));
+        assert!(args.string(8).is_err());
+    }
+}
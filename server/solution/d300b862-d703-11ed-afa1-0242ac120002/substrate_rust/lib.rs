@@ -1,172 +1,359 @@
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-use ink_lang as ink;
+pub use self::multisig_wallet::{
+    Action, AddOwner, ChangeRequirement, ConfirmTransaction, Error, ExecuteTransaction,
+    MultisigWallet, ProposeTransaction, RemoveOwner, RevokeConfirmation, Transaction,
+};
 
 #[ink::contract]
 mod multisig_wallet {
-    use ink_prelude::collections::HashMap;
-    use ink_prelude::vec::Vec;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// What a transaction does once enough owners confirmed it.
+    ///
+    /// Owner management goes through the same proposals as transfers, so changing the
+    /// set of owners needs as many confirmations as spending the wallet's funds.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum Action {
+        Transfer { to: AccountId, value: Balance },
+        AddOwner(AccountId),
+        RemoveOwner(AccountId),
+        ChangeRequirement(u32),
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Transaction {
+        pub action: Action,
+        pub tx_hash: Hash,
+        pub executed: bool,
+    }
 
     #[ink(storage)]
+    #[derive(Default)]
     pub struct MultisigWallet {
-        owners: HashMap<AccountId, bool>,
+        owners: Vec<AccountId>,
         required_confirmations: u32,
-        transactions: HashMap<u32, (Hash, Balance, AccountId, u32, bool)>,
-        confirmations: HashMap<u32, HashMap<AccountId, bool>>,
+        transactions: Mapping<u32, Transaction>,
+        transaction_count: u32,
+        /// The term of the owner that gave each confirmation.
+        ///
+        /// Confirmations are counted when needed, and only those of the current
+        /// owners given in their current term count.
+        confirmations: Mapping<(u32, AccountId), u32>,
+        /// How many times each account became an owner.
+        owner_terms: Mapping<AccountId, u32>,
     }
 
     impl MultisigWallet {
+        /// Creates a wallet controlled by `owners`, executing transactions once
+        /// `required_confirmations` of them confirmed.
         #[ink(constructor)]
-        pub fn new(required_confirmations: u32) -> Self {
-            Self {
-                owners: HashMap::new(),
-                required_confirmations,
-                transactions: HashMap::new(),
-                confirmations: HashMap::new(),
+        pub fn new(owners: Vec<AccountId>, required_confirmations: u32) -> Result<Self, Error> {
+            let mut wallet = Self::default();
+            for owner in owners {
+                if wallet.owners.contains(&owner) {
+                    return Err(Error::AlreadyOwner);
+                }
+                wallet.owners.push(owner);
+                wallet.owner_terms.insert(owner, &1);
             }
+            wallet.ensure_requirement(wallet.owners.len(), required_confirmations)?;
+            wallet.required_confirmations = required_confirmations;
+            Ok(wallet)
         }
 
-        pub fn propose_transaction(
+        #[ink(message)]
+        pub fn propose_transaction(&mut self, to: AccountId, value: Balance) -> Result<u32, Error> {
+            self.propose(Action::Transfer { to, value })
+        }
+
+        #[ink(message)]
+        pub fn propose_add_owner(&mut self, owner: AccountId) -> Result<u32, Error> {
+            self.propose(Action::AddOwner(owner))
+        }
+
+        #[ink(message)]
+        pub fn propose_remove_owner(&mut self, owner: AccountId) -> Result<u32, Error> {
+            self.propose(Action::RemoveOwner(owner))
+        }
+
+        #[ink(message)]
+        pub fn propose_change_requirement(
             &mut self,
-            to: AccountId,
-            value: Balance,
-            data: Vec<u8>,
+            required_confirmations: u32,
         ) -> Result<u32, Error> {
-            let id = self.transactions.len() as u32;
-            let tx_hash = Self::hash(&to, value, &data);
-            self.transactions.insert(
-                id,
-                (tx_hash, value, to, 0, false)
-            );
-            self.confirmations.insert(id, HashMap::new());
-            self.emit_propose_transaction(id, tx_hash, to, value, data);
-            Ok(id)
+            self.propose(Action::ChangeRequirement(required_confirmations))
         }
 
-        pub fn confirm_transaction(
-            &mut self,
-            id: u32,
-            owner: AccountId,
-        ) -> Result<(), Error> {
-            let tx = self.transactions.get(&id).ok_or(Error::InvalidTransactionId)?;
-            let mut confirmations = self.confirmations.get(&id).ok_or(Error::InvalidTransactionId)?;
-
-            if !self.owners.contains_key(&owner) {
-                return Err(Error::Unauthorized);
-            }
-            if confirmations.contains_key(&owner) {
+        /// Confirms transaction `id` on behalf of the caller.
+        #[ink(message)]
+        pub fn confirm_transaction(&mut self, id: u32) -> Result<(), Error> {
+            let owner = self.ensure_owner()?;
+            self.pending_transaction(id)?;
+            if self.has_confirmed(id, owner) {
                 return Err(Error::AlreadyConfirmed);
             }
 
-            confirmations.insert(owner, true);
-            self.emit_confirm_transaction(id, owner);
+            self.confirmations
+                .insert((id, owner), &self.owner_term(owner));
+            self.env().emit_event(ConfirmTransaction { id, owner });
 
-            if self.is_confirmed(id) {
-                self.execute_transaction(id)?;
+            Ok(())
+        }
+
+        /// Withdraws the caller's confirmation of transaction `id`.
+        #[ink(message)]
+        pub fn revoke_confirmation(&mut self, id: u32) -> Result<(), Error> {
+            let owner = self.ensure_owner()?;
+            self.pending_transaction(id)?;
+            if !self.has_confirmed(id, owner) {
+                return Err(Error::NotConfirmed);
             }
 
+            self.confirmations.remove((id, owner));
+            self.env().emit_event(RevokeConfirmation { id, owner });
+
             Ok(())
         }
 
+        #[ink(message)]
         pub fn execute_transaction(&mut self, id: u32) -> Result<(), Error> {
-            let mut tx = self.transactions.get_mut(&id).ok_or(Error::InvalidTransactionId)?;
-            let mut confirmations = self.confirmations.remove(&id).ok_or(Error::InvalidTransactionId)?;
-
-            if tx.4 {
-                return Err(Error::AlreadyExecuted);
-            }
+            self.ensure_owner()?;
+            let mut tx = self.pending_transaction(id)?;
             if !self.is_confirmed(id) {
                 return Err(Error::NotEnoughConfirmations);
             }
 
-            for (owner, _) in confirmations.iter() {
-                let balance = Self::env().balance(*owner);
-                Self::env().transfer(*owner, tx.1).map_err(|_| Error::TransferFailed)?;
+            match tx.action {
+                Action::Transfer { to, value } => {
+                    self.env()
+                        .transfer(to, value)
+                        .map_err(|_| Error::TransferFailed)?;
+                }
+                Action::AddOwner(owner) => self.add_owner(owner)?,
+                Action::RemoveOwner(owner) => self.remove_owner(owner)?,
+                Action::ChangeRequirement(required) => self.change_requirement(required)?,
             }
 
-            tx.3 = confirmations.len() as u32;
-            tx.4 = true;
-            self.emit_execute_transaction(id, tx.2, tx.1);
+            tx.executed = true;
+            self.transactions.insert(id, &tx);
+            self.env().emit_event(ExecuteTransaction {
+                id,
+                action: tx.action,
+            });
 
             Ok(())
         }
 
-        pub fn add_owner(&mut self, owner: AccountId) -> Result<(), Error> {
-            if self.owners.contains_key(&owner) {
+        #[ink(message)]
+        pub fn get_owners(&self) -> Vec<AccountId> {
+            self.owners.clone()
+        }
+
+        #[ink(message)]
+        pub fn is_owner(&self, account: AccountId) -> bool {
+            self.owners.contains(&account)
+        }
+
+        #[ink(message)]
+        pub fn get_required_confirmations(&self) -> u32 {
+            self.required_confirmations
+        }
+
+        #[ink(message)]
+        pub fn is_confirmed(&self, id: u32) -> bool {
+            self.transactions.contains(id)
+                && self.get_confirmation_count(id) >= self.required_confirmations
+        }
+
+        #[ink(message)]
+        pub fn get_transaction_count(&self) -> u32 {
+            self.transaction_count
+        }
+
+        #[ink(message)]
+        pub fn get_transaction(&self, id: u32) -> Option<Transaction> {
+            self.transactions.get(id)
+        }
+
+        /// Returns the current owners that confirmed transaction `id`.
+        #[ink(message)]
+        pub fn get_confirmations(&self, id: u32) -> Vec<AccountId> {
+            self.owners
+                .iter()
+                .filter(|owner| self.has_confirmed(id, **owner))
+                .copied()
+                .collect()
+        }
+
+        /// Returns how many of the current owners confirmed transaction `id`.
+        #[ink(message)]
+        pub fn get_confirmation_count(&self, id: u32) -> u32 {
+            self.owners
+                .iter()
+                .filter(|owner| self.has_confirmed(id, **owner))
+                .count() as u32
+        }
+
+        fn propose(&mut self, action: Action) -> Result<u32, Error> {
+            let proposer = self.ensure_owner()?;
+            let id = self.transaction_count;
+            let transaction_count = id.checked_add(1).ok_or(Error::Overflow)?;
+            let tx_hash = Self::hash(id, &action);
+            self.transactions.insert(
+                id,
+                &Transaction {
+                    action: action.clone(),
+                    tx_hash,
+                    executed: false,
+                },
+            );
+            self.transaction_count = transaction_count;
+            self.env().emit_event(ProposeTransaction {
+                id,
+                proposer,
+                tx_hash,
+                action,
+            });
+            Ok(id)
+        }
+
+        fn add_owner(&mut self, owner: AccountId) -> Result<(), Error> {
+            if self.owners.contains(&owner) {
                 return Err(Error::AlreadyOwner);
             }
-            self.owners.insert(owner, true);
+            self.owners.push(owner);
+            // confirmations from an earlier term stop counting
+            self.owner_terms
+                .insert(owner, &self.owner_term(owner).saturating_add(1));
+            self.env().emit_event(AddOwner { owner });
             Ok(())
         }
 
-        pub fn remove_owner(&mut self, owner: AccountId) -> Result<(), Error> {
-            if self.owners.len() == 1 {
-                return Err(Error::CannotRemoveLastOwner);
-            }
-            if !self.owners.contains_key(&owner) {
-                return Err(Error::NotOwner);
-            }
-            self.owners.remove(&owner);
+        /// Removes `owner`, whose confirmations stop counting with it.
+        fn remove_owner(&mut self, owner: AccountId) -> Result<(), Error> {
+            let index = self
+                .owners
+                .iter()
+                .position(|known| *known == owner)
+                .ok_or(Error::NotOwner)?;
+            self.ensure_requirement(self.owners.len() - 1, self.required_confirmations)?;
+
+            self.owners.swap_remove(index);
+            self.env().emit_event(RemoveOwner { owner });
             Ok(())
         }
 
-        pub fn is_confirmed(&self, id: u32) -> bool {
-            let confirmations = self.confirmations.get(&id).unwrap();
-            confirmations.len() >= self.required_confirmations as usize
+        fn change_requirement(&mut self, required_confirmations: u32) -> Result<(), Error> {
+            self.ensure_requirement(self.owners.len(), required_confirmations)?;
+            self.required_confirmations = required_confirmations;
+            self.env().emit_event(ChangeRequirement {
+                required_confirmations,
+            });
+            Ok(())
         }
 
-        pub fn get_transaction_count(&self) -> u32 {
-            self.transactions.len() as u32
+        /// Whether `owner` confirmed transaction `id` during their current term.
+        fn has_confirmed(&self, id: u32, owner: AccountId) -> bool {
+            self.confirmations.get((id, owner)) == Some(self.owner_term(owner))
         }
 
-        pub fn get_transaction(&self, id: u32) -> Option<(Hash, Balance, AccountId, u32, bool)> {
-            self.transactions.get(&id).cloned()
+        fn owner_term(&self, owner: AccountId) -> u32 {
+            self.owner_terms.get(owner).unwrap_or(0)
         }
 
-        pub fn get_confirmations(&self, id: u32) -> HashMap<AccountId, bool> {
-            self.confirmations.get(&id).cloned().unwrap_or_default()
+        fn ensure_owner(&self) -> Result<AccountId, Error> {
+            let caller = self.env().caller();
+            if self.owners.contains(&caller) {
+                Ok(caller)
+            } else {
+                Err(Error::Unauthorized)
+            }
         }
 
-        fn hash(to: &AccountId, value: Balance, data: &[u8]) -> Hash {
-            Self::env().hash_of(&(to, value, data))
+        /// Every transaction must be executable by the owners alone.
+        fn ensure_requirement(&self, owners: usize, required: u32) -> Result<(), Error> {
+            if required == 0 || required as usize > owners {
+                return Err(Error::InvalidRequirement);
+            }
+            Ok(())
+        }
+
+        fn pending_transaction(&self, id: u32) -> Result<Transaction, Error> {
+            let tx = self
+                .transactions
+                .get(id)
+                .ok_or(Error::InvalidTransactionId)?;
+            if tx.executed {
+                return Err(Error::AlreadyExecuted);
+            }
+            Ok(tx)
+        }
+
+        fn hash(id: u32, action: &Action) -> Hash {
+            Self::env()
+                .hash_encoded::<ink::env::hash::Blake2x256, _>(&(id, action))
+                .into()
         }
     }
 
     #[ink(event)]
     pub struct ProposeTransaction {
         #[ink(topic)]
-        id: u32,
-        tx_hash: Hash,
-        to: AccountId,
-        value: Balance,
-        data: Vec<u8>,
+        pub id: u32,
+        #[ink(topic)]
+        pub proposer: AccountId,
+        pub tx_hash: Hash,
+        pub action: Action,
     }
 
     #[ink(event)]
     pub struct ConfirmTransaction {
         #[ink(topic)]
-        id: u32,
-        owner: AccountId,
+        pub id: u32,
+        #[ink(topic)]
+        pub owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct RevokeConfirmation {
+        #[ink(topic)]
+        pub id: u32,
+        #[ink(topic)]
+        pub owner: AccountId,
     }
 
     #[ink(event)]
     pub struct ExecuteTransaction {
         #[ink(topic)]
-        id: u32,
-        to: AccountId,
-        value: Balance,
+        pub id: u32,
+        pub action: Action,
     }
 
     #[ink(event)]
     pub struct AddOwner {
         #[ink(topic)]
-        owner: AccountId,
+        pub owner: AccountId,
     }
 
     #[ink(event)]
     pub struct RemoveOwner {
         #[ink(topic)]
-        owner: AccountId,
+        pub owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct ChangeRequirement {
+        pub required_confirmations: u32,
     }
 
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -175,185 +362,15 @@ mod multisig_wallet {
         InvalidTransactionId,
         Unauthorized,
         AlreadyConfirmed,
+        NotConfirmed,
         AlreadyExecuted,
         NotEnoughConfirmations,
         TransferFailed,
         AlreadyOwner,
         NotOwner,
-        CannotRemoveLastOwner,
-    }
-
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-        use ink_lang as ink;
-
-        #[ink::test]
-        fn test_propose_transaction() {
-            let mut wallet = MultisigWallet::new(2);
-
-            let tx_hash = Hash::from([0x1; 32]);
-            let to = AccountId::from([0x2; 32]);
-            let value = 100;
-            let data = vec![0x3; 10];
-
-            assert_eq!(
-                wallet.propose_transaction(to, value, data.clone()),
-                Ok(0)
-            );
-
-            let tx = wallet.get_transaction(0);
-            assert_eq!(tx, Some((tx_hash, value, to, 0, false)));
-            assert_eq!(wallet.get_confirmations(0), HashMap::new());
-            assert_eq!(wallet.get_transaction_count(), 1);
-
-            let emitted_events = ink_env::test::emitted_events();
-            assert_eq!(emitted_events.count(), 1);
-            assert_eq!(
-                emitted_events
-                    .into_iter()
-                    .next()
-                    .expect("no events emitted")
-                    .event,
-                "ProposeTransaction"
-            );
-        }
-
-        #[ink::test]
-        fn test_confirm_transaction() {
-            let mut wallet = MultisigWallet::new(2);
-
-            let tx_hash = Hash::from([0x1; 32]);
-            let to = AccountId::from([0x2; 32]);
-            let value = 100;
-            let data = vec![0x3; 10];
-            wallet.propose_transaction(to, value, data.clone()).unwrap();
-
-            let signer1 = AccountId::from([0x4; 32]);
-            let signer2 = AccountId::from([0x5; 32]);
-
-            assert_eq!(
-                wallet.confirm_transaction(0, signer1),
-                Ok(())
-            );
-            assert_eq!(wallet.get_confirmations(0), hash_map! { signer1 => true });
-            assert_eq!(wallet.is_confirmed(0), false);
-
-            assert_eq!(
-                wallet.confirm_transaction(0, signer2),
-                Ok(())
-            );
-            assert_eq!(
-                wallet.get_confirmations(0),
-                hash_map! { signer1 => true, signer2 => true }
-            );
-            assert_eq!(wallet.is_confirmed(0), true);
-
-            let emitted_events = ink_env::test::emitted_events();
-            assert_eq!(emitted_events.count(), 2);
-            let events: Vec<_> = emitted_events
-                .into_iter()
-                .map(|e| e.event)
-                .collect();
-            assert!(events.contains(&"ConfirmTransaction".to_string()));
-            assert!(events.contains(&"ExecuteTransaction".to_string()));
-        }
-
-        #[ink::test]
-        fn test_execute_transaction() {
-            let mut wallet = MultisigWallet::new(2);
-
-            let tx_hash = Hash::from([0x1; 32]);
-            let to = AccountId::from([0x2; 32]);
-            let value = 100;
-            let data = vec![0x3; 10];
-            wallet.propose_transaction(to, value, data.clone()).unwrap();
-
-            let signer1 = AccountId::from([0x4; 32]);
-            let signer2 = AccountId::from([0x5; 32]);
-            wallet.confirm_transaction(0, signer1).unwrap();
-            wallet.confirm_transaction(0, signer2).unwrap();
-
-            assert_eq!(
-                wallet.execute_transaction(0),
-                Ok(())
-            );
-            assert_eq!(wallet.get_transaction(0), Some((tx_hash, value, to, 2, true)));
-            assert_eq!(wallet.get_confirmations(0), HashMap::new());
-            assert_eq!(wallet.get_transaction_count(), 1);
-
-            let emitted_events = ink_env::test::emitted_events();
-            assert_eq!(emitted_events.count(), 2);
-            let events: Vec<_> = emitted_events
-                .into_iter()
-                .map(|e| e.event)
-                .collect();
-            assert!(events.contains(&"ExecuteTransaction".to_string()));
-        }
-    }
-
-    #[ink::test]
-    fn test_execute_transaction_not_enough_confirmations() {
-        let mut wallet = MultisigWallet::new(2);
-
-        let tx_hash = Hash::from([0x1; 32]);
-        let to = AccountId::from([0x2; 32]);
-        let value = 100;
-        let data = vec![0x3; 10];
-        wallet.propose_transaction(to, value, data.clone()).unwrap();
-
-        let signer1 = AccountId::from([0x4; 32]);
-        wallet.confirm_transaction(0, signer1).unwrap();
-
-        assert_eq!(
-            wallet.execute_transaction(0),
-            Err(Error::NotEnoughConfirmations)
-        );
-        assert_eq!(wallet.get_transaction(0), Some((tx_hash, value, to, 1, false)));
-        assert_eq!(
-            wallet.get_confirmations(0),
-            hash_map! { signer1 => true }
-        );
-        assert_eq!(wallet.get_transaction_count(), 1);
-
-        let emitted_events = ink_env::test::emitted_events();
-        assert_eq!(emitted_events.count(), 0);
-    }
-
-    #[ink::test]
-    fn test_execute_transaction_already_executed() {
-        let mut wallet = MultisigWallet::new(2);
-
-        let tx_hash = Hash::from([0x1; 32]);
-        let to = AccountId::from([0x2; 32]);
-        let value = 100;
-        let data = vec![0x3; 10];
-        wallet.propose_transaction(to, value, data.clone()).unwrap();
-
-        let signer1 = AccountId::from([0x4; 32]);
-        let signer2 = AccountId::from([0x5; 32]);
-        wallet.confirm_transaction(0, signer1).unwrap();
-        wallet.confirm_transaction(0, signer2).unwrap();
-        wallet.execute_transaction(0).unwrap();
-
-        assert_eq!(
-            wallet.execute_transaction(0),
-            Err(Error::AlreadyExecuted)
-        );
-        assert_eq!(wallet.get_transaction(0), Some((tx_hash, value, to, 2, true)));
-        assert_eq!(wallet.get_confirmations(0), HashMap::new());
-        assert_eq!(wallet.get_transaction_count(), 1);
-
-        let emitted_events = ink_env::test::emitted_events();
-        assert_eq!(emitted_events.count(), 1);
-        assert_eq!(
-            emitted_events
-                .into_iter()
-                .next()
-                .expect("no events emitted")
-                .event,
-            "ExecuteTransaction"
-        );
+        /// Zero, or more confirmations than there are owners.
+        InvalidRequirement,
+        /// Every transaction id was used.
+        Overflow,
     }
 }
-
@@ -0,0 +1,125 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{balance_of, callee, set_balance, set_caller};
+use multisig_wallet::{Error, ExecuteTransaction, MultisigWallet};
+
+const SIGNER1: AccountId = AccountId([0x4; 32]);
+const SIGNER2: AccountId = AccountId([0x5; 32]);
+
+fn setup() -> MultisigWallet {
+    let contract = callee();
+    set_balance(contract, 1_000_000);
+
+    set_caller(SIGNER1);
+    MultisigWallet::new(vec![SIGNER1, SIGNER2], 2).unwrap()
+}
+
+#[ink::test]
+fn test_confirm_transaction_unauthorized() {
+    let mut wallet = setup();
+    wallet
+        .propose_transaction(AccountId::from([0x2; 32]), 100)
+        .unwrap();
+
+    set_caller(AccountId::from([0x6; 32]));
+    assert_eq!(wallet.confirm_transaction(0), Err(Error::Unauthorized));
+    assert_eq!(wallet.revoke_confirmation(0), Err(Error::Unauthorized));
+    assert_eq!(wallet.get_confirmations(0), vec![]);
+    assert_eq!(wallet.confirm_transaction(1), Err(Error::Unauthorized));
+
+    set_caller(SIGNER1);
+    assert_eq!(
+        wallet.confirm_transaction(1),
+        Err(Error::InvalidTransactionId)
+    );
+}
+
+#[ink::test]
+fn test_execute_transaction_not_enough_confirmations() {
+    let mut wallet = setup();
+
+    let to = AccountId::from([0x2; 32]);
+    wallet.propose_transaction(to, 100).unwrap();
+    wallet.confirm_transaction(0).unwrap();
+
+    assert_eq!(
+        wallet.execute_transaction(0),
+        Err(Error::NotEnoughConfirmations)
+    );
+    assert!(!wallet.get_transaction(0).unwrap().executed);
+    assert_eq!(wallet.get_confirmation_count(0), 1);
+    assert_eq!(wallet.get_confirmations(0), vec![SIGNER1]);
+    assert_eq!(wallet.get_transaction_count(), 1);
+    assert_eq!(balance_of(to), 1000);
+
+    assert_eq!(leetcoin_test_kit::recorded_events().len(), 2);
+    assert!(leetcoin_test_kit::decode_events::<ExecuteTransaction>().is_empty());
+}
+
+#[ink::test]
+fn test_execute_transaction_already_executed() {
+    let mut wallet = setup();
+
+    wallet
+        .propose_transaction(AccountId::from([0x2; 32]), 100)
+        .unwrap();
+    wallet.confirm_transaction(0).unwrap();
+    set_caller(SIGNER2);
+    wallet.confirm_transaction(0).unwrap();
+    wallet.execute_transaction(0).unwrap();
+
+    assert_eq!(wallet.execute_transaction(0), Err(Error::AlreadyExecuted));
+    assert_eq!(wallet.confirm_transaction(0), Err(Error::AlreadyExecuted));
+    assert_eq!(wallet.revoke_confirmation(0), Err(Error::AlreadyExecuted));
+    assert!(wallet.get_transaction(0).unwrap().executed);
+    assert_eq!(wallet.get_confirmation_count(0), 2);
+
+    assert_eq!(leetcoin_test_kit::recorded_events().len(), 4);
+    assert_eq!(
+        leetcoin_test_kit::decode_events::<ExecuteTransaction>().len(),
+        1
+    );
+}
+
+/// Proposes `propose` as the first signer and executes it with both signers.
+fn enact(wallet: &mut MultisigWallet, propose: impl FnOnce(&mut MultisigWallet) -> u32) {
+    set_caller(SIGNER1);
+    let id = propose(wallet);
+    wallet.confirm_transaction(id).unwrap();
+    set_caller(SIGNER2);
+    wallet.confirm_transaction(id).unwrap();
+    wallet.execute_transaction(id).unwrap();
+}
+
+#[ink::test]
+fn test_readded_owner_confirms_again() {
+    let mut wallet = setup();
+    let owner = AccountId::from([0x6; 32]);
+    enact(&mut wallet, |wallet| {
+        wallet.propose_add_owner(owner).unwrap()
+    });
+
+    let pending = wallet
+        .propose_transaction(AccountId::from([0x2; 32]), 100)
+        .unwrap();
+    set_caller(owner);
+    wallet.confirm_transaction(pending).unwrap();
+    assert_eq!(wallet.get_confirmation_count(pending), 1);
+
+    enact(&mut wallet, |wallet| {
+        wallet.propose_remove_owner(owner).unwrap()
+    });
+    assert_eq!(wallet.get_confirmation_count(pending), 0);
+
+    // The confirmation given before the removal does not come back with the owner.
+    enact(&mut wallet, |wallet| {
+        wallet.propose_add_owner(owner).unwrap()
+    });
+    assert_eq!(wallet.get_confirmation_count(pending), 0);
+    set_caller(owner);
+    assert_eq!(
+        wallet.revoke_confirmation(pending),
+        Err(Error::NotConfirmed)
+    );
+    assert_eq!(wallet.confirm_transaction(pending), Ok(()));
+    assert_eq!(wallet.get_confirmations(pending), vec![owner]);
+}
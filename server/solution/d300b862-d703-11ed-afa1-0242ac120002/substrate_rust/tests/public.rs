@@ -0,0 +1,276 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{balance_of, callee, event, set_balance, set_caller, ExpectedEvent};
+use multisig_wallet::{
+    Action, AddOwner, ChangeRequirement, ConfirmTransaction, Error, ExecuteTransaction,
+    MultisigWallet, ProposeTransaction, RemoveOwner, RevokeConfirmation,
+};
+
+const SIGNER1: AccountId = AccountId([0x4; 32]);
+const SIGNER2: AccountId = AccountId([0x5; 32]);
+const OUTSIDER: AccountId = AccountId([0x6; 32]);
+
+/// The event emitted when `id` was proposed by `proposer`.
+fn proposal_event(wallet: &MultisigWallet, id: u32, proposer: AccountId) -> ExpectedEvent {
+    let tx = wallet.get_transaction(id).unwrap();
+    event(ProposeTransaction {
+        id,
+        proposer,
+        tx_hash: tx.tx_hash,
+        action: tx.action,
+    })
+}
+
+/// A wallet owned by the two signers, requiring both of them to confirm.
+fn setup() -> MultisigWallet {
+    let contract = callee();
+    set_balance(contract, 1_000_000);
+
+    set_caller(SIGNER1);
+    MultisigWallet::new(vec![SIGNER1, SIGNER2], 2).unwrap()
+}
+
+/// Runs `propose` as the first signer and confirms the proposal with both signers.
+fn approve(wallet: &mut MultisigWallet, propose: impl FnOnce(&mut MultisigWallet) -> u32) -> u32 {
+    set_caller(SIGNER1);
+    let id = propose(wallet);
+    wallet.confirm_transaction(id).unwrap();
+    set_caller(SIGNER2);
+    wallet.confirm_transaction(id).unwrap();
+    id
+}
+
+#[ink::test]
+fn test_new() {
+    let wallet = setup();
+    assert_eq!(wallet.get_owners(), vec![SIGNER1, SIGNER2]);
+    assert_eq!(wallet.get_required_confirmations(), 2);
+    assert!(wallet.is_owner(SIGNER2));
+    assert!(!wallet.is_owner(OUTSIDER));
+    assert_eq!(wallet.get_transaction_count(), 0);
+
+    assert_eq!(
+        MultisigWallet::new(vec![SIGNER1, SIGNER2], 3).err(),
+        Some(Error::InvalidRequirement)
+    );
+    assert_eq!(
+        MultisigWallet::new(vec![SIGNER1], 0).err(),
+        Some(Error::InvalidRequirement)
+    );
+    assert_eq!(
+        MultisigWallet::new(vec![], 1).err(),
+        Some(Error::InvalidRequirement)
+    );
+    assert_eq!(
+        MultisigWallet::new(vec![SIGNER1, SIGNER1], 1).err(),
+        Some(Error::AlreadyOwner)
+    );
+}
+
+#[ink::test]
+fn test_propose_transaction() {
+    let mut wallet = setup();
+
+    let to = AccountId::from([0x2; 32]);
+    let value = 100;
+
+    assert_eq!(wallet.propose_transaction(to, value), Ok(0));
+
+    let tx = wallet
+        .get_transaction(0)
+        .expect("transaction was not stored");
+    assert_eq!(tx.action, Action::Transfer { to, value });
+    assert!(!tx.executed);
+    assert_eq!(wallet.get_confirmation_count(0), 0);
+    assert_eq!(wallet.get_confirmations(0), vec![]);
+    assert_eq!(wallet.get_transaction_count(), 1);
+
+    leetcoin_test_kit::assert_events([proposal_event(&wallet, 0, SIGNER1)]);
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_topic(&events[0], &0u32);
+    leetcoin_test_kit::assert_topic(&events[0], &SIGNER1);
+
+    set_caller(OUTSIDER);
+    assert_eq!(
+        wallet.propose_transaction(to, value),
+        Err(Error::Unauthorized)
+    );
+}
+
+#[ink::test]
+fn test_confirm_transaction() {
+    let mut wallet = setup();
+    wallet
+        .propose_transaction(AccountId::from([0x2; 32]), 100)
+        .unwrap();
+
+    assert_eq!(wallet.confirm_transaction(0), Ok(()));
+    assert_eq!(wallet.get_confirmations(0), vec![SIGNER1]);
+    assert!(!wallet.is_confirmed(0));
+    assert_eq!(wallet.confirm_transaction(0), Err(Error::AlreadyConfirmed));
+
+    set_caller(SIGNER2);
+    assert_eq!(wallet.confirm_transaction(0), Ok(()));
+    assert_eq!(wallet.get_confirmations(0), vec![SIGNER1, SIGNER2]);
+    assert!(wallet.is_confirmed(0));
+    assert_eq!(wallet.get_confirmation_count(0), 2);
+
+    leetcoin_test_kit::assert_events([
+        proposal_event(&wallet, 0, SIGNER1),
+        event(ConfirmTransaction {
+            id: 0,
+            owner: SIGNER1,
+        }),
+        event(ConfirmTransaction {
+            id: 0,
+            owner: SIGNER2,
+        }),
+    ]);
+}
+
+#[ink::test]
+fn test_revoke_confirmation() {
+    let mut wallet = setup();
+    wallet
+        .propose_transaction(AccountId::from([0x2; 32]), 100)
+        .unwrap();
+
+    assert_eq!(wallet.revoke_confirmation(0), Err(Error::NotConfirmed));
+    wallet.confirm_transaction(0).unwrap();
+    assert_eq!(wallet.revoke_confirmation(0), Ok(()));
+    assert_eq!(wallet.get_confirmations(0), vec![]);
+    assert_eq!(wallet.get_confirmation_count(0), 0);
+
+    let events = leetcoin_test_kit::decode_events::<RevokeConfirmation>();
+    assert_eq!(events.len(), 1);
+    assert_eq!((events[0].id, events[0].owner), (0, SIGNER1));
+}
+
+#[ink::test]
+fn test_execute_transaction() {
+    let mut wallet = setup();
+
+    let to = AccountId::from([0x2; 32]);
+    let id = approve(&mut wallet, |wallet| {
+        wallet.propose_transaction(to, 100).unwrap()
+    });
+
+    assert_eq!(wallet.execute_transaction(id), Ok(()));
+    assert!(wallet.get_transaction(id).unwrap().executed);
+    assert_eq!(wallet.get_confirmation_count(id), 2);
+    assert_eq!(balance_of(to), 1100);
+
+    assert_eq!(leetcoin_test_kit::recorded_events().len(), 4);
+    assert!(matches!(
+        leetcoin_test_kit::decode_events::<ExecuteTransaction>()[..],
+        [ExecuteTransaction {
+            id: 0,
+            action: Action::Transfer { value: 100, .. },
+        }]
+    ));
+}
+
+#[ink::test]
+fn test_add_owner() {
+    let mut wallet = setup();
+
+    let id = approve(&mut wallet, |wallet| {
+        wallet.propose_add_owner(OUTSIDER).unwrap()
+    });
+    wallet.execute_transaction(id).unwrap();
+
+    assert_eq!(wallet.get_owners(), vec![SIGNER1, SIGNER2, OUTSIDER]);
+    let events = leetcoin_test_kit::recorded_events();
+    assert_eq!(events.len(), 5);
+    leetcoin_test_kit::assert_signature::<AddOwner>(&events[3]);
+    leetcoin_test_kit::assert_topic(&events[3], &OUTSIDER);
+
+    let id = approve(&mut wallet, |wallet| {
+        wallet.propose_add_owner(OUTSIDER).unwrap()
+    });
+    assert_eq!(wallet.execute_transaction(id), Err(Error::AlreadyOwner));
+    assert!(!wallet.get_transaction(id).unwrap().executed);
+}
+
+#[ink::test]
+fn test_remove_owner() {
+    let mut wallet = setup();
+
+    let add = approve(&mut wallet, |wallet| {
+        wallet.propose_add_owner(OUTSIDER).unwrap()
+    });
+    wallet.execute_transaction(add).unwrap();
+
+    let pending = approve(&mut wallet, |wallet| {
+        wallet
+            .propose_transaction(AccountId::from([0x2; 32]), 100)
+            .unwrap()
+    });
+    let remove = approve(&mut wallet, |wallet| {
+        wallet.propose_remove_owner(SIGNER2).unwrap()
+    });
+    wallet.execute_transaction(remove).unwrap();
+
+    assert_eq!(wallet.get_owners(), vec![SIGNER1, OUTSIDER]);
+    assert_eq!(
+        wallet.execute_transaction(pending),
+        Err(Error::Unauthorized)
+    );
+    // The removed owner's confirmation no longer counts.
+    assert_eq!(wallet.get_confirmations(pending), vec![SIGNER1]);
+    assert_eq!(wallet.get_confirmation_count(pending), 1);
+    assert!(!wallet.is_confirmed(pending));
+    assert!(matches!(
+        leetcoin_test_kit::decode_events::<RemoveOwner>()[..],
+        [RemoveOwner { owner: SIGNER2 }]
+    ));
+}
+
+#[ink::test]
+fn test_remove_owner_keeps_requirement_reachable() {
+    let mut wallet = setup();
+
+    let id = approve(&mut wallet, |wallet| {
+        wallet.propose_remove_owner(SIGNER2).unwrap()
+    });
+    assert_eq!(
+        wallet.execute_transaction(id),
+        Err(Error::InvalidRequirement)
+    );
+
+    let id = approve(&mut wallet, |wallet| {
+        wallet.propose_remove_owner(OUTSIDER).unwrap()
+    });
+    assert_eq!(wallet.execute_transaction(id), Err(Error::NotOwner));
+    assert_eq!(wallet.get_owners(), vec![SIGNER1, SIGNER2]);
+}
+
+#[ink::test]
+fn test_change_requirement() {
+    let mut wallet = setup();
+
+    let id = approve(&mut wallet, |wallet| {
+        wallet.propose_change_requirement(3).unwrap()
+    });
+    assert_eq!(
+        wallet.execute_transaction(id),
+        Err(Error::InvalidRequirement)
+    );
+
+    let id = approve(&mut wallet, |wallet| {
+        wallet.propose_change_requirement(1).unwrap()
+    });
+    assert_eq!(wallet.execute_transaction(id), Ok(()));
+    assert_eq!(wallet.get_required_confirmations(), 1);
+    assert!(matches!(
+        leetcoin_test_kit::decode_events::<ChangeRequirement>()[..],
+        [ChangeRequirement {
+            required_confirmations: 1
+        }]
+    ));
+
+    wallet
+        .propose_transaction(AccountId::from([0x2; 32]), 100)
+        .unwrap();
+    wallet.confirm_transaction(2).unwrap();
+    assert_eq!(wallet.execute_transaction(2), Ok(()));
+}
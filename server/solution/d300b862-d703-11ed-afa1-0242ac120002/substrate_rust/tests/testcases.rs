@@ -0,0 +1,120 @@
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{json, Args, Contract, Event, ReplayError, ToValue, Value};
+use multisig_wallet::{
+    Action, AddOwner, ChangeRequirement, ConfirmTransaction, ExecuteTransaction, MultisigWallet,
+    ProposeTransaction, RemoveOwner, RevokeConfirmation, Transaction,
+};
+
+/// Binds `testcase.txt` to [`MultisigWallet`].
+struct Replay(MultisigWallet);
+
+/// Written as `{"Transfer": {"to", "value"}}`, `{"AddOwner": account}`, ...
+fn action_value(action: &Action) -> Value {
+    match action {
+        Action::Transfer { to, value } => json!({
+            "Transfer": {"to": to.to_value(), "value": value.to_value()}
+        }),
+        Action::AddOwner(owner) => json!({ "AddOwner": owner.to_value() }),
+        Action::RemoveOwner(owner) => json!({ "RemoveOwner": owner.to_value() }),
+        Action::ChangeRequirement(required) => json!({ "ChangeRequirement": required }),
+    }
+}
+
+fn transaction_value(tx: &Transaction) -> Value {
+    json!({
+        "action": action_value(&tx.action),
+        "executed": tx.executed,
+    })
+}
+
+impl Contract for Replay {
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => MultisigWallet::new(args.accounts(0)?, args.u32(1)?)
+                .map(Replay)
+                .map_err(|err| ReplayError::new(format!("`new` failed with {:?}", err))),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "propose_transaction" => Ok(self
+                .0
+                .propose_transaction(args.account(0)?, args.balance(1)?)
+                .to_value()),
+            "propose_add_owner" => Ok(self.0.propose_add_owner(args.account(0)?).to_value()),
+            "propose_remove_owner" => Ok(self.0.propose_remove_owner(args.account(0)?).to_value()),
+            "propose_change_requirement" => {
+                Ok(self.0.propose_change_requirement(args.u32(0)?).to_value())
+            }
+            "confirm_transaction" => Ok(self.0.confirm_transaction(args.u32(0)?).to_value()),
+            "revoke_confirmation" => Ok(self.0.revoke_confirmation(args.u32(0)?).to_value()),
+            "execute_transaction" => Ok(self.0.execute_transaction(args.u32(0)?).to_value()),
+            "get_owners" => Ok(self.0.get_owners().to_value()),
+            "is_owner" => Ok(self.0.is_owner(args.account(0)?).to_value()),
+            "get_required_confirmations" => Ok(self.0.get_required_confirmations().to_value()),
+            "is_confirmed" => Ok(self.0.is_confirmed(args.u32(0)?).to_value()),
+            "get_transaction_count" => Ok(self.0.get_transaction_count().to_value()),
+            "get_transaction" => Ok(self
+                .0
+                .get_transaction(args.u32(0)?)
+                .as_ref()
+                .map_or(Value::Null, transaction_value)),
+            "get_confirmations" => Ok(self.0.get_confirmations(args.u32(0)?).to_value()),
+            "get_confirmation_count" => Ok(self.0.get_confirmation_count(args.u32(0)?).to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        if let Some(event) = decode_event::<ProposeTransaction>(event) {
+            return Some(
+                Event::new("ProposeTransaction")
+                    .field("id", event.id)
+                    .field("proposer", event.proposer)
+                    .field("tx_hash", event.tx_hash)
+                    .field("action", action_value(&event.action)),
+            );
+        }
+        if let Some(event) = decode_event::<ConfirmTransaction>(event) {
+            return Some(
+                Event::new("ConfirmTransaction")
+                    .field("id", event.id)
+                    .field("owner", event.owner),
+            );
+        }
+        if let Some(event) = decode_event::<RevokeConfirmation>(event) {
+            return Some(
+                Event::new("RevokeConfirmation")
+                    .field("id", event.id)
+                    .field("owner", event.owner),
+            );
+        }
+        if let Some(event) = decode_event::<ExecuteTransaction>(event) {
+            return Some(
+                Event::new("ExecuteTransaction")
+                    .field("id", event.id)
+                    .field("action", action_value(&event.action)),
+            );
+        }
+        if let Some(event) = decode_event::<AddOwner>(event) {
+            return Some(Event::new("AddOwner").field("owner", event.owner));
+        }
+        if let Some(event) = decode_event::<RemoveOwner>(event) {
+            return Some(Event::new("RemoveOwner").field("owner", event.owner));
+        }
+        if let Some(event) = decode_event::<ChangeRequirement>(event) {
+            return Some(
+                Event::new("ChangeRequirement")
+                    .field("required_confirmations", event.required_confirmations),
+            );
+        }
+        None
+    }
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
@@ -0,0 +1,349 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::decentralized_oracle::{
+    CancelRequest, DecentralizedOracle, Error, FinalizeData, ProvideData, Request, RequestData,
+    MAX_KEY_LEN, MAX_REPORTERS,
+};
+
+#[ink::contract]
+mod decentralized_oracle {
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// The longest key that may be requested, in bytes. Every request stores its key.
+    pub const MAX_KEY_LEN: u32 = 64;
+    /// The most reporters an oracle may have, they are stored in a single cell.
+    pub const MAX_REPORTERS: u32 = 32;
+
+    /// A request for the value of `key`, paid for with `fee`.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Request {
+        pub requester: AccountId,
+        pub key: String,
+        pub fee: Balance,
+        /// Reports are accepted up to, but excluding, this block. Once it is reached
+        /// without a quorum, the requester can cancel the request.
+        pub deadline: BlockNumber,
+        /// The values submitted so far and the reporters that submitted them.
+        pub reports: Vec<(AccountId, u128)>,
+        /// The median of the reports, once `quorum` reporters submitted a value.
+        pub value: Option<u128>,
+    }
+
+    /// An oracle answering data requests through a fixed set of reporters.
+    ///
+    /// A request is finalized at the median of the first `quorum` values reported
+    /// for it, and its fee is split between those reporters, who withdraw their
+    /// rewards later on. A request that does not reach the quorum within
+    /// `request_timeout` blocks can be cancelled, paying its fee back.
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct DecentralizedOracle {
+        reporters: Vec<AccountId>,
+        quorum: u32,
+        request_timeout: BlockNumber,
+        requests: Mapping<u32, Request>,
+        request_count: u32,
+        /// The value of each key along with the request it was finalized for, a value
+        /// is only replaced by that of a later request.
+        data: Mapping<String, (u32, u128)>,
+        /// The fees each reporter earned and did not withdraw yet.
+        rewards: Mapping<AccountId, Balance>,
+    }
+
+    #[ink(event)]
+    pub struct RequestData {
+        #[ink(topic)]
+        pub requester: AccountId,
+        pub request_id: u32,
+        pub key: String,
+        pub fee: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ProvideData {
+        #[ink(topic)]
+        pub reporter: AccountId,
+        pub request_id: u32,
+        pub value: u128,
+    }
+
+    #[ink(event)]
+    pub struct FinalizeData {
+        pub request_id: u32,
+        pub key: String,
+        pub value: u128,
+    }
+
+    #[ink(event)]
+    pub struct CancelRequest {
+        #[ink(topic)]
+        pub requester: AccountId,
+        pub request_id: u32,
+        /// The fee paid back to the requester.
+        pub refund: Balance,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// The quorum must be at least one and at most the number of reporters.
+        InvalidQuorum,
+        /// Requests must stay open for at least one block.
+        InvalidTimeout,
+        DuplicateReporter,
+        /// More than `MAX_REPORTERS` reporters.
+        TooManyReporters,
+        /// The key is longer than `MAX_KEY_LEN` bytes.
+        KeyTooLong,
+        /// A request must be paid for.
+        InsufficientFee,
+        NotReporter,
+        /// Only the requester can cancel a request.
+        NotRequester,
+        RequestNotFound,
+        AlreadyReported,
+        AlreadyFinalized,
+        /// The deadline of the request was reached, it no longer accepts reports.
+        RequestExpired,
+        /// The request can only be cancelled once its deadline was reached.
+        RequestNotExpired,
+        NothingToWithdraw,
+        TransferFailed,
+        /// A request id, deadline or reward would leave the range of its type.
+        Overflow,
+    }
+
+    impl DecentralizedOracle {
+        /// Creates an oracle served by `reporters`, finalizing requests once `quorum`
+        /// of them reported within `request_timeout` blocks.
+        #[ink(constructor)]
+        pub fn new(
+            reporters: Vec<AccountId>,
+            quorum: u32,
+            request_timeout: BlockNumber,
+        ) -> Result<Self, Error> {
+            if reporters.len() > MAX_REPORTERS as usize {
+                return Err(Error::TooManyReporters);
+            }
+            let mut oracle = Self::default();
+            for reporter in reporters {
+                if oracle.reporters.contains(&reporter) {
+                    return Err(Error::DuplicateReporter);
+                }
+                oracle.reporters.push(reporter);
+            }
+            if quorum == 0 || quorum as usize > oracle.reporters.len() {
+                return Err(Error::InvalidQuorum);
+            }
+            if request_timeout == 0 {
+                return Err(Error::InvalidTimeout);
+            }
+            oracle.quorum = quorum;
+            oracle.request_timeout = request_timeout;
+            Ok(oracle)
+        }
+
+        /// Requests the value of `key`, paying the value sent along as the fee.
+        /// Returns the id of the request.
+        #[ink(message, payable)]
+        pub fn request_data(&mut self, key: String) -> Result<u32, Error> {
+            if key.len() > MAX_KEY_LEN as usize {
+                return Err(Error::KeyTooLong);
+            }
+            let fee = self.env().transferred_value();
+            if fee == 0 {
+                return Err(Error::InsufficientFee);
+            }
+            let deadline = self
+                .env()
+                .block_number()
+                .checked_add(self.request_timeout)
+                .ok_or(Error::Overflow)?;
+            let request_id = self.request_count;
+            self.request_count = request_id.checked_add(1).ok_or(Error::Overflow)?;
+
+            let requester = self.env().caller();
+            self.requests.insert(
+                request_id,
+                &Request {
+                    requester,
+                    key: key.clone(),
+                    fee,
+                    deadline,
+                    reports: Vec::new(),
+                    value: None,
+                },
+            );
+            self.env().emit_event(RequestData {
+                requester,
+                request_id,
+                key,
+                fee,
+            });
+            Ok(request_id)
+        }
+
+        /// Reports `value` for request `request_id`, finalizing the request if this is
+        /// the last report the quorum needs.
+        #[ink(message)]
+        pub fn provide_data(&mut self, request_id: u32, value: u128) -> Result<(), Error> {
+            let reporter = self.env().caller();
+            if !self.reporters.contains(&reporter) {
+                return Err(Error::NotReporter);
+            }
+            let mut request = self
+                .requests
+                .get(request_id)
+                .ok_or(Error::RequestNotFound)?;
+            if request.value.is_some() {
+                return Err(Error::AlreadyFinalized);
+            }
+            if self.env().block_number() >= request.deadline {
+                return Err(Error::RequestExpired);
+            }
+            if request.reports.iter().any(|(by, _)| *by == reporter) {
+                return Err(Error::AlreadyReported);
+            }
+
+            request.reports.push((reporter, value));
+            self.env().emit_event(ProvideData {
+                reporter,
+                request_id,
+                value,
+            });
+            if request.reports.len() == self.quorum as usize {
+                self.finalize(request_id, &mut request)?;
+            }
+            self.requests.insert(request_id, &request);
+            Ok(())
+        }
+
+        /// Cancels request `request_id`, which did not reach the quorum before its
+        /// deadline, and pays its fee back to the requester.
+        #[ink(message)]
+        pub fn cancel_request(&mut self, request_id: u32) -> Result<(), Error> {
+            let request = self
+                .requests
+                .get(request_id)
+                .ok_or(Error::RequestNotFound)?;
+            let requester = self.env().caller();
+            if requester != request.requester {
+                return Err(Error::NotRequester);
+            }
+            if request.value.is_some() {
+                return Err(Error::AlreadyFinalized);
+            }
+            if self.env().block_number() < request.deadline {
+                return Err(Error::RequestNotExpired);
+            }
+
+            self.env()
+                .transfer(requester, request.fee)
+                .map_err(|_| Error::TransferFailed)?;
+            self.requests.remove(request_id);
+            self.env().emit_event(CancelRequest {
+                requester,
+                request_id,
+                refund: request.fee,
+            });
+            Ok(())
+        }
+
+        /// Returns the value of `key` finalized for the latest request.
+        #[ink(message)]
+        pub fn get_data(&self, key: String) -> Option<u128> {
+            self.data.get(key).map(|(_, value)| value)
+        }
+
+        /// Pays the caller the fees they earned as a reporter.
+        #[ink(message)]
+        pub fn withdraw_rewards(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let amount = self.rewards.take(caller).ok_or(Error::NothingToWithdraw)?;
+            if self.env().transfer(caller, amount).is_err() {
+                self.rewards.insert(caller, &amount);
+                return Err(Error::TransferFailed);
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_request(&self, request_id: u32) -> Option<Request> {
+            self.requests.get(request_id)
+        }
+
+        #[ink(message)]
+        pub fn get_rewards(&self, reporter: AccountId) -> Balance {
+            self.rewards.get(reporter).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        pub fn get_reporters(&self) -> Vec<AccountId> {
+            self.reporters.clone()
+        }
+
+        #[ink(message)]
+        pub fn get_quorum(&self) -> u32 {
+            self.quorum
+        }
+
+        #[ink(message)]
+        pub fn get_request_timeout(&self) -> BlockNumber {
+            self.request_timeout
+        }
+
+        /// Settles `request` at the median of its reports and splits its fee between
+        /// the reporters, the first reporter also receiving what does not divide evenly.
+        ///
+        /// The value of the request's key is only replaced if no later request for it
+        /// was finalized first.
+        fn finalize(&mut self, request_id: u32, request: &mut Request) -> Result<(), Error> {
+            let mut values: Vec<u128> = request.reports.iter().map(|(_, value)| *value).collect();
+            values.sort_unstable();
+            let middle = values.len() / 2;
+            let value = if values.len().is_multiple_of(2) {
+                let (low, high) = (values[middle - 1], values[middle]);
+                low + (high - low) / 2
+            } else {
+                values[middle]
+            };
+
+            let reporters = request.reports.len() as Balance;
+            let share = request.fee / reporters;
+            let remainder = request.fee % reporters;
+            let mut rewards = Vec::with_capacity(request.reports.len());
+            for (index, (reporter, _)) in request.reports.iter().enumerate() {
+                let reward = if index == 0 { share + remainder } else { share };
+                let earned = self
+                    .get_rewards(*reporter)
+                    .checked_add(reward)
+                    .ok_or(Error::Overflow)?;
+                rewards.push((*reporter, earned));
+            }
+            for (reporter, earned) in rewards {
+                self.rewards.insert(reporter, &earned);
+            }
+
+            request.value = Some(value);
+            let is_latest = self
+                .data
+                .get(&request.key)
+                .is_none_or(|(latest, _)| request_id > latest);
+            if is_latest {
+                self.data.insert(&request.key, &(request_id, value));
+            }
+            self.env().emit_event(FinalizeData {
+                request_id,
+                key: request.key.clone(),
+                value,
+            });
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use decentralized_oracle::DecentralizedOracle;
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{set_callee, set_caller, transfer_in};
+use proptest::prelude::*;
+
+const CONTRACT: AccountId = AccountId([0xFF; 32]);
+
+fn reporters() -> Vec<AccountId> {
+    (1..=5).map(|id| AccountId::from([id; 32])).collect()
+}
+
+#[ink::test]
+fn test_rewards_add_up_across_requests() {
+    set_callee(CONTRACT);
+    let reporters = reporters();
+    let mut oracle = DecentralizedOracle::new(reporters.clone(), 2, 10).unwrap();
+    for (request_id, fee) in [(0, 10), (1, 7)] {
+        set_caller(reporters[0]);
+        transfer_in(fee);
+        oracle.request_data("ETH/USD".to_string()).unwrap();
+        for reporter in &reporters[1..3] {
+            set_caller(*reporter);
+            oracle.provide_data(request_id, 1).unwrap();
+        }
+    }
+
+    // the whole fee pool is handed out, nothing more
+    assert_eq!(oracle.get_rewards(reporters[1]), 5 + 4);
+    assert_eq!(oracle.get_rewards(reporters[2]), 5 + 3);
+    assert_eq!(oracle.get_rewards(reporters[0]), 0);
+}
+
+#[ink::test]
+fn test_earlier_request_does_not_replace_later_value() {
+    set_callee(CONTRACT);
+    let reporters = reporters();
+    let mut oracle = DecentralizedOracle::new(reporters.clone(), 2, 10).unwrap();
+    set_caller(reporters[0]);
+    for _ in 0..2 {
+        transfer_in(10);
+        oracle.request_data("ETH/USD".to_string()).unwrap();
+    }
+
+    // The later request is settled first, the earlier one must not overwrite it.
+    for (request_id, value) in [(1, 200), (0, 100)] {
+        for reporter in &reporters[1..3] {
+            set_caller(*reporter);
+            oracle.provide_data(request_id, value).unwrap();
+        }
+    }
+    assert_eq!(oracle.get_request(0).unwrap().value, Some(100));
+    assert_eq!(oracle.get_data("ETH/USD".to_string()), Some(200));
+}
+
+proptest! {
+    /// Whatever the reports, at least half of them lie on either side of the median.
+    #[test]
+    fn test_median_is_between_reports(values in prop::collection::vec(any::<u128>(), 1..=5)) {
+        leetcoin_test_kit::off_chain(|| {
+            set_callee(CONTRACT);
+            let reporters = reporters();
+            let mut oracle =
+                DecentralizedOracle::new(reporters.clone(), values.len() as u32, 10).unwrap();
+            set_caller(reporters[0]);
+            transfer_in(1);
+            oracle.request_data("BTC/USD".to_string()).unwrap();
+            for (reporter, value) in reporters.iter().zip(&values) {
+                set_caller(*reporter);
+                oracle.provide_data(0, *value).unwrap();
+            }
+
+            let median = oracle.get_data("BTC/USD".to_string()).unwrap();
+            let below = values.iter().filter(|value| **value <= median).count();
+            let above = values.iter().filter(|value| **value >= median).count();
+            assert!(below * 2 >= values.len());
+            assert!(above * 2 >= values.len());
+        });
+    }
+}
@@ -0,0 +1,303 @@
+use decentralized_oracle::{
+    CancelRequest, DecentralizedOracle, Error, FinalizeData, ProvideData, Request, RequestData,
+    MAX_KEY_LEN, MAX_REPORTERS,
+};
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{balance_of, event, set_block_number, set_callee, set_caller, transfer_in};
+
+/// Requests data, starting with a balance of 1000000 in the off-chain environment.
+const ALICE: AccountId = AccountId([0x1; 32]);
+/// Starts with a balance of 1000 in the off-chain environment.
+const BOB: AccountId = AccountId([0x2; 32]);
+const CHARLIE: AccountId = AccountId([0x3; 32]);
+const DJANGO: AccountId = AccountId([0x4; 32]);
+const EVE: AccountId = AccountId([0x5; 32]);
+/// The off-chain environment deploys at alice's account unless told otherwise.
+const CONTRACT: AccountId = AccountId([0xFF; 32]);
+const KEY: &str = "BTC/USD";
+/// Requests accept reports for this many blocks.
+const TIMEOUT: u32 = 10;
+
+/// An oracle served by bob, charlie, django and eve, finalizing after three reports.
+fn setup() -> DecentralizedOracle {
+    set_callee(CONTRACT);
+    DecentralizedOracle::new(vec![BOB, CHARLIE, DJANGO, EVE], 3, TIMEOUT).unwrap()
+}
+
+/// Requests `KEY` as alice, paying `fee`.
+fn request(oracle: &mut DecentralizedOracle, fee: u128) -> Result<u32, Error> {
+    set_caller(ALICE);
+    transfer_in(fee);
+    oracle.request_data(KEY.to_string())
+}
+
+fn provide(
+    oracle: &mut DecentralizedOracle,
+    reporter: AccountId,
+    request_id: u32,
+    value: u128,
+) -> Result<(), Error> {
+    set_caller(reporter);
+    oracle.provide_data(request_id, value)
+}
+
+#[ink::test]
+fn test_new() {
+    let oracle = setup();
+    assert_eq!(oracle.get_reporters(), vec![BOB, CHARLIE, DJANGO, EVE]);
+    assert_eq!(oracle.get_quorum(), 3);
+    assert_eq!(oracle.get_request_timeout(), TIMEOUT);
+
+    for quorum in [0, 3] {
+        assert_eq!(
+            DecentralizedOracle::new(vec![BOB, CHARLIE], quorum, TIMEOUT).err(),
+            Some(Error::InvalidQuorum)
+        );
+    }
+    assert_eq!(
+        DecentralizedOracle::new(vec![BOB, CHARLIE, BOB], 2, TIMEOUT).err(),
+        Some(Error::DuplicateReporter)
+    );
+    assert_eq!(
+        DecentralizedOracle::new(vec![BOB, CHARLIE], 2, 0).err(),
+        Some(Error::InvalidTimeout)
+    );
+
+    let reporters: Vec<AccountId> = (0..=MAX_REPORTERS as u8)
+        .map(|index| AccountId([index; 32]))
+        .collect();
+    assert_eq!(
+        DecentralizedOracle::new(reporters.clone(), 1, TIMEOUT).err(),
+        Some(Error::TooManyReporters)
+    );
+    let oracle = DecentralizedOracle::new(reporters[1..].to_vec(), MAX_REPORTERS, TIMEOUT).unwrap();
+    assert_eq!(oracle.get_reporters().len(), MAX_REPORTERS as usize);
+}
+
+#[ink::test]
+fn test_request_data() {
+    let mut oracle = setup();
+    assert_eq!(request(&mut oracle, 0), Err(Error::InsufficientFee));
+    assert_eq!(request(&mut oracle, 300), Ok(0));
+    assert_eq!(request(&mut oracle, 600), Ok(1));
+    assert_eq!(
+        oracle.get_request(0),
+        Some(Request {
+            requester: ALICE,
+            key: KEY.to_string(),
+            fee: 300,
+            deadline: TIMEOUT,
+            reports: vec![],
+            value: None,
+        })
+    );
+    assert_eq!(balance_of(CONTRACT), 900);
+    assert_eq!(oracle.get_data(KEY.to_string()), None);
+
+    let events = leetcoin_test_kit::recorded_events();
+    assert_eq!(events.len(), 2);
+    leetcoin_test_kit::assert_topic(&events[0], &ALICE);
+    assert!(matches!(
+        &leetcoin_test_kit::decode_events::<RequestData>()[..],
+        [
+            RequestData {
+                request_id: 0,
+                fee: 300,
+                ..
+            },
+            RequestData {
+                request_id: 1,
+                fee: 600,
+                ..
+            }
+        ]
+    ));
+}
+
+#[ink::test]
+fn test_request_data_key_too_long() {
+    let mut oracle = setup();
+    set_caller(ALICE);
+    transfer_in(300);
+    assert_eq!(
+        oracle.request_data("k".repeat(MAX_KEY_LEN as usize + 1)),
+        Err(Error::KeyTooLong)
+    );
+    assert_eq!(oracle.get_request(0), None);
+
+    transfer_in(300);
+    assert_eq!(oracle.request_data("k".repeat(MAX_KEY_LEN as usize)), Ok(0));
+}
+
+#[ink::test]
+fn test_provide_data() {
+    let mut oracle = setup();
+    request(&mut oracle, 300).unwrap();
+
+    assert_eq!(provide(&mut oracle, ALICE, 0, 100), Err(Error::NotReporter));
+    assert_eq!(
+        provide(&mut oracle, BOB, 1, 100),
+        Err(Error::RequestNotFound)
+    );
+    assert_eq!(provide(&mut oracle, BOB, 0, 100), Ok(()));
+    assert_eq!(
+        provide(&mut oracle, BOB, 0, 200),
+        Err(Error::AlreadyReported)
+    );
+    assert_eq!(
+        oracle.get_request(0).map(|request| request.reports),
+        Some(vec![(BOB, 100)])
+    );
+    assert_eq!(oracle.get_data(KEY.to_string()), None);
+
+    leetcoin_test_kit::assert_events([
+        event(RequestData {
+            requester: ALICE,
+            request_id: 0,
+            key: KEY.to_string(),
+            fee: 300,
+        }),
+        event(ProvideData {
+            reporter: BOB,
+            request_id: 0,
+            value: 100,
+        }),
+    ]);
+}
+
+#[ink::test]
+fn test_finalize_at_median() {
+    let mut oracle = setup();
+    request(&mut oracle, 300).unwrap();
+    provide(&mut oracle, BOB, 0, 30_150).unwrap();
+    provide(&mut oracle, CHARLIE, 0, 90_000).unwrap();
+    assert_eq!(oracle.get_request(0).unwrap().value, None);
+
+    // The outlier reported by charlie does not move the median.
+    provide(&mut oracle, DJANGO, 0, 30_100).unwrap();
+    assert_eq!(oracle.get_request(0).unwrap().value, Some(30_150));
+    assert_eq!(oracle.get_data(KEY.to_string()), Some(30_150));
+    assert_eq!(
+        provide(&mut oracle, EVE, 0, 30_000),
+        Err(Error::AlreadyFinalized)
+    );
+
+    let events = leetcoin_test_kit::recorded_events();
+    assert_eq!(events.len(), 5);
+    leetcoin_test_kit::assert_signature::<FinalizeData>(&events[4]);
+    assert!(matches!(
+        &leetcoin_test_kit::decode_events::<FinalizeData>()[..],
+        [FinalizeData {
+            request_id: 0,
+            value: 30_150,
+            ..
+        }]
+    ));
+}
+
+#[ink::test]
+fn test_even_quorum() {
+    set_callee(CONTRACT);
+    let mut oracle = DecentralizedOracle::new(vec![BOB, CHARLIE], 2, TIMEOUT).unwrap();
+    request(&mut oracle, 100).unwrap();
+    provide(&mut oracle, BOB, 0, 101).unwrap();
+    provide(&mut oracle, CHARLIE, 0, 200).unwrap();
+
+    // The median of an even number of reports lies halfway, rounded down.
+    assert_eq!(oracle.get_data(KEY.to_string()), Some(150));
+}
+
+#[ink::test]
+fn test_latest_value() {
+    let mut oracle = setup();
+    for (request_id, value) in [(0, 100), (1, 120)] {
+        request(&mut oracle, 300).unwrap();
+        for reporter in [BOB, CHARLIE, DJANGO] {
+            provide(&mut oracle, reporter, request_id, value).unwrap();
+        }
+    }
+    assert_eq!(oracle.get_data(KEY.to_string()), Some(120));
+    assert_eq!(oracle.get_request(0).unwrap().value, Some(100));
+    assert_eq!(oracle.get_data("ETH/USD".to_string()), None);
+}
+
+#[ink::test]
+fn test_withdraw_rewards() {
+    let mut oracle = setup();
+    request(&mut oracle, 100).unwrap();
+    provide(&mut oracle, EVE, 0, 10).unwrap();
+    provide(&mut oracle, BOB, 0, 20).unwrap();
+    assert_eq!(oracle.get_rewards(EVE), 0);
+    provide(&mut oracle, CHARLIE, 0, 30).unwrap();
+
+    // The first reporter receives what does not divide evenly.
+    assert_eq!(oracle.get_rewards(EVE), 34);
+    assert_eq!(oracle.get_rewards(BOB), 33);
+    assert_eq!(oracle.get_rewards(CHARLIE), 33);
+    assert_eq!(oracle.get_rewards(DJANGO), 0);
+
+    set_caller(EVE);
+    assert_eq!(oracle.withdraw_rewards(), Ok(()));
+    assert_eq!(balance_of(EVE), 34);
+    assert_eq!(oracle.get_rewards(EVE), 0);
+    assert_eq!(oracle.withdraw_rewards(), Err(Error::NothingToWithdraw));
+    set_caller(DJANGO);
+    assert_eq!(oracle.withdraw_rewards(), Err(Error::NothingToWithdraw));
+    assert_eq!(balance_of(CONTRACT), 66);
+}
+
+#[ink::test]
+fn test_cancel_request() {
+    let mut oracle = setup();
+    set_block_number(5);
+    request(&mut oracle, 300).unwrap();
+    provide(&mut oracle, BOB, 0, 100).unwrap();
+    assert_eq!(oracle.get_request(0).unwrap().deadline, 5 + TIMEOUT);
+
+    set_block_number(5 + TIMEOUT - 1);
+    set_caller(ALICE);
+    assert_eq!(oracle.cancel_request(0), Err(Error::RequestNotExpired));
+    provide(&mut oracle, CHARLIE, 0, 100).unwrap();
+
+    // Without a quorum by the deadline, the request takes no more reports.
+    set_block_number(5 + TIMEOUT);
+    assert_eq!(
+        provide(&mut oracle, DJANGO, 0, 100),
+        Err(Error::RequestExpired)
+    );
+    set_caller(BOB);
+    assert_eq!(oracle.cancel_request(0), Err(Error::NotRequester));
+    let balance = balance_of(ALICE);
+    set_caller(ALICE);
+    assert_eq!(oracle.cancel_request(0), Ok(()));
+    assert_eq!(balance_of(ALICE), balance + 300);
+    assert_eq!(balance_of(CONTRACT), 0);
+    assert_eq!(oracle.get_request(0), None);
+    assert_eq!(oracle.cancel_request(0), Err(Error::RequestNotFound));
+    assert_eq!(oracle.get_rewards(BOB), 0);
+
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_topic(&events[3], &ALICE);
+    assert!(matches!(
+        leetcoin_test_kit::decode_events::<CancelRequest>()[..],
+        [CancelRequest {
+            request_id: 0,
+            refund: 300,
+            ..
+        }]
+    ));
+}
+
+#[ink::test]
+fn test_cancel_finalized_request() {
+    let mut oracle = setup();
+    request(&mut oracle, 300).unwrap();
+    for reporter in [BOB, CHARLIE, DJANGO] {
+        provide(&mut oracle, reporter, 0, 100).unwrap();
+    }
+
+    set_block_number(TIMEOUT);
+    set_caller(ALICE);
+    assert_eq!(oracle.cancel_request(0), Err(Error::AlreadyFinalized));
+    assert_eq!(balance_of(CONTRACT), 300);
+}
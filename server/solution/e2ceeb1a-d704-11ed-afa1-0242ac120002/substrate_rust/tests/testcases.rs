@@ -0,0 +1,95 @@
+use decentralized_oracle::{
+    CancelRequest, DecentralizedOracle, FinalizeData, ProvideData, Request, RequestData,
+};
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{json, Args, Contract, Event, ReplayError, ToValue, Value};
+
+/// Binds `testcase.txt` to [`DecentralizedOracle`].
+struct Replay(DecentralizedOracle);
+
+fn request_value(request: &Request) -> Value {
+    json!({
+        "requester": request.requester.to_value(),
+        "key": request.key,
+        "fee": request.fee.to_value(),
+        "deadline": request.deadline,
+        "reports": request.reports.to_value(),
+        "value": request.value.to_value(),
+    })
+}
+
+impl Contract for Replay {
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => DecentralizedOracle::new(args.accounts(0)?, args.u32(1)?, args.u32(2)?)
+                .map(Replay)
+                .map_err(|err| ReplayError::new(format!("`new` failed with {:?}", err))),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "request_data" => Ok(self.0.request_data(args.string(0)?).to_value()),
+            "provide_data" => Ok(self
+                .0
+                .provide_data(args.u32(0)?, args.balance(1)?)
+                .to_value()),
+            "cancel_request" => Ok(self.0.cancel_request(args.u32(0)?).to_value()),
+            "get_data" => Ok(self.0.get_data(args.string(0)?).to_value()),
+            "withdraw_rewards" => Ok(self.0.withdraw_rewards().to_value()),
+            "get_request" => Ok(self
+                .0
+                .get_request(args.u32(0)?)
+                .as_ref()
+                .map_or(Value::Null, request_value)),
+            "get_rewards" => Ok(self.0.get_rewards(args.account(0)?).to_value()),
+            "get_reporters" => Ok(self.0.get_reporters().to_value()),
+            "get_quorum" => Ok(self.0.get_quorum().to_value()),
+            "get_request_timeout" => Ok(self.0.get_request_timeout().to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        if let Some(event) = decode_event::<RequestData>(event) {
+            return Some(
+                Event::new("RequestData")
+                    .field("requester", event.requester)
+                    .field("request_id", event.request_id)
+                    .field("key", event.key)
+                    .field("fee", event.fee),
+            );
+        }
+        if let Some(event) = decode_event::<ProvideData>(event) {
+            return Some(
+                Event::new("ProvideData")
+                    .field("reporter", event.reporter)
+                    .field("request_id", event.request_id)
+                    .field("value", event.value),
+            );
+        }
+        if let Some(event) = decode_event::<FinalizeData>(event) {
+            return Some(
+                Event::new("FinalizeData")
+                    .field("request_id", event.request_id)
+                    .field("key", event.key)
+                    .field("value", event.value),
+            );
+        }
+        if let Some(event) = decode_event::<CancelRequest>(event) {
+            return Some(
+                Event::new("CancelRequest")
+                    .field("requester", event.requester)
+                    .field("request_id", event.request_id)
+                    .field("refund", event.refund),
+            );
+        }
+        None
+    }
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
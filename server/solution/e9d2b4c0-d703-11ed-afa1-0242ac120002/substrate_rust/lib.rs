@@ -1,17 +1,90 @@
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-use ink_lang as ink;
+pub use self::counter::{Counter, CounterError, Decremented, Incremented, Reset};
 
 #[ink::contract]
 mod counter {
+    use ink::storage::Mapping;
+
+    /// A counter that moves in steps and stays within `min..=max`.
+    ///
+    /// Next to the global value it keeps, per account, the net amount that account
+    /// moved the counter by, so that `value == init_value + total` always holds.
     #[ink(storage)]
     pub struct Counter {
         value: i32,
+        /// The value the counter started at, restored by `reset`.
+        init_value: i32,
+        min: i32,
+        max: i32,
+        /// The amount `increment` and `decrement` move the counter by.
+        step: u32,
+        /// The deployer, the only account allowed to reset the counter.
+        owner: AccountId,
+        /// The net amount each account moved the counter by.
+        counts: Mapping<AccountId, i64>,
+        /// The sum of all `counts`.
+        total: i64,
+    }
+
+    #[ink(event)]
+    pub struct Incremented {
+        #[ink(topic)]
+        pub caller: AccountId,
+        pub amount: u32,
+        /// The value after the change.
+        pub value: i32,
+    }
+
+    #[ink(event)]
+    pub struct Decremented {
+        #[ink(topic)]
+        pub caller: AccountId,
+        pub amount: u32,
+        /// The value after the change.
+        pub value: i32,
+    }
+
+    #[ink(event)]
+    pub struct Reset {
+        #[ink(topic)]
+        pub caller: AccountId,
+        pub value: i32,
     }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum CounterError {
+        /// The value would leave `min..=max`.
+        OutOfBounds,
+        /// `min` is above `max`, or the initial value lies outside them.
+        InvalidBounds,
+        /// The step must not be zero.
+        InvalidStep,
+        Unauthorized,
+        /// The caller's own count would leave the range of an `i64`.
+        Overflow,
+    }
+
     impl Counter {
         #[ink(constructor)]
-        pub fn new() -> Self {
-            Self { value: 0 }
+        pub fn new(init_value: i32, min: i32, max: i32, step: u32) -> Result<Self, CounterError> {
+            if min > max || !(min..=max).contains(&init_value) {
+                return Err(CounterError::InvalidBounds);
+            }
+            if step == 0 {
+                return Err(CounterError::InvalidStep);
+            }
+            Ok(Self {
+                value: init_value,
+                init_value,
+                min,
+                max,
+                step,
+                owner: Self::env().caller(),
+                counts: Mapping::new(),
+                total: 0,
+            })
         }
 
         #[ink(message)]
@@ -19,57 +92,108 @@ mod counter {
             self.value
         }
 
+        /// Returns `(min, max)`.
         #[ink(message)]
-        pub fn increment(&mut self) {
-            self.value += 1;
+        pub fn get_bounds(&self) -> (i32, i32) {
+            (self.min, self.max)
         }
 
         #[ink(message)]
-        pub fn decrement(&mut self) {
-            self.value -= 1;
+        pub fn get_step(&self) -> u32 {
+            self.step
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-
-        use ink_lang as ink;
+        #[ink(message)]
+        pub fn get_owner(&self) -> AccountId {
+            self.owner
+        }
 
-        #[ink::test]
-        fn test_increment() {
-            let mut counter = Counter::new();
-            assert_eq!(counter.get(), 0);
+        /// Returns the net amount the caller moved the counter by.
+        #[ink(message)]
+        pub fn get_mine(&self) -> i64 {
+            self.get_of(self.env().caller())
+        }
 
-            counter.increment();
-            assert_eq!(counter.get(), 1);
+        #[ink(message)]
+        pub fn get_of(&self, account: AccountId) -> i64 {
+            self.counts.get(account).unwrap_or(0)
+        }
 
-            counter.increment();
-            assert_eq!(counter.get(), 2);
+        /// Returns the sum of the counts of all accounts.
+        #[ink(message)]
+        pub fn total(&self) -> i64 {
+            self.total
         }
 
-        #[ink::test]
-        fn test_decrement() {
-            let mut counter = Counter::new();
-            assert_eq!(counter.get(), 0);
+        /// Moves the counter up by one step.
+        #[ink(message)]
+        pub fn increment(&mut self) -> Result<(), CounterError> {
+            self.increment_by(self.step)
+        }
 
-            counter.decrement();
-            assert_eq!(counter.get(), -1);
+        /// Moves the counter down by one step.
+        #[ink(message)]
+        pub fn decrement(&mut self) -> Result<(), CounterError> {
+            self.decrement_by(self.step)
+        }
 
-            counter.decrement();
-            assert_eq!(counter.get(), -2);
+        #[ink(message)]
+        pub fn increment_by(&mut self, amount: u32) -> Result<(), CounterError> {
+            let caller = self.env().caller();
+            self.move_by(caller, i64::from(amount))?;
+            self.env().emit_event(Incremented {
+                caller,
+                amount,
+                value: self.value,
+            });
+            Ok(())
         }
 
-        #[ink::test]
-        fn test_increment_and_decrement() {
-            let mut counter = Counter::new();
-            assert_eq!(counter.get(), 0);
+        #[ink(message)]
+        pub fn decrement_by(&mut self, amount: u32) -> Result<(), CounterError> {
+            let caller = self.env().caller();
+            self.move_by(caller, -i64::from(amount))?;
+            self.env().emit_event(Decremented {
+                caller,
+                amount,
+                value: self.value,
+            });
+            Ok(())
+        }
 
-            counter.increment();
-            assert_eq!(counter.get(), 1);
+        /// Puts the counter back to its initial value, called by the deployer. The
+        /// change counts as the deployer's own.
+        #[ink(message)]
+        pub fn reset(&mut self) -> Result<(), CounterError> {
+            let caller = self.env().caller();
+            if caller != self.owner {
+                return Err(CounterError::Unauthorized);
+            }
+            let delta = i64::from(self.init_value) - i64::from(self.value);
+            self.move_by(caller, delta)?;
+            self.env().emit_event(Reset {
+                caller,
+                value: self.value,
+            });
+            Ok(())
+        }
 
-            counter.decrement();
-            assert_eq!(counter.get(), 0);
+        /// Moves the counter by `delta` on behalf of `account`. The sums are computed in
+        /// `i64`, as a single move, a reset included, can span more than an `i32`.
+        fn move_by(&mut self, account: AccountId, delta: i64) -> Result<(), CounterError> {
+            let value = i64::from(self.value) + delta;
+            if value < i64::from(self.min) || value > i64::from(self.max) {
+                return Err(CounterError::OutOfBounds);
+            }
+            let count = self
+                .get_of(account)
+                .checked_add(delta)
+                .ok_or(CounterError::Overflow)?;
+            self.value = value as i32;
+            self.counts.insert(account, &count);
+            // Cannot overflow, the total stays equal to `value - init_value`.
+            self.total += delta;
+            Ok(())
         }
     }
 }
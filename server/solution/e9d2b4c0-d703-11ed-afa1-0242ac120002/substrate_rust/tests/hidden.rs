@@ -0,0 +1,132 @@
+use counter::{Counter, CounterError, Incremented};
+use ink::primitives::AccountId;
+use leetcoin_test_kit::set_caller;
+use proptest::prelude::*;
+
+#[ink::test]
+fn test_increment_and_decrement() {
+    let mut counter = Counter::new(0, -10, 10, 1).unwrap();
+    assert_eq!(counter.get(), 0);
+
+    counter.increment().unwrap();
+    assert_eq!(counter.get(), 1);
+
+    counter.decrement().unwrap();
+    assert_eq!(counter.get(), 0);
+}
+
+#[ink::test]
+fn test_anyone_can_count() {
+    let mut counter = Counter::new(0, -10, 10, 1).unwrap();
+    let caller = AccountId::from([0x2; 32]);
+    set_caller(caller);
+
+    assert_eq!(counter.increment_by(3), Ok(()));
+    assert_eq!(counter.reset(), Err(CounterError::Unauthorized));
+    let events = leetcoin_test_kit::decode_events::<Incremented>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].caller, caller);
+}
+
+proptest! {
+    /// Counting up from near `i32::MAX` stops at the maximum instead of wrapping.
+    #[test]
+    fn test_increment_never_wraps(offset in 0..64i32, steps in 0..128usize) {
+        leetcoin_test_kit::off_chain(|| {
+            let mut counter = Counter::new(i32::MAX - offset, i32::MIN, i32::MAX, 1).unwrap();
+            for _ in 0..steps {
+                let before = counter.get();
+                match counter.increment() {
+                    Ok(()) => assert_eq!(counter.get(), before + 1),
+                    Err(err) => {
+                        assert_eq!(err, CounterError::OutOfBounds);
+                        assert_eq!(before, i32::MAX);
+                        assert_eq!(counter.get(), i32::MAX);
+                    }
+                }
+            }
+            let expected = (i32::MAX - offset).saturating_add(steps as i32);
+            assert_eq!(counter.get(), expected);
+        });
+    }
+
+    #[test]
+    fn test_decrement_never_wraps(offset in 0..64i32, steps in 0..128usize) {
+        leetcoin_test_kit::off_chain(|| {
+            let mut counter = Counter::new(i32::MIN + offset, i32::MIN, i32::MAX, 1).unwrap();
+            for _ in 0..steps {
+                let before = counter.get();
+                match counter.decrement() {
+                    Ok(()) => assert_eq!(counter.get(), before - 1),
+                    Err(err) => {
+                        assert_eq!(err, CounterError::OutOfBounds);
+                        assert_eq!(before, i32::MIN);
+                        assert_eq!(counter.get(), i32::MIN);
+                    }
+                }
+            }
+            let expected = (i32::MIN + offset).saturating_sub(steps as i32);
+            assert_eq!(counter.get(), expected);
+        });
+    }
+
+    /// Whoever moves the counter, the counts of all accounts add up to the total and
+    /// the total to the distance from the initial value.
+    #[test]
+    fn test_total_stays_consistent(
+        moves in prop::collection::vec((1..=4u8, 0..3u8, 0..8u32), 0..32),
+    ) {
+        leetcoin_test_kit::off_chain(|| {
+            let mut counter = Counter::new(2, -20, 20, 3).unwrap();
+            for (caller, action, amount) in moves {
+                set_caller(AccountId::from([caller; 32]));
+                let _ = match action {
+                    0 => counter.increment_by(amount),
+                    1 => counter.decrement_by(amount),
+                    _ => counter.reset(),
+                };
+                let counts: i64 = (1..=4u8)
+                    .map(|account| counter.get_of(AccountId::from([account; 32])))
+                    .sum();
+                assert_eq!(counts, counter.total());
+                assert_eq!(i64::from(counter.get()), 2 + counter.total());
+            }
+        });
+    }
+
+    /// Arbitrary moves and resets by a few accounts never leave the bounds, and the
+    /// counts keep adding up to the total however far a single move goes.
+    #[test]
+    fn test_stays_within_bounds(
+        (min, max) in (any::<i32>(), any::<i32>()).prop_map(|(a, b)| (a.min(b), a.max(b))),
+        moves in prop::collection::vec((1..=3u8, 0..3u8, any::<u32>()), 0..32),
+    ) {
+        leetcoin_test_kit::off_chain(|| {
+            let mut counter = Counter::new(min, min, max, 1).unwrap();
+            for (caller, action, amount) in moves {
+                // The deployer, alice, is one of the callers.
+                set_caller(AccountId::from([caller; 32]));
+                let before = i64::from(counter.get());
+                let (target, result) = match action {
+                    0 => (before + i64::from(amount), counter.increment_by(amount)),
+                    1 => (before - i64::from(amount), counter.decrement_by(amount)),
+                    _ => (i64::from(min), counter.reset()),
+                };
+                if action == 2 && caller != 1 {
+                    assert_eq!(result, Err(CounterError::Unauthorized));
+                    assert_eq!(i64::from(counter.get()), before);
+                } else if (i64::from(min)..=i64::from(max)).contains(&target) {
+                    assert_eq!(result, Ok(()));
+                    assert_eq!(i64::from(counter.get()), target);
+                } else {
+                    assert_eq!(result, Err(CounterError::OutOfBounds));
+                    assert_eq!(i64::from(counter.get()), before);
+                }
+                let counts: i64 = (1..=3u8)
+                    .map(|account| counter.get_of(AccountId::from([account; 32])))
+                    .sum();
+                assert_eq!(counts, counter.total());
+            }
+        });
+    }
+}
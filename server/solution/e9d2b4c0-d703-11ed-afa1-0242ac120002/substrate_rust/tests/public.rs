@@ -0,0 +1,192 @@
+use counter::{Counter, CounterError, Decremented, Incremented, Reset};
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{event, set_caller};
+
+/// The default caller, which deploys the counter.
+const OWNER: AccountId = AccountId([0x1; 32]);
+const BOB: AccountId = AccountId([0x2; 32]);
+const CHARLIE: AccountId = AccountId([0x3; 32]);
+
+fn setup() -> Counter {
+    Counter::new(0, -10, 10, 1).unwrap()
+}
+
+#[ink::test]
+fn test_new() {
+    let counter = Counter::new(5, -10, 10, 2).unwrap();
+    assert_eq!(counter.get(), 5);
+    assert_eq!(counter.get_bounds(), (-10, 10));
+    assert_eq!(counter.get_step(), 2);
+    assert_eq!(counter.get_owner(), OWNER);
+
+    assert_eq!(
+        Counter::new(0, 10, -10, 1).err(),
+        Some(CounterError::InvalidBounds)
+    );
+    assert_eq!(
+        Counter::new(11, -10, 10, 1).err(),
+        Some(CounterError::InvalidBounds)
+    );
+    assert_eq!(
+        Counter::new(0, -10, 10, 0).err(),
+        Some(CounterError::InvalidStep)
+    );
+}
+
+#[ink::test]
+fn test_increment() {
+    let mut counter = setup();
+    assert_eq!(counter.get(), 0);
+
+    assert_eq!(counter.increment(), Ok(()));
+    assert_eq!(counter.get(), 1);
+
+    assert_eq!(counter.increment(), Ok(()));
+    assert_eq!(counter.get(), 2);
+
+    leetcoin_test_kit::assert_events([
+        event(Incremented {
+            caller: OWNER,
+            amount: 1,
+            value: 1,
+        }),
+        event(Incremented {
+            caller: OWNER,
+            amount: 1,
+            value: 2,
+        }),
+    ]);
+}
+
+#[ink::test]
+fn test_decrement() {
+    let mut counter = setup();
+    assert_eq!(counter.get(), 0);
+
+    assert_eq!(counter.decrement(), Ok(()));
+    assert_eq!(counter.get(), -1);
+
+    assert_eq!(counter.decrement(), Ok(()));
+    assert_eq!(counter.get(), -2);
+
+    let events = leetcoin_test_kit::recorded_events();
+    leetcoin_test_kit::assert_signature::<Decremented>(&events[1]);
+    leetcoin_test_kit::assert_topic(&events[1], &OWNER);
+}
+
+#[ink::test]
+fn test_step() {
+    let mut counter = Counter::new(0, -10, 10, 4).unwrap();
+    assert_eq!(counter.increment(), Ok(()));
+    assert_eq!(counter.increment(), Ok(()));
+    assert_eq!(counter.get(), 8);
+    assert_eq!(counter.increment(), Err(CounterError::OutOfBounds));
+    assert_eq!(counter.increment_by(2), Ok(()));
+    assert_eq!(counter.get(), 10);
+}
+
+#[ink::test]
+fn test_bounds() {
+    let mut counter = setup();
+
+    assert_eq!(counter.increment_by(10), Ok(()));
+    assert_eq!(counter.increment_by(1), Err(CounterError::OutOfBounds));
+    assert_eq!(counter.get(), 10);
+
+    assert_eq!(counter.decrement_by(20), Ok(()));
+    assert_eq!(counter.decrement_by(1), Err(CounterError::OutOfBounds));
+    assert_eq!(counter.get(), -10);
+
+    // Failed calls leave no event behind.
+    assert_eq!(leetcoin_test_kit::recorded_events().len(), 2);
+}
+
+#[ink::test]
+fn test_bounds_at_the_limits_of_i32() {
+    let mut counter = Counter::new(i32::MAX - 1, i32::MIN, i32::MAX, 1).unwrap();
+    assert_eq!(counter.increment(), Ok(()));
+    assert_eq!(counter.increment(), Err(CounterError::OutOfBounds));
+    assert_eq!(
+        counter.increment_by(u32::MAX),
+        Err(CounterError::OutOfBounds)
+    );
+    assert_eq!(counter.get(), i32::MAX);
+
+    // A single move can span more than an `i32`, and so can the caller's own count.
+    set_caller(BOB);
+    assert_eq!(counter.decrement_by(u32::MAX), Ok(()));
+    assert_eq!(counter.get(), i32::MIN);
+    assert_eq!(counter.decrement(), Err(CounterError::OutOfBounds));
+
+    assert_eq!(counter.get_of(BOB), -i64::from(u32::MAX));
+    assert_eq!(
+        counter.total(),
+        i64::from(i32::MIN) - i64::from(i32::MAX - 1)
+    );
+}
+
+#[ink::test]
+fn test_reset_beyond_i32() {
+    let mut counter = Counter::new(i32::MAX, i32::MIN, i32::MAX, 1).unwrap();
+    set_caller(BOB);
+    counter.decrement_by(1 << 31).unwrap();
+    set_caller(CHARLIE);
+    counter.decrement_by(i32::MAX as u32).unwrap();
+    assert_eq!(counter.get(), i32::MIN);
+
+    // The owner's reset moves the counter by more than `i32::MAX`.
+    set_caller(OWNER);
+    assert_eq!(counter.reset(), Ok(()));
+    assert_eq!(counter.get(), i32::MAX);
+    assert_eq!(counter.get_mine(), i64::from(u32::MAX));
+    assert_eq!(counter.get_of(BOB), i64::from(i32::MIN));
+    assert_eq!(counter.total(), 0);
+}
+
+#[ink::test]
+fn test_counts_per_account() {
+    let mut counter = setup();
+    counter.increment_by(5).unwrap();
+    set_caller(BOB);
+    counter.decrement_by(2).unwrap();
+    counter.decrement().unwrap();
+    set_caller(CHARLIE);
+    counter.increment_by(4).unwrap();
+
+    assert_eq!(counter.get_mine(), 4);
+    assert_eq!(counter.get_of(OWNER), 5);
+    assert_eq!(counter.get_of(BOB), -3);
+    assert_eq!(counter.get_of(AccountId::from([0x4; 32])), 0);
+    assert_eq!(counter.total(), 6);
+    assert_eq!(i64::from(counter.get()), counter.total());
+
+    // Failed calls count for nobody.
+    assert_eq!(counter.increment_by(5), Err(CounterError::OutOfBounds));
+    assert_eq!(counter.get_mine(), 4);
+    assert_eq!(counter.total(), 6);
+}
+
+#[ink::test]
+fn test_reset() {
+    let mut counter = Counter::new(3, -10, 10, 1).unwrap();
+
+    set_caller(BOB);
+    counter.increment_by(5).unwrap();
+    assert_eq!(counter.reset(), Err(CounterError::Unauthorized));
+    assert_eq!(counter.get(), 8);
+
+    set_caller(OWNER);
+    assert_eq!(counter.reset(), Ok(()));
+    assert_eq!(counter.get(), 3);
+    // The reset counts as the owner's own change.
+    assert_eq!(counter.get_mine(), -5);
+    assert_eq!(counter.get_of(BOB), 5);
+    assert_eq!(counter.total(), 0);
+    assert_eq!(
+        leetcoin_test_kit::decode_events::<Reset>()
+            .iter()
+            .map(|event| (event.caller, event.value))
+            .collect::<Vec<_>>(),
+        [(OWNER, 3)]
+    );
+}
@@ -0,0 +1,67 @@
+use counter::{Counter, Decremented, Incremented, Reset};
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{Args, Contract, Event, ReplayError, ToValue, Value};
+
+/// Binds `testcase.txt` to [`Counter`].
+struct Replay(Counter);
+
+impl Contract for Replay {
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => Counter::new(args.i32(0)?, args.i32(1)?, args.i32(2)?, args.u32(3)?)
+                .map(Replay)
+                .map_err(|err| ReplayError::new(format!("`new` failed with {:?}", err))),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "get" => Ok(self.0.get().to_value()),
+            "get_bounds" => Ok(self.0.get_bounds().to_value()),
+            "get_step" => Ok(self.0.get_step().to_value()),
+            "get_owner" => Ok(self.0.get_owner().to_value()),
+            "get_mine" => Ok(self.0.get_mine().to_value()),
+            "get_of" => Ok(self.0.get_of(args.account(0)?).to_value()),
+            "total" => Ok(self.0.total().to_value()),
+            "increment" => Ok(self.0.increment().to_value()),
+            "decrement" => Ok(self.0.decrement().to_value()),
+            "increment_by" => Ok(self.0.increment_by(args.u32(0)?).to_value()),
+            "decrement_by" => Ok(self.0.decrement_by(args.u32(0)?).to_value()),
+            "reset" => Ok(self.0.reset().to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        if let Some(event) = decode_event::<Incremented>(event) {
+            return Some(
+                Event::new("Incremented")
+                    .field("caller", event.caller)
+                    .field("amount", event.amount)
+                    .field("value", event.value),
+            );
+        }
+        if let Some(event) = decode_event::<Decremented>(event) {
+            return Some(
+                Event::new("Decremented")
+                    .field("caller", event.caller)
+                    .field("amount", event.amount)
+                    .field("value", event.value),
+            );
+        }
+        if let Some(event) = decode_event::<Reset>(event) {
+            return Some(
+                Event::new("Reset")
+                    .field("caller", event.caller)
+                    .field("value", event.value),
+            );
+        }
+        None
+    }
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
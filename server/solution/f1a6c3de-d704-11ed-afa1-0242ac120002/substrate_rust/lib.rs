@@ -0,0 +1,303 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use self::milestone_escrow::{
+    Error, EscrowStatus, LockFunds, Milestone, MilestoneEscrow, MilestoneStatus, RefundMilestone,
+    ReleaseMilestone, MAX_MILESTONES,
+};
+
+#[ink::contract]
+mod milestone_escrow {
+    use ink::prelude::vec::Vec;
+
+    /// The most milestones an escrow may have, all of them are stored in a single cell.
+    pub const MAX_MILESTONES: u32 = 32;
+
+    /// An escrow paying the seller in stages: the buyer deposits the sum of all
+    /// milestones up front and approves them one at a time.
+    #[ink(storage)]
+    pub struct MilestoneEscrow {
+        seller: AccountId,
+        buyer: AccountId,
+        milestones: Vec<Milestone>,
+        /// How many milestones were neither released nor refunded yet.
+        pending: u32,
+        status: EscrowStatus,
+        /// After this timestamp the buyer can take back the pending milestones.
+        deadline: Timestamp,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Milestone {
+        pub amount: Balance,
+        pub status: MilestoneStatus,
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum MilestoneStatus {
+        #[default]
+        Pending,
+        /// Paid to the seller.
+        Released,
+        /// Paid back to the buyer.
+        Refunded,
+    }
+
+    /// The escrow is `Locked` once the buyer deposited the total and `Closed` once
+    /// every milestone was released or refunded.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum EscrowStatus {
+        #[default]
+        AwaitingDeposit,
+        Locked,
+        Closed,
+    }
+
+    #[ink(event)]
+    pub struct LockFunds {
+        #[ink(topic)]
+        pub seller: AccountId,
+        #[ink(topic)]
+        pub buyer: AccountId,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ReleaseMilestone {
+        #[ink(topic)]
+        pub index: u32,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct RefundMilestone {
+        #[ink(topic)]
+        pub index: u32,
+        pub amount: Balance,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Unauthorized,
+        TransferFailed,
+        /// The escrow is not in a status that allows this call.
+        InvalidStatus,
+        /// The deposit does not match the sum of the milestones.
+        IncorrectAmount,
+        /// No milestones, or a milestone without an amount.
+        InvalidMilestones,
+        /// More than `MAX_MILESTONES` milestones.
+        TooManyMilestones,
+        MilestoneNotFound,
+        /// The milestone was already released or refunded.
+        MilestoneClosed,
+        /// The milestones add up to more than `Balance::MAX`.
+        Overflow,
+        DeadlineNotReached,
+        /// The seller and the buyer are the same account.
+        InvalidParties,
+    }
+
+    impl MilestoneEscrow {
+        #[ink(constructor)]
+        pub fn new(
+            seller: AccountId,
+            buyer: AccountId,
+            amounts: Vec<Balance>,
+            deadline: Timestamp,
+        ) -> Result<Self, Error> {
+            if seller == buyer {
+                return Err(Error::InvalidParties);
+            }
+            if amounts.is_empty() || amounts.contains(&0) {
+                return Err(Error::InvalidMilestones);
+            }
+            if amounts.len() > MAX_MILESTONES as usize {
+                return Err(Error::TooManyMilestones);
+            }
+            // Checked once here, so that summing the milestones later cannot overflow.
+            amounts
+                .iter()
+                .try_fold(0, |total: Balance, amount| total.checked_add(*amount))
+                .ok_or(Error::Overflow)?;
+            let pending = amounts.len() as u32;
+            let milestones = amounts
+                .into_iter()
+                .map(|amount| Milestone {
+                    amount,
+                    status: MilestoneStatus::Pending,
+                })
+                .collect();
+            Ok(Self {
+                seller,
+                buyer,
+                milestones,
+                pending,
+                status: EscrowStatus::AwaitingDeposit,
+                deadline,
+            })
+        }
+
+        /// Deposits the sum of all milestones, which must be sent along with the call by
+        /// the buyer.
+        #[ink(message, payable)]
+        pub fn lock_funds(&mut self) -> Result<(), Error> {
+            if self.env().caller() != self.buyer {
+                return Err(Error::Unauthorized);
+            }
+            if self.status != EscrowStatus::AwaitingDeposit {
+                return Err(Error::InvalidStatus);
+            }
+            let amount = self.total();
+            if self.env().transferred_value() != amount {
+                return Err(Error::IncorrectAmount);
+            }
+            self.status = EscrowStatus::Locked;
+            self.env().emit_event(LockFunds {
+                seller: self.seller,
+                buyer: self.buyer,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Pays milestone `index` to the seller, called by the buyer once it was delivered.
+        #[ink(message)]
+        pub fn approve_milestone(&mut self, index: u32) -> Result<(), Error> {
+            if self.env().caller() != self.buyer {
+                return Err(Error::Unauthorized);
+            }
+            let amount = self.close_milestone(index, MilestoneStatus::Released, self.seller)?;
+            self.env().emit_event(ReleaseMilestone { index, amount });
+            Ok(())
+        }
+
+        /// Pays milestone `index` back to the buyer, called by the seller.
+        #[ink(message)]
+        pub fn refund_milestone(&mut self, index: u32) -> Result<(), Error> {
+            if self.env().caller() != self.seller {
+                return Err(Error::Unauthorized);
+            }
+            self.refund(index)
+        }
+
+        /// Refunds every milestone that is still pending in a single transfer, called by
+        /// the seller at any time or by the buyer once the deadline passed.
+        #[ink(message)]
+        pub fn refund_remaining(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.seller && caller != self.buyer {
+                return Err(Error::Unauthorized);
+            }
+            if self.status != EscrowStatus::Locked {
+                return Err(Error::InvalidStatus);
+            }
+            if caller == self.buyer && self.env().block_timestamp() <= self.deadline {
+                return Err(Error::DeadlineNotReached);
+            }
+
+            self.env()
+                .transfer(self.buyer, self.get_remaining())
+                .map_err(|_| Error::TransferFailed)?;
+            for index in 0..self.milestones.len() {
+                let milestone = &mut self.milestones[index];
+                if milestone.status == MilestoneStatus::Pending {
+                    milestone.status = MilestoneStatus::Refunded;
+                    let amount = milestone.amount;
+                    self.env().emit_event(RefundMilestone {
+                        index: index as u32,
+                        amount,
+                    });
+                }
+            }
+            self.pending = 0;
+            self.status = EscrowStatus::Closed;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_deadline(&self) -> Timestamp {
+            self.deadline
+        }
+
+        #[ink(message)]
+        pub fn get_status(&self) -> EscrowStatus {
+            self.status
+        }
+
+        #[ink(message)]
+        pub fn get_milestones(&self) -> Vec<Milestone> {
+            self.milestones.clone()
+        }
+
+        #[ink(message)]
+        pub fn get_milestone(&self, index: u32) -> Option<Milestone> {
+            self.milestones.get(index as usize).cloned()
+        }
+
+        /// Returns the amount of the milestones that are still pending.
+        #[ink(message)]
+        pub fn get_remaining(&self) -> Balance {
+            self.milestones
+                .iter()
+                .filter(|milestone| milestone.status == MilestoneStatus::Pending)
+                .map(|milestone| milestone.amount)
+                .sum()
+        }
+
+        fn total(&self) -> Balance {
+            self.milestones
+                .iter()
+                .map(|milestone| milestone.amount)
+                .sum()
+        }
+
+        fn refund(&mut self, index: u32) -> Result<(), Error> {
+            let amount = self.close_milestone(index, MilestoneStatus::Refunded, self.buyer)?;
+            self.env().emit_event(RefundMilestone { index, amount });
+            Ok(())
+        }
+
+        /// Pays a pending milestone to `to` and closes the escrow after its last one.
+        fn close_milestone(
+            &mut self,
+            index: u32,
+            status: MilestoneStatus,
+            to: AccountId,
+        ) -> Result<Balance, Error> {
+            if self.status != EscrowStatus::Locked {
+                return Err(Error::InvalidStatus);
+            }
+            let milestone = self
+                .milestones
+                .get(index as usize)
+                .ok_or(Error::MilestoneNotFound)?;
+            if milestone.status != MilestoneStatus::Pending {
+                return Err(Error::MilestoneClosed);
+            }
+            let amount = milestone.amount;
+
+            self.env()
+                .transfer(to, amount)
+                .map_err(|_| Error::TransferFailed)?;
+            self.milestones[index as usize].status = status;
+            self.pending -= 1;
+            if self.pending == 0 {
+                self.status = EscrowStatus::Closed;
+            }
+            Ok(amount)
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{balance_of, callee, set_balance, set_caller, transfer_in};
+use milestone_escrow::{Error, EscrowStatus, MilestoneEscrow, MilestoneStatus, RefundMilestone};
+
+const SELLER: AccountId = AccountId([0x4; 32]);
+const BUYER: AccountId = AccountId([0x2; 32]);
+
+fn setup() -> MilestoneEscrow {
+    let contract = callee();
+    set_balance(contract, 1_000_000);
+
+    let mut escrow = MilestoneEscrow::new(SELLER, BUYER, vec![100, 200, 300], 1_000).unwrap();
+    set_caller(BUYER);
+    transfer_in(600);
+    escrow.lock_funds().unwrap();
+    escrow
+}
+
+#[ink::test]
+fn test_refund_single_milestone() {
+    let mut escrow = setup();
+
+    assert_eq!(escrow.refund_milestone(2), Err(Error::Unauthorized));
+    set_caller(SELLER);
+    assert_eq!(escrow.refund_milestone(2), Ok(()));
+    assert_eq!(escrow.refund_milestone(2), Err(Error::MilestoneClosed));
+    assert_eq!(balance_of(BUYER), 700);
+
+    set_caller(BUYER);
+    assert_eq!(escrow.approve_milestone(2), Err(Error::MilestoneClosed));
+    assert_eq!(escrow.approve_milestone(0), Ok(()));
+    assert_eq!(escrow.get_remaining(), 200);
+    assert_eq!(escrow.get_status(), EscrowStatus::Locked);
+    assert_eq!(escrow.approve_milestone(1), Ok(()));
+    assert_eq!(escrow.get_status(), EscrowStatus::Closed);
+    assert_eq!(
+        escrow.get_milestone(2).map(|milestone| milestone.status),
+        Some(MilestoneStatus::Refunded)
+    );
+    assert_eq!(
+        leetcoin_test_kit::decode_events::<RefundMilestone>().len(),
+        1
+    );
+}
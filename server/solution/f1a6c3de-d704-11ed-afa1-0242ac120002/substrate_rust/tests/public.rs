@@ -0,0 +1,215 @@
+use ink::primitives::AccountId;
+use leetcoin_test_kit::{
+    balance_of, callee, event, set_balance, set_block_timestamp, set_caller, transfer_in,
+};
+use milestone_escrow::{
+    Error, EscrowStatus, LockFunds, Milestone, MilestoneEscrow, MilestoneStatus, RefundMilestone,
+    ReleaseMilestone, MAX_MILESTONES,
+};
+
+const SELLER: AccountId = AccountId([0x4; 32]);
+/// Starts with a balance of 1000 in the off-chain environment.
+const BUYER: AccountId = AccountId([0x2; 32]);
+const DEADLINE: u64 = 1_000;
+
+/// Deploys an escrow with milestones of 100, 200 and 300 and deposits them.
+fn setup() -> MilestoneEscrow {
+    let contract = callee();
+    set_balance(contract, 1_000_000);
+
+    let mut escrow = MilestoneEscrow::new(SELLER, BUYER, vec![100, 200, 300], DEADLINE).unwrap();
+    set_caller(BUYER);
+    transfer_in(600);
+    escrow.lock_funds().unwrap();
+    escrow
+}
+
+#[ink::test]
+fn test_new() {
+    let escrow = MilestoneEscrow::new(SELLER, BUYER, vec![100, 200], DEADLINE).unwrap();
+    assert_eq!(escrow.get_status(), EscrowStatus::AwaitingDeposit);
+    assert_eq!(escrow.get_remaining(), 300);
+    assert_eq!(
+        escrow.get_milestone(1),
+        Some(Milestone {
+            amount: 200,
+            status: MilestoneStatus::Pending
+        })
+    );
+    assert_eq!(escrow.get_milestone(2), None);
+    assert_eq!(escrow.get_deadline(), DEADLINE);
+
+    assert_eq!(
+        MilestoneEscrow::new(SELLER, SELLER, vec![100], DEADLINE).err(),
+        Some(Error::InvalidParties)
+    );
+    assert_eq!(
+        MilestoneEscrow::new(SELLER, BUYER, vec![], DEADLINE).err(),
+        Some(Error::InvalidMilestones)
+    );
+    assert_eq!(
+        MilestoneEscrow::new(SELLER, BUYER, vec![100, 0], DEADLINE).err(),
+        Some(Error::InvalidMilestones)
+    );
+    assert_eq!(
+        MilestoneEscrow::new(SELLER, BUYER, vec![u128::MAX, 1], DEADLINE).err(),
+        Some(Error::Overflow)
+    );
+}
+
+#[ink::test]
+fn test_lock_funds() {
+    let mut escrow = MilestoneEscrow::new(SELLER, BUYER, vec![100, 200], DEADLINE).unwrap();
+
+    set_caller(BUYER);
+    transfer_in(100);
+    assert_eq!(escrow.lock_funds(), Err(Error::IncorrectAmount));
+    set_caller(SELLER);
+    assert_eq!(escrow.approve_milestone(0), Err(Error::Unauthorized));
+    assert_eq!(escrow.refund_milestone(0), Err(Error::InvalidStatus));
+
+    set_caller(BUYER);
+    assert_eq!(escrow.approve_milestone(0), Err(Error::InvalidStatus));
+    transfer_in(300);
+    assert_eq!(escrow.lock_funds(), Ok(()));
+    assert_eq!(escrow.get_status(), EscrowStatus::Locked);
+    assert_eq!(escrow.lock_funds(), Err(Error::InvalidStatus));
+
+    leetcoin_test_kit::assert_events([event(LockFunds {
+        seller: SELLER,
+        buyer: BUYER,
+        amount: 300,
+    })]);
+}
+
+#[ink::test]
+fn test_partial_release() {
+    let mut escrow = setup();
+
+    assert_eq!(escrow.approve_milestone(1), Ok(()));
+    assert_eq!(balance_of(SELLER), 200);
+    assert_eq!(escrow.get_remaining(), 400);
+    assert_eq!(
+        escrow.get_milestone(1).map(|milestone| milestone.status),
+        Some(MilestoneStatus::Released)
+    );
+    assert_eq!(escrow.get_status(), EscrowStatus::Locked);
+
+    assert_eq!(escrow.approve_milestone(1), Err(Error::MilestoneClosed));
+    assert_eq!(escrow.approve_milestone(3), Err(Error::MilestoneNotFound));
+
+    assert_eq!(escrow.approve_milestone(0), Ok(()));
+    assert_eq!(balance_of(SELLER), 300);
+
+    let events = leetcoin_test_kit::recorded_events();
+    assert_eq!(events.len(), 3);
+    leetcoin_test_kit::assert_topic(&events[1], &1u32);
+    let released: Vec<(u32, u128)> = leetcoin_test_kit::decode_events::<ReleaseMilestone>()
+        .into_iter()
+        .map(|event| (event.index, event.amount))
+        .collect();
+    assert_eq!(released, vec![(1, 200), (0, 100)]);
+}
+
+#[ink::test]
+fn test_refund_remaining() {
+    let mut escrow = setup();
+    escrow.approve_milestone(0).unwrap();
+
+    assert_eq!(escrow.refund_remaining(), Err(Error::DeadlineNotReached));
+    set_caller(SELLER);
+    assert_eq!(escrow.refund_remaining(), Ok(()));
+
+    assert_eq!(balance_of(SELLER), 100);
+    assert_eq!(balance_of(BUYER), 900);
+    assert_eq!(escrow.get_remaining(), 0);
+    assert_eq!(escrow.get_status(), EscrowStatus::Closed);
+    assert_eq!(
+        escrow
+            .get_milestones()
+            .iter()
+            .map(|milestone| milestone.status)
+            .collect::<Vec<_>>(),
+        vec![
+            MilestoneStatus::Released,
+            MilestoneStatus::Refunded,
+            MilestoneStatus::Refunded
+        ]
+    );
+    assert_eq!(escrow.refund_remaining(), Err(Error::InvalidStatus));
+
+    let events = leetcoin_test_kit::recorded_events();
+    assert_eq!(events.len(), 4);
+    assert!(matches!(
+        leetcoin_test_kit::decode_events::<RefundMilestone>()[..],
+        [
+            RefundMilestone {
+                index: 1,
+                amount: 200
+            },
+            RefundMilestone {
+                index: 2,
+                amount: 300
+            }
+        ]
+    ));
+}
+
+#[ink::test]
+fn test_max_milestones() {
+    let contract = callee();
+    set_balance(contract, 1_000_000);
+    assert_eq!(
+        MilestoneEscrow::new(
+            SELLER,
+            BUYER,
+            vec![10; MAX_MILESTONES as usize + 1],
+            DEADLINE
+        )
+        .err(),
+        Some(Error::TooManyMilestones)
+    );
+
+    let amounts = vec![10; MAX_MILESTONES as usize];
+    let mut escrow = MilestoneEscrow::new(SELLER, BUYER, amounts, DEADLINE).unwrap();
+    set_caller(BUYER);
+    transfer_in(10 * u128::from(MAX_MILESTONES));
+    escrow.lock_funds().unwrap();
+    escrow.approve_milestone(0).unwrap();
+
+    set_caller(SELLER);
+    assert_eq!(escrow.refund_remaining(), Ok(()));
+    assert_eq!(balance_of(SELLER), 10);
+    assert_eq!(balance_of(BUYER), 990);
+    assert_eq!(escrow.get_remaining(), 0);
+    assert_eq!(escrow.get_status(), EscrowStatus::Closed);
+    assert_eq!(
+        leetcoin_test_kit::decode_events::<RefundMilestone>().len(),
+        MAX_MILESTONES as usize - 1
+    );
+}
+
+#[ink::test]
+fn test_refund_remaining_after_deadline() {
+    let mut escrow = setup();
+    escrow.approve_milestone(1).unwrap();
+
+    set_block_timestamp(DEADLINE);
+    assert_eq!(escrow.refund_remaining(), Err(Error::DeadlineNotReached));
+
+    set_block_timestamp(DEADLINE + 1);
+    set_caller(AccountId([0x3; 32]));
+    assert_eq!(escrow.refund_remaining(), Err(Error::Unauthorized));
+    set_caller(BUYER);
+    assert_eq!(escrow.refund_remaining(), Ok(()));
+
+    assert_eq!(balance_of(SELLER), 200);
+    assert_eq!(balance_of(BUYER), 800);
+    assert_eq!(escrow.get_status(), EscrowStatus::Closed);
+    assert_eq!(escrow.refund_remaining(), Err(Error::InvalidStatus));
+    let refunded: Vec<(u32, u128)> = leetcoin_test_kit::decode_events::<RefundMilestone>()
+        .into_iter()
+        .map(|event| (event.index, event.amount))
+        .collect();
+    assert_eq!(refunded, vec![(0, 100), (2, 300)]);
+}
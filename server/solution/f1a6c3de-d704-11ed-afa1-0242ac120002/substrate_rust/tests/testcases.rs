@@ -0,0 +1,85 @@
+use leetcoin_test_kit::{decode_event, EmittedEvent};
+use leetcoin_testcase::{json, Args, Contract, Event, ReplayError, ToValue, Value};
+use milestone_escrow::{LockFunds, Milestone, MilestoneEscrow, RefundMilestone, ReleaseMilestone};
+
+/// Binds `testcase.txt` to [`MilestoneEscrow`].
+struct Replay(MilestoneEscrow);
+
+fn milestone_value(milestone: &Milestone) -> Value {
+    json!({
+        "amount": milestone.amount.to_value(),
+        "status": format!("{:?}", milestone.status),
+    })
+}
+
+impl Contract for Replay {
+    fn construct(constructor: &str, args: &Args) -> Result<Self, ReplayError> {
+        match constructor {
+            "new" => MilestoneEscrow::new(
+                args.account(0)?,
+                args.account(1)?,
+                args.balances(2)?,
+                args.u64(3)?,
+            )
+            .map(Replay)
+            .map_err(|err| ReplayError::new(format!("`new` failed with {:?}", err))),
+            _ => Err(ReplayError::unknown("constructor", constructor)),
+        }
+    }
+
+    fn call(&mut self, message: &str, args: &Args) -> Result<Value, ReplayError> {
+        match message {
+            "lock_funds" => Ok(self.0.lock_funds().to_value()),
+            "approve_milestone" => Ok(self.0.approve_milestone(args.u32(0)?).to_value()),
+            "refund_milestone" => Ok(self.0.refund_milestone(args.u32(0)?).to_value()),
+            "refund_remaining" => Ok(self.0.refund_remaining().to_value()),
+            "get_status" => Ok(format!("{:?}", self.0.get_status()).to_value()),
+            "get_milestones" => Ok(Value::Array(
+                self.0
+                    .get_milestones()
+                    .iter()
+                    .map(milestone_value)
+                    .collect(),
+            )),
+            "get_milestone" => Ok(self
+                .0
+                .get_milestone(args.u32(0)?)
+                .as_ref()
+                .map_or(Value::Null, milestone_value)),
+            "get_remaining" => Ok(self.0.get_remaining().to_value()),
+            "get_deadline" => Ok(self.0.get_deadline().to_value()),
+            _ => Err(ReplayError::unknown("message", message)),
+        }
+    }
+
+    fn decode_event(event: &EmittedEvent) -> Option<Event> {
+        if let Some(event) = decode_event::<LockFunds>(event) {
+            return Some(
+                Event::new("LockFunds")
+                    .field("seller", event.seller)
+                    .field("buyer", event.buyer)
+                    .field("amount", event.amount),
+            );
+        }
+        if let Some(event) = decode_event::<ReleaseMilestone>(event) {
+            return Some(
+                Event::new("ReleaseMilestone")
+                    .field("index", event.index)
+                    .field("amount", event.amount),
+            );
+        }
+        if let Some(event) = decode_event::<RefundMilestone>(event) {
+            return Some(
+                Event::new("RefundMilestone")
+                    .field("index", event.index)
+                    .field("amount", event.amount),
+            );
+        }
+        None
+    }
+}
+
+#[test]
+fn testcases() {
+    leetcoin_testcase::assert_replay::<Replay>(include_str!("../testcase.txt"));
+}
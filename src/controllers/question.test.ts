@@ -0,0 +1,94 @@
+import {test} from 'node:test';
+import * as assert from 'node:assert';
+import {Request, Response} from 'express';
+import {getQuestionSubmissions, submit} from "./question";
+import {QuestionSubmissionRepo} from "../storage/questionSubmissionRepo";
+import {CaseMap, QuestionSubmission, RunnerOutput, RunnerStatus} from "../models";
+import runnerManager = require("../engine/runnerManager");
+
+const HIDDEN_OUTPUT = 'expected hidden value 42';
+
+const graded: CaseMap = {
+  public_case: { id: 'public_case', passed: true, rawOutput: 'public output', runtime: 3, display: true },
+  hidden_case: {
+    id: 'hidden_case',
+    passed: false,
+    panicMessage: HIDDEN_OUTPUT,
+    rawOutput: HIDDEN_OUTPUT,
+    runtime: 5,
+    display: false,
+  },
+};
+
+function submission(): QuestionSubmission {
+  return {
+    id: 'submission',
+    questionId: 'question',
+    userId: 'user',
+    submissionCode: Buffer.from('fn main() {}').toString('base64'),
+    language: 'substrate_rust',
+    status: 'initial',
+    results: { cases: {} },
+  };
+}
+
+function response() {
+  const res = {
+    code: 0,
+    body: undefined as unknown,
+    status(code: number) {
+      res.code = code;
+      return res;
+    },
+    json(body: unknown) {
+      res.body = body;
+      return res;
+    },
+    send(body: unknown) {
+      res.body = body;
+      return res;
+    },
+  };
+  return res;
+}
+
+test('hidden case output is neither returned nor stored by submit', async () => {
+  const repo = QuestionSubmissionRepo.getInstance();
+  const stored: QuestionSubmission[] = [];
+  repo.createQuestionSubmission = async () => submission();
+  repo.updateQuestionSubmission = async (s) => {
+    stored.push(JSON.parse(JSON.stringify(s)));
+    return s;
+  };
+  Object.assign(runnerManager, {
+    run: async (): Promise<RunnerOutput> => ({ rawOutput: JSON.stringify(graded), status: RunnerStatus.FAILED_TESTS, cases: graded }),
+  });
+
+  const res = response();
+  await submit({ body: submission() } as Request, res as unknown as Response);
+
+  assert.strictEqual(res.code, 200);
+  assert.ok(!JSON.stringify(res.body).includes(HIDDEN_OUTPUT));
+  assert.ok(JSON.stringify(res.body).includes('public output'));
+  assert.strictEqual(stored.length, 1);
+  assert.ok(!JSON.stringify(stored).includes(HIDDEN_OUTPUT));
+  assert.deepStrictEqual(stored[0].results.cases.hidden_case, {
+    id: 'hidden_case',
+    passed: false,
+    rawOutput: '',
+    runtime: 5,
+    display: false,
+  });
+});
+
+test('hidden case output is not returned by getQuestionSubmissions', async () => {
+  const repo = QuestionSubmissionRepo.getInstance();
+  repo.getQuestionSubmissions = async () => [{ ...submission(), status: 'failed_tests', results: { cases: graded } }];
+
+  const res = response();
+  await getQuestionSubmissions({ params: { questionId: 'question' } } as unknown as Request, res as unknown as Response);
+
+  assert.strictEqual(res.code, 200);
+  assert.ok(!JSON.stringify(res.body).includes(HIDDEN_OUTPUT));
+  assert.ok(JSON.stringify(res.body).includes('public output'));
+});
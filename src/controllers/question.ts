@@ -9,6 +9,8 @@ import {
     RunnerStatus
 } from "../models";
 import {run} from "../engine/runnerManager";
+import {publicCases, publicRawOutput} from "../engine/results";
+import {mapRunnerStatus} from "../engine/runnerStatus";
 
 export async function getQuestion(req: Request, res: Response) {
     const questionId = req.params.questionId;
@@ -94,7 +96,8 @@ export async function getQuestionSubmissions(req: Request, res: Response) {
     }
 
     if (submissions) {
-        res.status(200).send(submissions);
+        // submissions graded before hidden output was dropped still hold it
+        res.status(200).send(submissions.map(s => ({ ...s, results: { cases: publicCases(s.results?.cases) } })));
     } else {
         res.status(404).send();
     }
@@ -131,8 +134,13 @@ export async function submit(req: Request, res: Response) {
     const solution = buff.toString('ascii');
 
     try {
-        const { rawOutput, status } = await run(submission.id, submission.questionId, submission.language, solution);
-        res.status(200).json({ rawOutput, status });
+        const { rawOutput, status, cases } = await run(submission.id, submission.questionId, submission.language, solution);
+        submission.status = mapRunnerStatus(status);
+        // the grader output holds a record per test, hidden ones included
+        const visible = publicCases(cases);
+        submission.results = { cases: visible };
+        await questionSubmissionRepo.updateQuestionSubmission(submission);
+        res.status(200).json({ rawOutput: cases ? publicRawOutput(visible) : rawOutput, status, cases: visible });
         return;
     } catch (err) {
         console.log(err);
@@ -148,6 +156,7 @@ export async function submit(req: Request, res: Response) {
         submission.results.cases = {
             lang: {
                 id: '',
+                passed: false,
                 rawOutput: '',
                 runtime: 0,
                 display: true,
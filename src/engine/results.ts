@@ -0,0 +1,20 @@
+import {CaseMap} from "../models";
+
+// hidden cases only report whether they passed
+export function publicCases(cases?: CaseMap): CaseMap {
+  const result: CaseMap = {};
+  const all = cases ?? {};
+  for (const id of Object.keys(all)) {
+    const c = all[id];
+    result[id] = c.display ? c : { id, passed: c.passed, rawOutput: '', runtime: c.runtime, display: false };
+  }
+  return result;
+}
+
+export function publicRawOutput(cases: CaseMap): string {
+  return Object.keys(cases)
+    .map(id => cases[id])
+    .filter(c => c.display)
+    .map(c => JSON.stringify(c))
+    .join('\n');
+}
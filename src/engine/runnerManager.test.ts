@@ -0,0 +1,78 @@
+import {test} from 'node:test';
+import * as assert from 'node:assert';
+import * as path from "path";
+import {runExclusive, workspaceFilter} from "./runnerManager";
+
+const WORKSPACE = path.join("/app", "server", "solution");
+const QUESTION = "97bca69a-d703-11ed-afa1-0242ac120002";
+const OTHER = "d300b862-d703-11ed-afa1-0242ac120002";
+
+function copied(...parts: string[]): boolean {
+  return workspaceFilter(WORKSPACE, QUESTION)(path.join(WORKSPACE, ...parts));
+}
+
+test('the workspace copy keeps the question crate and the shared crates', () => {
+  assert.ok(copied());
+  assert.ok(copied("Cargo.toml"));
+  assert.ok(copied("Cargo.lock"));
+  assert.ok(copied("crates", "leetcoin_grader", "main.rs"));
+  assert.ok(copied(QUESTION, "substrate_rust", "lib.rs"));
+  assert.ok(copied(QUESTION, "substrate_rust", "tests", "hidden.rs"));
+});
+
+test('the workspace copy only keeps the manifest of other questions', () => {
+  assert.ok(copied(OTHER, "substrate_rust"));
+  assert.ok(copied(OTHER, "substrate_rust", "Cargo.toml"));
+  assert.ok(!copied(OTHER, "substrate_rust", "lib.rs"));
+  assert.ok(!copied(OTHER, "substrate_rust", "tests"));
+  assert.ok(!copied(OTHER, "substrate_rust", "tests", "hidden.rs"));
+});
+
+test('the workspace copy leaves out build output', () => {
+  assert.ok(!copied("target"));
+  assert.ok(!copied(QUESTION, "substrate_rust", "target"));
+});
+
+// resolves once `release` is called, recording when it started and finished
+function pending(events: string[], name: string): {task: () => Promise<string>, release: () => void} {
+  let release = () => {};
+  const done = new Promise<void>((resolve) => {
+    release = () => resolve();
+  });
+  const task = async () => {
+    events.push(`${name} started`);
+    await done;
+    events.push(`${name} finished`);
+    return name;
+  };
+  return {task, release: () => release()};
+}
+
+test('submissions of the same question run one after the other', async () => {
+  const events: string[] = [];
+  const first = pending(events, "first");
+  const second = pending(events, "second");
+  const other = pending(events, "other");
+
+  const firstResult = runExclusive(QUESTION, first.task);
+  const secondResult = runExclusive(QUESTION, second.task);
+  const otherResult = runExclusive(OTHER, other.task);
+  await new Promise((resolve) => setImmediate(resolve));
+  // a submission of another question does not wait
+  assert.deepStrictEqual(events, ["first started", "other started"]);
+
+  second.release();
+  first.release();
+  other.release();
+  assert.deepStrictEqual(await Promise.all([firstResult, secondResult, otherResult]), ["first", "second", "other"]);
+  assert.ok(events.indexOf("first finished") < events.indexOf("second started"));
+});
+
+test('a failed submission does not hold up the next one', async () => {
+  const failed = runExclusive(QUESTION, async () => {
+    throw new Error("grader error");
+  });
+  const next = runExclusive(QUESTION, async () => "graded");
+  await assert.rejects(failed, /grader error/);
+  assert.strictEqual(await next, "graded");
+});
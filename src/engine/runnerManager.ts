@@ -29,51 +29,98 @@ export async function run(
   const factory = new Factory();
   const runner = factory.createRunner(lang);
 
-  // copy all files in the question folder from solution folder
-  const sourceDir = path.resolve(appRoot.path, "server", "solution", questionId, lang);
+  // the solution crates share one cargo workspace, so copy the workspace with the
+  // question crate and run it from inside it.
+  const workspaceDir = path.resolve(appRoot.path, "server", "solution");
+  const sourceDir = path.resolve(workspaceDir, questionId, lang);
   const now = moment().toISOString();
 
-  const name = `${submissionId}`;
-
+  // every submission of a question builds at the same path, so cargo recompiles the
+  // crates of the previous submission in place rather than keeping a copy of them per
+  // submission in the shared target directory.
   const targetDir = path.resolve(
     appRoot.path,
     "server",
     "engine",
     "temp",
-    name, // TODO change this to submission id
+    questionId,
   );
 
-  try {
-    // copy source code files
-    await FileApi.copyDirectory(sourceDir, targetDir);
-
-    const testcaseFile = path.join(targetDir, "testcase.txt");
-
-    // copy test case file
-    await FileApi.copyFile(path.join(sourceDir, "testcase.txt"), testcaseFile);
-    console.log(`[engine][run], sourceFile: ${runner.getSourceFile()}}`);
-    // save the solution to Solution.rs
-    const sourceFile = path.resolve(targetDir, runner.getSourceFile());
-    const filename = path.parse(sourceFile).name; // lib
-    const extension = path.parse(sourceFile).ext; // .rs
-
-    await FileApi.saveFile(sourceFile, solution);
-
-    const testFile = path.resolve(targetDir, runner.getTestFile());
-    const testFileName = path.parse(testFile).name; // main
-
-    return await runner.run(
-      testFile,
-      targetDir,
-      testFileName,
-      extension
-    );
-  } catch (err) {
-    console.log(`[engine][run], err: ${err}`)
-    throw err;
-  }
+  const crateDir = path.resolve(targetDir, questionId, lang);
+
+  // submissions of the same question wait for each other. Submissions of different
+  // questions run at once, but share the target directory of the reference workspace,
+  // so cargo compiles for one of them at a time while the others wait on its build
+  // lock. Running the tests does not hold the lock.
+  return runExclusive(questionId, async () => {
+    try {
+      console.log(`[engine][run], submissionId: ${submissionId}, questionId: ${questionId}`);
+      // nothing a previous submission wrote into the crate is left for this one
+      await FileApi.removeDirectory(targetDir);
+      // copy source code files
+      await FileApi.copyDirectory(workspaceDir, targetDir, workspaceFilter(workspaceDir, questionId));
+
+      const testcaseFile = path.join(crateDir, "testcase.txt");
+
+      // copy test case file
+      await FileApi.copyFile(path.join(sourceDir, "testcase.txt"), testcaseFile);
+      console.log(`[engine][run], sourceFile: ${runner.getSourceFile()}}`);
+      // save the solution to Solution.rs
+      const sourceFile = path.resolve(crateDir, runner.getSourceFile());
+      const filename = path.parse(sourceFile).name; // lib
+      const extension = path.parse(sourceFile).ext; // .rs
+
+      await FileApi.saveFile(sourceFile, solution);
+
+      const testFile = path.resolve(crateDir, runner.getTestFile());
+      const testFileName = path.parse(testFile).name; // main
+
+      return await runner.run(
+        testFile,
+        crateDir,
+        testFileName,
+        extension
+      );
+    } catch (err) {
+      console.log(`[engine][run], err: ${err}`)
+      throw err;
+    }
+  });
 }
 
+const running = new Map<string, Promise<unknown>>();
+
+// Runs `task` once every task started before it with the same key has settled.
+export function runExclusive<T>(key: string, task: () => Promise<T>): Promise<T> {
+  const previous = running.get(key) ?? Promise.resolve();
+  const result = previous.catch(() => undefined).then(task);
+  const settled = result.catch(() => undefined);
+  running.set(key, settled);
+  settled.then(() => {
+    if (running.get(key) === settled) {
+      running.delete(key);
+    }
+  });
+  return result;
+}
+
+
+// Keeps the workspace manifest, its lock file, the shared crates and the question
+// crate. The other questions only keep their Cargo.toml, which cargo needs to load
+// every workspace member and still resolve to the committed Cargo.lock.
+export function workspaceFilter(workspaceDir: string, questionId: string): (file: string) => boolean {
+  return (file) => {
+    const parts = path.relative(workspaceDir, file).split(path.sep);
+    if (parts.includes("target")) {
+      return false;
+    }
+    if (parts.length === 1 || parts[0] === "crates" || parts[0] === questionId) {
+      return true;
+    }
+    // <question id>/<language>/Cargo.toml
+    return parts.length < 3 || (parts.length === 3 && parts[2] === "Cargo.toml");
+  };
+}
 
 // function mapLanguageToDirectoryName(lang: Language): string {
 //   if (lang === 'substrate_rust') {
@@ -0,0 +1,14 @@
+import {QuestionSubmissionStatus, RunnerStatus} from "../models";
+
+export function mapRunnerStatus(status: RunnerStatus): QuestionSubmissionStatus {
+  switch (status) {
+    case RunnerStatus.SUCCESS:
+      return 'success';
+    case RunnerStatus.FAILED_TO_COMPILE:
+      return 'failed_to_compile';
+    case RunnerStatus.FAILED_TESTS:
+      return 'failed_tests';
+    default:
+      return 'system_error';
+  }
+}
@@ -1,6 +1,19 @@
+import * as path from "path";
+import * as appRoot from "app-root-path";
 import spawnAsync from '@expo/spawn-async';
 import Runner from './runner';
-import {RunnerStatus, RunnerOutput} from "../models";
+import {RunnerStatus, RunnerOutput, CaseMap} from "../models";
+
+// exit codes of the leetcoin-grader binary in server/solution/crates/leetcoin_grader
+const GRADER_FAILED_TESTS = 1;
+const GRADER_FAILED_TO_COMPILE = 2;
+const GRADER_ERROR = 3;
+// the grader limits each test on its own, this bounds compiling and running all of them
+const GRADER_TIMEOUT_MS = 5 * 60 * 1000;
+// shared by every submission, so the dependencies built with the reference workspace
+// are reused and only the question crate and the grader are compiled. Each question
+// is built at a fixed path (see runnerManager), so this holds one build of it at most.
+const CARGO_TARGET_DIR = path.resolve(appRoot.path, "server", "solution", "target");
 
 export class SubstrateRustRunner extends Runner {
 
@@ -40,17 +53,46 @@ export class SubstrateRustRunner extends Runner {
   async execute(directory: string, filename: string, options: any): Promise<RunnerOutput> {
     console.log(`[substrateRustRunner][execute] directory: ${directory}, filename: ${filename}`)
     try {
-      const {stdout, stderr} = await spawnAsync('cargo', ['test', '--quiet'], options);
+      const {stdout} = await spawnAsync('cargo', ['run', '--quiet', '--locked', '-p', 'leetcoin_grader', '--', 'Cargo.toml'], {...options, env: {...process.env, CARGO_TARGET_DIR}, timeout: GRADER_TIMEOUT_MS});
       console.log(`[substrateRustRunner][execute] stdout: ${stdout}`);
-      if (stderr !== '') {
-        console.log(`[substrateRustRunner][execute] stderr: ${stderr}`);
-        return {status: RunnerStatus.FAILED_TESTS, rawOutput: stderr};
+      return {status: RunnerStatus.SUCCESS, rawOutput: stdout, cases: this.parseCases(stdout)};
+    } catch (err: any) {
+      console.log(`[substrateRustRunner][execute] stderr: ${err.stderr}`);
+      if (err.status === GRADER_FAILED_TESTS) {
+        return {status: RunnerStatus.FAILED_TESTS, rawOutput: err.stdout, cases: this.parseCases(err.stdout)};
       }
-      return {status: RunnerStatus.SUCCESS, rawOutput: stdout};
-    } catch (err) {
-      console.error(`[substrateRustRunner][execute] stderr: ${err}`);
-      return {status: RunnerStatus.SYSTEM_ERROR, rawOutput: JSON.stringify(err)};
+      if (err.status === GRADER_FAILED_TO_COMPILE) {
+        // the grader only prints the diagnostics pointing at the submitted lib.rs
+        return {status: RunnerStatus.FAILED_TO_COMPILE, rawOutput: err.stderr};
+      }
+      if (err.status === GRADER_ERROR) {
+        console.error(`[substrateRustRunner][execute] grader error: ${err.stderr}`);
+      } else {
+        console.error(`[substrateRustRunner][execute] err: ${err}`);
+      }
+      // the output may quote the hidden tests, so it stays in the server log
+      return {status: RunnerStatus.SYSTEM_ERROR, rawOutput: 'System error'};
+    }
+  }
+
+  // the grader prints one JSON record per test
+  parseCases(stdout: string): CaseMap {
+    const cases: CaseMap = {};
+    for (const line of stdout.split('\n')) {
+      if (line.trim() === '') {
+        continue;
+      }
+      const record = JSON.parse(line);
+      cases[record.id] = {
+        id: record.id,
+        passed: record.passed,
+        panicMessage: record.panicMessage ?? undefined,
+        rawOutput: record.rawOutput,
+        runtime: record.runtime,
+        display: record.public,
+      };
     }
+    return cases;
   }
 
   log(message: string) {
@@ -30,12 +30,14 @@ export interface Question {
 
 export interface Case {
   id: string,
+  passed: boolean,
+  panicMessage?: string,
   rawOutput: string,
   runtime: number,
   display: boolean,
 }
 
-type CaseMap = Record<string, Case>;
+export type CaseMap = Record<string, Case>;
 
 export interface QuestionSubmissionResult {
   cases: CaseMap,
@@ -65,4 +67,5 @@ export enum RunnerStatus {
 export interface RunnerOutput {
   rawOutput: string,
   status: RunnerStatus,
+  cases?: CaseMap,
 }
@@ -1,7 +1,8 @@
 import {PsqlDB} from "./db";
 import {QueryResultRow} from "pg";
-import {QuestionSubmission, QuestionSubmissionStatus, RunnerStatus} from "../models";
+import {QuestionSubmission, QuestionSubmissionStatus} from "../models";
 import * as RunnerManager from '../engine/runnerManager';
+import {mapRunnerStatus} from "../engine/runnerStatus";
 import {QuestionRepo} from "./questionRepo";
 
 
@@ -79,16 +80,8 @@ export class QuestionSubmissionRepo {
 }
 
 async function run(questionSubmission: QuestionSubmission, codeSolution: string): Promise<QuestionSubmissionStatus> {
-  const {status, rawOutput} = await RunnerManager.run(questionSubmission.id, questionSubmission.questionId, questionSubmission.language, codeSolution);
-  if (status === RunnerStatus.SUCCESS) {
-    return 'success';
-  } else if (status === RunnerStatus.FAILED_TESTS) {
-    return 'failed_tests';
-  } else if (status === RunnerStatus.FAILED_TO_COMPILE) {
-    return 'failed_to_compile';
-  } else {
-    return 'system_error';
-  }
+  const {status} = await RunnerManager.run(questionSubmission.id, questionSubmission.questionId, questionSubmission.language, codeSolution);
+  return mapRunnerStatus(status);
 }
 
 function mapRecordToQuestionSubmission(record: QueryResultRow): QuestionSubmission {
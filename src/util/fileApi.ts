@@ -85,11 +85,12 @@ export async function copyFile(source: string, target: string): Promise<void> {
 export async function copyDirectory(
   source: string,
   target: string,
+  filter?: (file: string) => boolean,
 ): Promise<void> {
   try {
     console.log(`[fileApi][copyDirectory], source: ${source}, target: ${target}`);
     await fs.promises.mkdir(target, {recursive: true});
-    await ncpPromise(source, target);
+    await ncpPromise(source, target, filter ? {filter} : {});
     return;
   } catch (err) {
     console.log(`[fileApi][copyDirectory], err: ${err}`);
@@ -97,6 +98,16 @@ export async function copyDirectory(
   }
 }
 
+export async function removeDirectory(directory: string): Promise<void> {
+  try {
+    console.log(`[fileApi][removeDirectory], directory: ${directory}`);
+    await fs.promises.rm(directory, {recursive: true, force: true});
+  } catch (err) {
+    console.log(`[fileApi][removeDirectory], err: ${err}`);
+    throw err;
+  }
+}
+
 export async function readFile(file: string, callback: (err: NodeJS.ErrnoException | null, data: string) => void) {
   console.log(`[fileApi][readFile], file: ${file}`);
   try {